@@ -1,10 +1,12 @@
 use clap::{Parser, Subcommand};
 use rs_wordle_solver::scorers::*;
 use rs_wordle_solver::*;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io;
 use std::io::BufRead;
+use std::io::Write;
 use std::result::Result;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -38,9 +40,14 @@ enum GuesserImpl {
     Random,
     UniqueLetterFrequency,
     LocatedLetters,
+    PositionalLetters,
     ApproximateEliminations,
     MaxEliminations,
+    MaxEntropy,
+    Minimax,
     MaxComboEliminations,
+    MinExpectedGuesses,
+    ApproxExpectedGuesses,
 }
 
 impl std::str::FromStr for GuesserImpl {
@@ -50,10 +57,15 @@ impl std::str::FromStr for GuesserImpl {
             "random" => Ok(GuesserImpl::Random),
             "unique_letters" => Ok(GuesserImpl::UniqueLetterFrequency),
             "located_letters" => Ok(GuesserImpl::LocatedLetters),
+            "positional_letters" => Ok(GuesserImpl::PositionalLetters),
             "approx_eliminations" => Ok(GuesserImpl::ApproximateEliminations),
             "max_eliminations" => Ok(GuesserImpl::MaxEliminations),
+            "max_entropy" => Ok(GuesserImpl::MaxEntropy),
+            "minimax" => Ok(GuesserImpl::Minimax),
             "max_combo_eliminations" => Ok(GuesserImpl::MaxComboEliminations),
-            _ => Err(String::from("Valid guesser implementations are: 'approx_eliminations', 'located_letters', 'max_eliminations', 'max_combo_eliminations', 'random', 'unique_letters', and 'unique_unguessed_letters'."))
+            "min_expected_guesses" => Ok(GuesserImpl::MinExpectedGuesses),
+            "approx_expected_guesses" => Ok(GuesserImpl::ApproxExpectedGuesses),
+            _ => Err(String::from("Valid guesser implementations are: 'approx_eliminations', 'approx_expected_guesses', 'located_letters', 'max_eliminations', 'max_combo_eliminations', 'max_entropy', 'min_expected_guesses', 'minimax', 'positional_letters', 'random', 'unique_letters', and 'unique_unguessed_letters'."))
         }
     }
 }
@@ -75,11 +87,32 @@ impl std::str::FromStr for GuessFrom {
     }
 }
 
-impl Into<rs_wordle_solver::GuessFrom> for GuessFrom {
-    fn into(self) -> rs_wordle_solver::GuessFrom {
-        match self {
-            Self::AllUnguessedWords => rs_wordle_solver::GuessFrom::AllUnguessedWords,
-            Self::PossibleWords => rs_wordle_solver::GuessFrom::PossibleWords,
+impl From<GuessFrom> for rs_wordle_solver::GuessFrom {
+    fn from(guess_from: GuessFrom) -> Self {
+        match guess_from {
+            GuessFrom::AllUnguessedWords => Self::AllUnguessedWords,
+            GuessFrom::PossibleWords => Self::PossibleWords,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum InteractiveMode {
+    /// The human chooses a hidden word, and the solver tries to guess it.
+    Solve,
+    /// The human makes their own guesses against a known answer, and the solver suggests guesses.
+    AssistHuman,
+}
+
+impl std::str::FromStr for InteractiveMode {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "solve" => Ok(InteractiveMode::Solve),
+            "assist-human" => Ok(InteractiveMode::AssistHuman),
+            _ => Err(String::from(
+                "Valid interactive modes are: 'assist-human' and 'solve'.",
+            )),
         }
     }
 }
@@ -90,11 +123,27 @@ enum Command {
     Benchmark {
         /// The file of words to benchmark against.
         bench_file: String,
+        /// If set, prints some example words for each number of guesses that was needed.
+        #[clap(long)]
+        group_by_difficulty: bool,
+        /// If set, prints every word that needed more than this many guesses.
+        #[clap(long, value_name = "THRESHOLD")]
+        list_hard: Option<usize>,
+        /// If set, writes the number of guesses needed for each word to this CSV file.
+        #[clap(long, value_name = "FILE")]
+        csv: Option<String>,
     },
+    /// Print the summary statistics from a benchmark CSV file, without re-running the benchmark.
+    Replay { csv_file: String },
     /// Run a single game with the given word.
     Single { word: String },
     /// Run an interactive game against the solver.
-    Interactive,
+    Interactive {
+        /// Either "solve", where the solver guesses your word, or "assist-human", where you make
+        /// the guesses and the solver computes the feedback and suggests guesses.
+        #[clap(long, default_value = "solve")]
+        mode: InteractiveMode,
+    },
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -107,14 +156,37 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("There are {} possible words.", word_bank.len());
 
     match args.command {
-        Command::Benchmark { bench_file } => {
-            run_benchmark(word_bank, args.guesser_impl, args.guess_from, &bench_file)?
+        Command::Benchmark {
+            bench_file,
+            group_by_difficulty,
+            list_hard,
+            csv,
+        } => run_benchmark(
+            word_bank,
+            args.guesser_impl,
+            args.guess_from,
+            &bench_file,
+            &ReportOptions {
+                group_by_difficulty,
+                hard_threshold: list_hard,
+                csv_file: csv,
+            },
+        )?,
+        Command::Replay { csv_file } => {
+            let statistics = stats_from_csv(io::BufReader::new(File::open(csv_file)?))?;
+            println!("Replayed {} words. Results:", statistics.num_games());
+            print!("{}", statistics);
+            println!(
+                "\n**Average number of guesses:** {:.2} +/- {:.2}",
+                statistics.mean_guesses(),
+                statistics.std_dev()
+            );
         }
         Command::Single { word } => {
             play_single_game(&word, word_bank, args.guesser_impl, args.guess_from)?
         }
-        Command::Interactive => {
-            play_interactive_game(word_bank, args.guesser_impl, args.guess_from)?
+        Command::Interactive { mode } => {
+            play_interactive_game(word_bank, args.guesser_impl, args.guess_from, mode)?
         }
     }
 
@@ -126,7 +198,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Optional extra output to print after a benchmark.
+struct ReportOptions {
+    /// Whether to print example words for each number of guesses.
+    group_by_difficulty: bool,
+    /// If set, all words that needed more than this many guesses are printed.
+    hard_threshold: Option<usize>,
+    /// If set, the result for each word is written to this CSV file.
+    csv_file: Option<String>,
+}
+
+const NUM_EXAMPLE_WORDS_PER_DIFFICULTY: usize = 10;
+
 struct TimedGameResult {
+    word: Arc<str>,
     duration: Duration,
     game_result: GameResult,
 }
@@ -136,8 +221,8 @@ fn run_benchmark(
     guesser_impl: GuesserImpl,
     guess_from: GuessFrom,
     bench_file: &str,
+    report_options: &ReportOptions,
 ) -> Result<(), WordleError> {
-    let mut num_guesses_per_game: Vec<u32> = Vec::new();
     let mut second_guess_count: HashMap<Box<str>, u32> = HashMap::new();
     let mut third_guess_count: HashMap<Box<str>, u32> = HashMap::new();
     let bench_words_reader = io::BufReader::new(File::open(bench_file)?);
@@ -152,7 +237,7 @@ fn run_benchmark(
             .filter(|maybe_word| {
                 maybe_word
                     .as_ref()
-                    .map_or(true, |word: &Arc<str>| word.len() > 0)
+                    .map_or(true, |word: &Arc<str>| !word.is_empty())
             })
             .collect::<Result<Vec<Arc<str>>, WordleError>>()?,
     );
@@ -164,7 +249,7 @@ fn run_benchmark(
             .collect(),
     );
 
-    let results = benchmark_words(&bench_words, all_words, guesser_impl, guess_from);
+    let results = benchmark_words(&bench_words, all_words, guesser_impl, guess_from)?;
 
     let mut first_guess: Box<str> = Box::from("");
     let possible_word_buckets = vec![1, 2, 4, 8, 16, 32, 64, 96, 128, 256, 512, 1024, 2048];
@@ -178,13 +263,12 @@ fn run_benchmark(
     let mut possible_words_count_3_from_end = vec![0; 13];
     for result in results.iter().map(|timed_result| &timed_result.game_result) {
         if let GameResult::Success(data) = result {
-            num_guesses_per_game.push(data.turns.len() as u32);
             first_guess = data.turns[0].guess.clone();
-            if data.turns.len() > 1 {
+            if data.num_turns() > 1 {
                 *second_guess_count
                     .entry(data.turns[1].guess.clone())
                     .or_default() += 1;
-                if data.turns.len() > 2 {
+                if data.num_turns() > 2 {
                     *third_guess_count
                         .entry(data.turns[2].guess.clone())
                         .or_default() += 1;
@@ -201,7 +285,7 @@ fn run_benchmark(
                     .enumerate()
                     .filter(|(_, bucket_min)| **bucket_min <= num_possible_words)
                     .map(|(bucket_index, _)| bucket_index)
-                    .last()
+                    .next_back()
                     .unwrap();
                 match index {
                     0 => possible_words_count_0[bucket_index] += 1,
@@ -223,7 +307,7 @@ fn run_benchmark(
                     .enumerate()
                     .filter(|(_, bucket_min)| **bucket_min <= num_possible_words)
                     .map(|(bucket_index, _)| bucket_index)
-                    .last()
+                    .next_back()
                     .unwrap();
                 match index {
                     0 => possible_words_count_0_from_end[bucket_index] += 1,
@@ -238,22 +322,8 @@ fn run_benchmark(
 
     println!("Solved {} words. Results:", num_bench_words);
 
-    let mut num_games_per_round: HashMap<u32, u32> = HashMap::new();
-    for num_guesses in num_guesses_per_game.iter() {
-        *(num_games_per_round.entry(*num_guesses).or_insert(0)) += 1;
-    }
-
-    println!("|Num guesses|Num games|");
-    println!("|-----------|---------|");
-    let mut num_rounds = Vec::from_iter(num_games_per_round.keys().copied());
-    num_rounds.sort_unstable();
-    for num_round in num_rounds.iter() {
-        println!(
-            "|{}|{}|",
-            num_round,
-            num_games_per_round.get(num_round).unwrap()
-        );
-    }
+    let statistics = GameStats::from_results(results.iter().map(|result| &result.game_result));
+    print!("{}", statistics);
 
     println!("\nNum possible words remaining:");
 
@@ -327,22 +397,10 @@ fn run_benchmark(
     println!("Top third guesses:");
     print_top_n(third_guess_count, 10);
 
-    let average: f64 = num_games_per_round
-        .iter()
-        .fold(0, |acc, (num_guesses, num_games)| {
-            acc + (num_guesses * num_games)
-        }) as f64
-        / num_guesses_per_game.len() as f64;
-    let std_dev: f64 = (num_guesses_per_game
-        .iter()
-        .map(|num_guesses| (*num_guesses as f64 - average).powi(2))
-        .sum::<f64>()
-        / num_guesses_per_game.len() as f64)
-        .sqrt();
-
     println!(
         "\n**Average number of guesses:** {:.2} +/- {:.2}",
-        average, std_dev
+        statistics.mean_guesses(),
+        statistics.std_dev()
     );
 
     let duration_sum: Duration = results
@@ -365,9 +423,123 @@ fn run_benchmark(
         std_dev_duration_us / 1000.0
     );
 
+    if report_options.group_by_difficulty {
+        println!("\nExample words by number of guesses:");
+        for (num_guesses, words) in words_by_num_guesses(&results) {
+            let examples: Vec<&str> = words
+                .iter()
+                .take(NUM_EXAMPLE_WORDS_PER_DIFFICULTY)
+                .copied()
+                .collect();
+            println!(
+                "Words that took {} guesses ({} total): {}",
+                num_guesses,
+                words.len(),
+                examples.join(", ")
+            );
+        }
+    }
+
+    if let Some(threshold) = report_options.hard_threshold {
+        let hard = hard_words(&results, threshold);
+        println!(
+            "\n{} words took more than {} guesses:",
+            hard.len(),
+            threshold
+        );
+        for word in hard {
+            println!("\t{}", word);
+        }
+    }
+
+    if let Some(csv_file) = &report_options.csv_file {
+        write_csv(&results, io::BufWriter::new(File::create(csv_file)?))?;
+        println!("\nWrote results to {}", csv_file);
+    }
+
     Ok(())
 }
 
+const CSV_HEADER: &str = "word,num_guesses,solved";
+
+/// Writes the number of guesses used for each benchmarked word as `word,num_guesses,solved` rows,
+/// after a header row.
+fn write_csv<W: Write>(results: &[TimedGameResult], mut writer: W) -> io::Result<()> {
+    writeln!(writer, "{}", CSV_HEADER)?;
+    for result in results {
+        let num_guesses = result
+            .game_result
+            .game_data()
+            .map_or(0, GameData::num_turns);
+        writeln!(
+            writer,
+            "{},{},{}",
+            result.word,
+            num_guesses,
+            result.game_result.is_success()
+        )?;
+    }
+    writer.flush()
+}
+
+/// Reconstructs the statistics from a CSV file written by [`write_csv()`].
+fn stats_from_csv<R: BufRead>(reader: R) -> Result<GameStats, WordleError> {
+    let mut stats = GameStats::default();
+    for maybe_line in reader.lines() {
+        let line = maybe_line?;
+        let line = line.trim();
+        if line.is_empty() || line == CSV_HEADER {
+            continue;
+        }
+        let invalid_line = || {
+            WordleError::IoError(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("expected a `{}` row, found: {}", CSV_HEADER, line),
+            ))
+        };
+        let fields: Vec<&str> = line.split(',').collect();
+        let (num_guesses, solved) = match fields.as_slice() {
+            [_, num_guesses, solved] => (
+                num_guesses.parse::<u32>().map_err(|_| invalid_line())?,
+                solved.parse::<bool>().map_err(|_| invalid_line())?,
+            ),
+            _ => return Err(invalid_line()),
+        };
+        if solved {
+            stats.add_solved(num_guesses);
+        } else {
+            stats.num_unsolved += 1;
+        }
+    }
+    Ok(stats)
+}
+
+/// Groups the benchmarked words by the number of guesses needed to solve them, in increasing order
+/// of difficulty. Games that were not solved are skipped.
+fn words_by_num_guesses(results: &[TimedGameResult]) -> BTreeMap<usize, Vec<&str>> {
+    let mut words_by_num_guesses: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+    for result in results {
+        if let GameResult::Success(data) = &result.game_result {
+            words_by_num_guesses
+                .entry(data.num_turns())
+                .or_default()
+                .push(result.word.as_ref());
+        }
+    }
+    words_by_num_guesses
+}
+
+/// Returns the benchmarked words that needed more than `threshold` guesses to solve, from hardest
+/// to easiest.
+fn hard_words(results: &[TimedGameResult], threshold: usize) -> Vec<&str> {
+    words_by_num_guesses(results)
+        .into_iter()
+        .rev()
+        .take_while(|(num_guesses, _)| *num_guesses > threshold)
+        .flat_map(|(_, words)| words)
+        .collect()
+}
+
 fn benchmark_guesser<G: Guesser + Clone>(
     preconstruction_start: Instant,
     words_to_bench: &[Arc<str>],
@@ -381,13 +553,23 @@ fn benchmark_guesser<G: Guesser + Clone>(
     for word in words_to_bench.iter() {
         let start = Instant::now();
         let max_num_guesses = 128;
-        let result = play_game_with_guesser(word, max_num_guesses, guesser.clone());
-        if let GameResult::Success(data) = &result {
-            println!("Solved {} in {} guesses", word, data.turns.len());
-        } else {
-            panic!("Failed to guess word: {}. Error: {:?}", word, result);
+        // Errors are counted as unsolved games, so one bad word doesn't stop the benchmark.
+        let result = match play_game_with_guesser(word, max_num_guesses, guesser.clone()) {
+            Ok(result) => result,
+            Err(err) => {
+                println!("Failed to guess word: {}. Error: {}", word, err);
+                GameResult::Failure(GameData { turns: Vec::new() })
+            }
+        };
+        match &result {
+            GameResult::Success(data) => {
+                println!("Solved {} in {} guesses", word, data.num_turns())
+            }
+            GameResult::Failure(data) if data.turns.is_empty() => {}
+            _ => println!("Failed to guess word: {}. Result: {:?}", word, result),
         }
         results.push(TimedGameResult {
+            word: Arc::clone(word),
             duration: start.elapsed(),
             game_result: result,
         });
@@ -400,10 +582,10 @@ fn benchmark_words(
     all_words: Arc<Vec<Arc<str>>>,
     guesser_impl: GuesserImpl,
     guess_from: GuessFrom,
-) -> Vec<TimedGameResult> {
-    let word_bank: WordBank = WordBank::from_iterator(all_words.iter()).unwrap();
+) -> Result<Vec<TimedGameResult>, WordleError> {
+    let word_bank: WordBank = WordBank::from_iterator(all_words.iter())?;
     let preconstruction_start = Instant::now();
-    match guesser_impl {
+    let results = match guesser_impl {
         GuesserImpl::Random => benchmark_guesser(
             preconstruction_start,
             words_to_bench,
@@ -421,6 +603,12 @@ fn benchmark_words(
             guesser.compute_scores_if_unknown();
             benchmark_guesser(preconstruction_start, words_to_bench, guesser)
         }
+        GuesserImpl::PositionalLetters => {
+            let scorer = PositionalLetterFrequencyScorer::new(&word_bank);
+            let mut guesser = MaxScoreGuesser::new(guess_from.into(), word_bank, scorer);
+            guesser.compute_scores_if_unknown();
+            benchmark_guesser(preconstruction_start, words_to_bench, guesser)
+        }
         GuesserImpl::ApproximateEliminations => {
             let scorer = MaxApproximateEliminationsScorer::new(&word_bank);
             let mut guesser = MaxScoreGuesser::new(guess_from.into(), word_bank, scorer);
@@ -428,7 +616,19 @@ fn benchmark_words(
             benchmark_guesser(preconstruction_start, words_to_bench, guesser)
         }
         GuesserImpl::MaxEliminations => {
-            let scorer = MaxEliminationsScorer::new(word_bank.clone());
+            let scorer = MaxEliminationsScorer::new(word_bank.clone())?;
+            let mut guesser = MaxScoreGuesser::new(guess_from.into(), word_bank, scorer);
+            guesser.compute_scores_if_unknown();
+            benchmark_guesser(preconstruction_start, words_to_bench, guesser)
+        }
+        GuesserImpl::MaxEntropy => {
+            let scorer = MaxEntropyScorer::new(word_bank.clone())?;
+            let mut guesser = MaxScoreGuesser::new(guess_from.into(), word_bank, scorer);
+            guesser.compute_scores_if_unknown();
+            benchmark_guesser(preconstruction_start, words_to_bench, guesser)
+        }
+        GuesserImpl::Minimax => {
+            let scorer = MinimaxScorer::new(word_bank.clone())?;
             let mut guesser = MaxScoreGuesser::new(guess_from.into(), word_bank, scorer);
             guesser.compute_scores_if_unknown();
             benchmark_guesser(preconstruction_start, words_to_bench, guesser)
@@ -438,13 +638,25 @@ fn benchmark_words(
                 word_bank.clone(),
                 guess_from.into(),
                 MIN_WORD_LIMIT_FOR_COMBO,
-            )
-            .unwrap();
+            )?;
             let mut guesser = MaxScoreGuesser::new(guess_from.into(), word_bank, scorer);
             guesser.compute_scores_if_unknown();
             benchmark_guesser(preconstruction_start, words_to_bench, guesser)
         }
-    }
+        GuesserImpl::MinExpectedGuesses => {
+            let scorer = MinExpectedGuessesExactScorer::new(word_bank.clone())?;
+            let mut guesser = MaxScoreGuesser::new(guess_from.into(), word_bank, scorer);
+            guesser.compute_scores_if_unknown();
+            benchmark_guesser(preconstruction_start, words_to_bench, guesser)
+        }
+        GuesserImpl::ApproxExpectedGuesses => {
+            let scorer = MinExpectedGuessesApproximateScorer::new(word_bank.clone())?;
+            let mut guesser = MaxScoreGuesser::new(guess_from.into(), word_bank, scorer);
+            guesser.compute_scores_if_unknown();
+            benchmark_guesser(preconstruction_start, words_to_bench, guesser)
+        }
+    };
+    Ok(results)
 }
 
 fn print_top_n(guess_count: HashMap<Box<str>, u32>, n: usize) {
@@ -497,6 +709,15 @@ fn play_single_game(
                 LocatedLettersScorer::new(&word_bank),
             ),
         ),
+        GuesserImpl::PositionalLetters => play_game_with_guesser(
+            word,
+            max_num_guesses,
+            MaxScoreGuesser::new(
+                guess_from.into(),
+                word_bank.clone(),
+                PositionalLetterFrequencyScorer::new(&word_bank),
+            ),
+        ),
         GuesserImpl::ApproximateEliminations => play_game_with_guesser(
             word,
             max_num_guesses,
@@ -512,7 +733,25 @@ fn play_single_game(
             MaxScoreGuesser::new(
                 guess_from.into(),
                 word_bank.clone(),
-                MaxEliminationsScorer::new(word_bank),
+                MaxEliminationsScorer::new(word_bank)?,
+            ),
+        ),
+        GuesserImpl::MaxEntropy => play_game_with_guesser(
+            word,
+            max_num_guesses,
+            MaxScoreGuesser::new(
+                guess_from.into(),
+                word_bank.clone(),
+                MaxEntropyScorer::new(word_bank)?,
+            ),
+        ),
+        GuesserImpl::Minimax => play_game_with_guesser(
+            word,
+            max_num_guesses,
+            MaxScoreGuesser::new(
+                guess_from.into(),
+                word_bank.clone(),
+                MinimaxScorer::new(word_bank)?,
             ),
         ),
         GuesserImpl::MaxComboEliminations => play_game_with_guesser(
@@ -528,10 +767,28 @@ fn play_single_game(
                 )?,
             ),
         ),
+        GuesserImpl::MinExpectedGuesses => play_game_with_guesser(
+            word,
+            max_num_guesses,
+            MaxScoreGuesser::new(
+                guess_from.into(),
+                word_bank.clone(),
+                MinExpectedGuessesExactScorer::new(word_bank)?,
+            ),
+        ),
+        GuesserImpl::ApproxExpectedGuesses => play_game_with_guesser(
+            word,
+            max_num_guesses,
+            MaxScoreGuesser::new(
+                guess_from.into(),
+                word_bank.clone(),
+                MinExpectedGuessesApproximateScorer::new(word_bank)?,
+            ),
+        ),
     };
-    match result {
+    match result? {
         GameResult::Success(data) => {
-            println!("Solved it! It took me {} guesses.", data.turns.len());
+            println!("Solved it! It took me {} guesses.", data.num_turns());
             for guess in data.turns.iter().map(|turn| &turn.guess) {
                 println!("\t{}", guess);
             }
@@ -539,12 +796,18 @@ fn play_single_game(
         GameResult::Failure(data) => {
             println!(
                 "I still couldn't solve it after {} guesses :(",
-                data.turns.len()
+                data.num_turns()
             );
             for guess in data.turns.iter().map(|turn| &turn.guess) {
                 println!("\t{}", guess);
             }
         }
+        GameResult::TimedOut(data) => {
+            println!("I ran out of time after {} guesses :(", data.num_turns());
+            for guess in data.turns.iter().map(|turn| &turn.guess) {
+                println!("\t{}", guess);
+            }
+        }
         GameResult::UnknownWord => {
             eprintln!("Error: given word not in the word list.");
             std::process::exit(1);
@@ -557,35 +820,71 @@ fn play_interactive_game(
     word_bank: WordBank,
     guesser_impl: GuesserImpl,
     guess_from: GuessFrom,
+    mode: InteractiveMode,
 ) -> Result<(), Box<dyn std::error::Error>> {
     match guesser_impl {
-        GuesserImpl::Random => play_interactive_game_with_guesser(RandomGuesser::new(word_bank)),
-        GuesserImpl::UniqueLetterFrequency => {
-            play_interactive_game_with_guesser(MaxScoreGuesser::new(
+        GuesserImpl::Random => {
+            play_interactive_mode_with_guesser(mode, RandomGuesser::new(word_bank))
+        }
+        GuesserImpl::UniqueLetterFrequency => play_interactive_mode_with_guesser(
+            mode,
+            MaxScoreGuesser::new(
                 guess_from.into(),
                 word_bank.clone(),
                 MaxUniqueLetterFrequencyScorer::new(&word_bank),
-            ))
-        }
-        GuesserImpl::LocatedLetters => play_interactive_game_with_guesser(MaxScoreGuesser::new(
-            guess_from.into(),
-            word_bank.clone(),
-            LocatedLettersScorer::new(&word_bank),
-        )),
-        GuesserImpl::ApproximateEliminations => {
-            play_interactive_game_with_guesser(MaxScoreGuesser::new(
+            ),
+        ),
+        GuesserImpl::LocatedLetters => play_interactive_mode_with_guesser(
+            mode,
+            MaxScoreGuesser::new(
+                guess_from.into(),
+                word_bank.clone(),
+                LocatedLettersScorer::new(&word_bank),
+            ),
+        ),
+        GuesserImpl::PositionalLetters => play_interactive_mode_with_guesser(
+            mode,
+            MaxScoreGuesser::new(
+                guess_from.into(),
+                word_bank.clone(),
+                PositionalLetterFrequencyScorer::new(&word_bank),
+            ),
+        ),
+        GuesserImpl::ApproximateEliminations => play_interactive_mode_with_guesser(
+            mode,
+            MaxScoreGuesser::new(
                 guess_from.into(),
                 word_bank.clone(),
                 MaxApproximateEliminationsScorer::new(&word_bank),
-            ))
-        }
-        GuesserImpl::MaxEliminations => play_interactive_game_with_guesser(MaxScoreGuesser::new(
-            guess_from.into(),
-            word_bank.clone(),
-            MaxEliminationsScorer::new(word_bank),
-        )),
-        GuesserImpl::MaxComboEliminations => {
-            play_interactive_game_with_guesser(MaxScoreGuesser::new(
+            ),
+        ),
+        GuesserImpl::MaxEliminations => play_interactive_mode_with_guesser(
+            mode,
+            MaxScoreGuesser::new(
+                guess_from.into(),
+                word_bank.clone(),
+                MaxEliminationsScorer::new(word_bank)?,
+            ),
+        ),
+        GuesserImpl::MaxEntropy => play_interactive_mode_with_guesser(
+            mode,
+            MaxScoreGuesser::new(
+                guess_from.into(),
+                word_bank.clone(),
+                MaxEntropyScorer::new(word_bank)?,
+            ),
+        ),
+        GuesserImpl::Minimax => play_interactive_mode_with_guesser(
+            mode,
+            MaxScoreGuesser::new(
+                guess_from.into(),
+                word_bank.clone(),
+                MinimaxScorer::new(word_bank)?,
+            ),
+        ),
+        GuesserImpl::MaxComboEliminations => play_interactive_mode_with_guesser(
+            mode,
+            MaxScoreGuesser::new(
                 guess_from.into(),
                 word_bank.clone(),
                 MaxComboEliminationsScorer::new(
@@ -593,13 +892,39 @@ fn play_interactive_game(
                     guess_from.into(),
                     MIN_WORD_LIMIT_FOR_COMBO,
                 )?,
-            ))
-        }
+            ),
+        ),
+        GuesserImpl::MinExpectedGuesses => play_interactive_mode_with_guesser(
+            mode,
+            MaxScoreGuesser::new(
+                guess_from.into(),
+                word_bank.clone(),
+                MinExpectedGuessesExactScorer::new(word_bank)?,
+            ),
+        ),
+        GuesserImpl::ApproxExpectedGuesses => play_interactive_mode_with_guesser(
+            mode,
+            MaxScoreGuesser::new(
+                guess_from.into(),
+                word_bank.clone(),
+                MinExpectedGuessesApproximateScorer::new(word_bank)?,
+            ),
+        ),
     }
     .map_err(Box::from)
 }
 
-fn play_interactive_game_with_guesser(mut guesser: impl Guesser) -> io::Result<()> {
+fn play_interactive_mode_with_guesser(
+    mode: InteractiveMode,
+    guesser: impl Guesser,
+) -> io::Result<()> {
+    match mode {
+        InteractiveMode::Solve => play_interactive_game_with_guesser(guesser),
+        InteractiveMode::AssistHuman => assist_human_with_guesser(guesser),
+    }
+}
+
+fn play_interactive_game_with_guesser(guesser: impl Guesser) -> io::Result<()> {
     println!("Choose a word from the word-list. Press enter once you've chosen.");
     {
         let mut buffer = String::new();
@@ -614,41 +939,115 @@ fn play_interactive_game_with_guesser(mut guesser: impl Guesser) -> io::Result<(
            * 'g' = this letter is in the word and in the right location.\n\n\
          For example, if your word was \"spade\" and the guess was \"soapy\", you would enter \"g.gy.\"");
 
-    for round in 1..7 {
-        let guess = guesser.select_next_guess().unwrap();
+    let mut solver = InteractiveSolver::new(guesser);
+    for _ in 1..7 {
+        let guess = match solver.next_guess() {
+            Some(guess) => guess,
+            None => break,
+        };
         println!("I'm guessing: {}. How did I do?", guess);
 
-        let mut result = get_result_for_guess(guess.as_ref());
-        while result.is_err() {
-            println!("{}", result.unwrap_err());
-            result = get_result_for_guess(guess.as_ref());
+        let status = loop {
+            match get_result_for_guess(guess.as_ref())
+                .map_err(WordleError::IoError)
+                .and_then(|result| solver.submit_result(&result.results))
+            {
+                Ok(status) => break status,
+                Err(e) => println!("{}", e),
+            }
+        };
+
+        if status == GameStatus::Won {
+            println!("I did it! It took me {} guesses.", solver.num_guesses());
+            return Ok(());
         }
+    }
+
+    println!("I couldn't guess it :(");
+
+    Ok(())
+}
 
-        let result = result.unwrap();
+fn assist_human_with_guesser(mut guesser: impl Guesser) -> io::Result<()> {
+    println!("Enter the hidden answer (or have someone else enter it), then press enter.");
+    let answer = {
+        let mut buffer = String::new();
+        io::stdin().read_line(&mut buffer)?;
+        buffer.trim().to_lowercase()
+    };
 
-        if result
-            .results
+    println!(
+        "Now enter your guesses. For each guess, I will show the correctness of each letter as:\n\n\
+           * '.' = this letter is not in the word\n\
+           * 'y' = this letter is in the word, but not in this location\n\
+           * 'g' = this letter is in the word and in the right location."
+    );
+
+    for round in 1..7 {
+        if let Some(suggestion) = guesser.select_next_guess() {
+            println!("I suggest guessing: {}", suggestion);
+        }
+        println!("What's your guess?");
+        let results = loop {
+            let mut buffer = String::new();
+            if io::stdin().read_line(&mut buffer)? == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "no more guesses to read",
+                ));
+            }
+            let guess = buffer.trim().to_lowercase();
+            match assist_turn(&mut guesser, &answer, &guess) {
+                Ok(result) => break result.results,
+                Err(e) => println!("{} Try again.", e),
+            }
+        };
+        println!("{}", format_letter_results(&results));
+
+        if results
             .iter()
             .all(|letter_result| *letter_result == LetterResult::Correct)
         {
-            println!("I did it! It took me {} guesses.", round);
+            println!("You did it! It took you {} guesses.", round);
             return Ok(());
         }
-
-        guesser.update(&result).unwrap();
     }
 
-    println!("I couldn't guess it :(");
+    println!("Out of guesses! The answer was: {}", answer);
 
     Ok(())
 }
 
-fn get_result_for_guess(guess: &str) -> io::Result<GuessResult> {
+/// Computes the feedback for a human's guess against the known answer, and updates the guesser
+/// with it so it can suggest the next guess.
+fn assist_turn<'a>(
+    guesser: &mut impl Guesser,
+    answer: &str,
+    guess: &'a str,
+) -> Result<GuessResult<'a>, WordleError> {
+    let result = rs_wordle_solver::get_result_for_guess(answer, guess)?;
+    guesser.update(&result)?;
+    Ok(result)
+}
+
+/// Formats letter results in the same form that is used for input: '.', 'y', or 'g'.
+fn format_letter_results(results: &[LetterResult]) -> String {
+    results
+        .iter()
+        .map(|letter_result| match letter_result {
+            LetterResult::NotPresent => '.',
+            LetterResult::PresentNotHere => 'y',
+            LetterResult::Correct => 'g',
+        })
+        .collect()
+}
+
+fn get_result_for_guess(guess: &str) -> io::Result<GuessResult<'_>> {
     let mut buffer = String::new();
     io::stdin().read_line(&mut buffer)?;
     let input = buffer.trim();
 
-    if guess.len() != input.len() {
+    if guess.chars().count() != input.chars().count() {
         return Err(io::Error::new(
             io::ErrorKind::InvalidInput,
             format!(
@@ -660,17 +1059,125 @@ fn get_result_for_guess(guess: &str) -> io::Result<GuessResult> {
 
     Ok(GuessResult {
         guess,
-        results: input
-            .char_indices()
-            .map(|(_, letter)| match letter {
-                '.' => Ok(LetterResult::NotPresent),
-                'y' => Ok(LetterResult::PresentNotHere),
-                'g' => Ok(LetterResult::Correct),
-                _ => Err(io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    "Must enter only the letters '.', 'y', or 'g'. Try again.",
-                )),
-            })
-            .collect::<io::Result<Vec<LetterResult>>>()?,
+        results: parse_result_string(input).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Must enter only the letters '.', 'y', or 'g'. Try again.",
+            )
+        })?,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timed_success(word: &str, guesses: &[&str]) -> TimedGameResult {
+        TimedGameResult {
+            word: Arc::from(word),
+            duration: Duration::ZERO,
+            game_result: GameResult::Success(GameData {
+                turns: guesses
+                    .iter()
+                    .map(|guess| TurnData {
+                        guess: Box::from(*guess),
+                        num_possible_words_before_guess: 1,
+                        restrictions: None,
+                    })
+                    .collect(),
+            }),
+        }
+    }
+
+    #[test]
+    fn hard_words_are_above_threshold() {
+        let results = vec![
+            timed_success("abc", &["abc"]),
+            timed_success("bcd", &["abc", "def", "bcd"]),
+            timed_success("cde", &["abc", "cde"]),
+            timed_success("def", &["abc", "bcd", "cde", "def"]),
+        ];
+
+        assert_eq!(hard_words(&results, 2), vec!["def", "bcd"]);
+        assert_eq!(hard_words(&results, 4), Vec::<&str>::new());
+        assert_eq!(
+            words_by_num_guesses(&results),
+            BTreeMap::from([
+                (1, vec!["abc"]),
+                (2, vec!["cde"]),
+                (3, vec!["bcd"]),
+                (4, vec!["def"]),
+            ])
+        );
+    }
+
+    #[test]
+    fn statistics_csv_round_trip() -> Result<(), WordleError> {
+        let results = vec![
+            timed_success("abc", &["abc"]),
+            timed_success("bcd", &["abc", "def", "bcd"]),
+            timed_success("cde", &["abc", "cde"]),
+            timed_success("def", &["abc", "bcd", "def"]),
+            TimedGameResult {
+                word: Arc::from("xyz"),
+                duration: Duration::ZERO,
+                game_result: GameResult::UnknownWord,
+            },
+        ];
+        let mut csv = Vec::new();
+        write_csv(&results, &mut csv)?;
+
+        let statistics = stats_from_csv(io::Cursor::new(csv))?;
+
+        assert_eq!(
+            statistics,
+            GameStats::from_results(results.iter().map(|result| &result.game_result))
+        );
+        assert_eq!(
+            statistics.histogram,
+            BTreeMap::from([(1, 1), (2, 1), (3, 2)])
+        );
+        assert_eq!(statistics.num_unsolved, 1);
+        assert_eq!(statistics.mean_guesses(), 2.25);
+        Ok(())
+    }
+
+    #[test]
+    fn statistics_from_csv_invalid_row() {
+        let csv = "word,num_guesses,solved\nabc,two,true\n";
+
+        assert!(matches!(
+            stats_from_csv(io::Cursor::new(csv)),
+            Err(WordleError::IoError(_))
+        ));
+    }
+
+    #[test]
+    fn assist_turn_computes_feedback_and_updates_guesser() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd", "xyz"])?;
+        let mut guesser = MaxScoreGuesser::new(
+            rs_wordle_solver::GuessFrom::AllUnguessedWords,
+            bank.clone(),
+            MaxEliminationsScorer::new(bank)?,
+        );
+
+        let result = assist_turn(&mut guesser, "abd", "bcd")?;
+
+        assert_eq!(
+            result.results,
+            vec![
+                LetterResult::PresentNotHere,
+                LetterResult::NotPresent,
+                LetterResult::Correct
+            ]
+        );
+        assert_eq!(format_letter_results(&result.results), "y.g");
+        assert_eq!(guesser.possible_words(), &[Arc::from("abd")]);
+        assert_eq!(guesser.select_next_guess(), Some(Arc::from("abd")));
+        assert!(matches!(
+            assist_turn(&mut guesser, "abd", "abcd"),
+            Err(WordleError::WordLength(3))
+        ));
+        Ok(())
+    }
+}
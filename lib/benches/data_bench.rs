@@ -35,7 +35,7 @@ fn bench_word_counter_clone(b: &mut Bencher) -> Result<(), WordleError> {
 fn bench_word_tracker_clone(b: &mut Bencher) -> Result<(), WordleError> {
     let words_reader = io::BufReader::new(File::open("../data/1000-improved-words-shuffled.txt")?);
     let bank = WordBank::from_reader(words_reader)?;
-    let tracker: WordTracker = WordTracker::new(&bank);
+    let tracker: WordTracker = WordTracker::from_slice(&bank);
 
     b.iter(|| tracker.clone());
 
@@ -119,7 +119,7 @@ fn bench_max_eliminations_improved_words_with_precompute(
     let all_words = io::BufReader::new(File::open("../data/improved-words.txt")?);
 
     let bank = WordBank::from_reader(all_words)?;
-    let scorer = MaxEliminationsScorer::new(bank.clone());
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
     let mut base_guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
     base_guesser.compute_scores_if_unknown();
 
@@ -141,7 +141,7 @@ fn bench_max_eliminations_scorer_precompute_improved_words(
 ) -> std::result::Result<(), Box<dyn Error>> {
     let mut all_words = io::BufReader::new(File::open("../data/improved-words.txt")?);
     let bank = WordBank::from_reader(&mut all_words)?;
-    let scorer = MaxEliminationsScorer::new(bank.clone());
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
 
     b.iter(|| {
         let mut guesser =
@@ -162,7 +162,7 @@ macro_rules! bench_max_eliminations_with_precompute_parallelisation_limit {
             let all_words = io::BufReader::new(File::open("../data/improved-words.txt")?);
 
             let bank = WordBank::from_reader(all_words)?;
-            let scorer = MaxEliminationsScorer::new(bank.clone());
+            let scorer = MaxEliminationsScorer::new(bank.clone())?;
             let mut base_guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer)
                 .with_parallelisation_limit($limit);
             base_guesser.compute_scores_if_unknown();
@@ -204,7 +204,7 @@ fn bench_select_top_5_guesses_all(b: &mut Bencher) -> Result<(), WordleError> {
     let all_words = io::BufReader::new(File::open("../data/wordle-words.txt")?);
 
     let bank = WordBank::from_reader(all_words)?;
-    let scorer = MaxEliminationsScorer::new(bank.clone());
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
     let mut base_guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
     base_guesser.compute_scores_if_unknown();
 
@@ -225,7 +225,7 @@ fn bench_select_top_5_guesses_post_guess_all(b: &mut Bencher) -> Result<(), Word
     let mut test_word_iter = test_words.iter().cycle();
 
     let bank = WordBank::from_reader(all_words)?;
-    let scorer = MaxEliminationsScorer::new(bank.clone());
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
     let mut base_guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
     base_guesser.compute_scores_if_unknown();
 
@@ -255,7 +255,7 @@ macro_rules! bench_select_top_n_parallelisation_limit {
             let mut test_word_iter = test_words.iter().cycle();
 
             let bank = WordBank::from_reader(all_words)?;
-            let scorer = MaxEliminationsScorer::new(bank.clone());
+            let scorer = MaxEliminationsScorer::new(bank.clone())?;
             let mut base_guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer)
                 .with_parallelisation_limit($limit);
             base_guesser.compute_scores_if_unknown();
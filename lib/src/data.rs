@@ -1,5 +1,9 @@
+use crate::parallel::*;
+use crate::restrictions::WordRestrictions;
 use crate::results::*;
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt::{Debug, Display};
 use std::hash::Hash;
 use std::io;
@@ -15,7 +19,7 @@ use std::sync::Arc;
 /// let word = "abc";
 ///
 /// let mut located_letters = Vec::new();
-/// for (index, letter) in word.char_indices() {
+/// for (index, letter) in word.chars().enumerate() {
 ///    located_letters.push(LocatedLetter::new(letter, index as u8));
 /// }
 ///
@@ -39,19 +43,64 @@ impl LocatedLetter {
     }
 }
 
+/// Options controlling how words are normalized when constructing a [`WordBank`].
+///
+/// The default options trim and lower-case every word. With the `unicode` feature, words are also
+/// normalized to Unicode NFC form, and words with leftover combining characters are rejected.
+///
+/// ```
+/// use std::sync::Arc;
+/// use rs_wordle_solver::LoadOptions;
+/// use rs_wordle_solver::WordBank;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let options = LoadOptions {
+///     lowercase: false,
+///     ..LoadOptions::default()
+/// };
+/// let word_bank = WordBank::from_iterator_with_options(&["Word ", "word"], &options)?;
+///
+/// assert_eq!(&word_bank as &[Arc<str>], &[Arc::from("Word"), Arc::from("word")]);
+/// # Ok::<(), WordleError>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoadOptions {
+    /// Whether to convert each word to lower case.
+    pub lowercase: bool,
+    /// Whether to trim leading and trailing whitespace from each word.
+    pub trim: bool,
+}
+
+impl Default for LoadOptions {
+    fn default() -> Self {
+        LoadOptions {
+            lowercase: true,
+            trim: true,
+        }
+    }
+}
+
 /// Contains all the possible words for a Wordle game.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WordBank {
     pub(crate) all_words: Vec<Arc<str>>,
     word_length: usize,
+    /// The original form of each normalized word, if constructed with
+    /// [`WordBank::from_iterator_preserving_case()`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "HashMap::is_empty")
+    )]
+    original_words: HashMap<Arc<str>, Arc<str>>,
 }
 
 impl WordBank {
     /// Constructs a new `WordBank` struct by reading words from the given reader.
     ///
     /// The reader should provide one word per line. Each word will be trimmed and converted to
-    /// lower case.
+    /// lower case. A leading UTF-8 byte order mark is ignored.
     ///
     /// After trimming, all words must be the same length, else this returns an error of type
     /// [`WordleError::WordLength`].
@@ -67,16 +116,35 @@ impl WordBank {
     /// # Ok::<(), WordleError>(())
     /// ```
     pub fn from_reader<R: io::BufRead>(word_reader: R) -> Result<Self, WordleError> {
+        WordBank::from_reader_with_options(word_reader, &LoadOptions::default())
+    }
+
+    /// Constructs a new `WordBank` struct by reading words from the given reader, normalizing
+    /// each word according to the given [`LoadOptions`].
+    ///
+    /// The reader should provide one word per line, separated by either `\n` or `\r\n`. A leading
+    /// UTF-8 byte order mark is ignored. After normalizing, all words must be the same length,
+    /// else this returns an error of type [`WordleError::WordLength`].
+    pub fn from_reader_with_options<R: io::BufRead>(
+        word_reader: R,
+        options: &LoadOptions,
+    ) -> Result<Self, WordleError> {
         let mut word_length = 0;
         let all_words = word_reader
             .lines()
-            .filter_map(|maybe_word| {
+            .enumerate()
+            .filter_map(|(index, maybe_word)| {
                 maybe_word.map_or_else(
                     |err| Some(Err(WordleError::from(err))),
                     |word| {
+                        let word = if index == 0 {
+                            strip_byte_order_mark(&word)
+                        } else {
+                            &word
+                        };
                         let normalized: Option<Result<Arc<str>, WordleError>>;
                         (word_length, normalized) =
-                            WordBank::parse_word_to_arc(word_length, word.as_ref());
+                            WordBank::parse_word_to_arc(word_length, word, options, None);
                         normalized
                     },
                 )
@@ -85,6 +153,59 @@ impl WordBank {
         Ok(WordBank {
             all_words,
             word_length,
+            original_words: HashMap::new(),
+        })
+    }
+
+    /// Constructs a new `WordBank` struct by reading words from each of the given readers, as in
+    /// [`Self::from_reader()`].
+    ///
+    /// Words that appear more than once, whether in the same reader or different readers, are only
+    /// included once, in the order they first appear. All words across all readers must be the
+    /// same length, else this returns an error of type [`WordleError::WordLength`].
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::WordBank;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let allowed = Cursor::new("abc\nbcd\n");
+    /// let answers = Cursor::new("BCD\ncde\n");
+    /// let word_bank = WordBank::from_readers([allowed, answers])?;
+    ///
+    /// assert_eq!(
+    ///     &word_bank as &[Arc<str>],
+    ///     &[Arc::from("abc"), Arc::from("bcd"), Arc::from("cde")]
+    /// );
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn from_readers<R: io::BufRead>(
+        readers: impl IntoIterator<Item = R>,
+    ) -> Result<Self, WordleError> {
+        let mut word_length = 0;
+        let mut seen_words: HashSet<Arc<str>> = HashSet::new();
+        let mut all_words = Vec::new();
+        for reader in readers {
+            let bank = WordBank::from_reader(reader)?;
+            if bank.all_words.is_empty() {
+                continue;
+            }
+            if word_length == 0 {
+                word_length = bank.word_length;
+            } else if bank.word_length != word_length {
+                return Err(WordleError::WordLength(word_length));
+            }
+            for word in bank.all_words {
+                if seen_words.insert(Arc::clone(&word)) {
+                    all_words.push(word);
+                }
+            }
+        }
+        Ok(WordBank {
+            all_words,
+            word_length,
+            original_words: HashMap::new(),
         })
     }
 
@@ -106,6 +227,34 @@ impl WordBank {
     /// # Ok::<(), WordleError>(())
     /// ```
     pub fn from_iterator<S>(words: impl IntoIterator<Item = S>) -> Result<Self, WordleError>
+    where
+        S: AsRef<str>,
+    {
+        WordBank::from_iterator_with_options(words, &LoadOptions::default())
+    }
+
+    /// Constructs a new `WordBank` struct using the words from the given iterator, normalizing
+    /// each word according to the given [`LoadOptions`].
+    ///
+    /// After normalizing, all words must be the same length, else this returns an error of type
+    /// [`WordleError::WordLength`].
+    pub fn from_iterator_with_options<S>(
+        words: impl IntoIterator<Item = S>,
+        options: &LoadOptions,
+    ) -> Result<Self, WordleError>
+    where
+        S: AsRef<str>,
+    {
+        WordBank::parse_words(words, options, false)
+    }
+
+    /// Parses each word as in [`Self::parse_word_to_arc()`], stopping at the first error. If
+    /// `report_index` is true, length mismatches are reported with the index of the word.
+    fn parse_words<S>(
+        words: impl IntoIterator<Item = S>,
+        options: &LoadOptions,
+        report_index: bool,
+    ) -> Result<Self, WordleError>
     where
         S: AsRef<str>,
     {
@@ -113,37 +262,209 @@ impl WordBank {
         Ok(WordBank {
             all_words: words
                 .into_iter()
-                .filter_map(|word| {
+                .enumerate()
+                .filter_map(|(index, word)| {
                     let normalized: Option<Result<Arc<str>, WordleError>>;
-                    (word_length, normalized) =
-                        WordBank::parse_word_to_arc(word_length, word.as_ref());
+                    (word_length, normalized) = WordBank::parse_word_to_arc(
+                        word_length,
+                        word.as_ref(),
+                        options,
+                        report_index.then_some(index),
+                    );
                     normalized
                 })
                 .collect::<Result<Vec<Arc<str>>, WordleError>>()?,
             word_length,
+            original_words: HashMap::new(),
         })
     }
 
-    /// Cleans and parses the given word to an `Arc<str>`, while filtering out empty lines and
-    /// returning an error if the word's length differs from `word_length` (if non-zero).
+    /// Constructs a new `WordBank` struct using the words from the given iterator. Each word will be
+    /// trimmed, but its case is left unchanged, for puzzles where case is meaningful.
+    ///
+    /// This is equivalent to [`Self::from_iterator_with_options()`] with
+    /// [`LoadOptions::lowercase`] disabled. [`get_result_for_guess()`] compares letters exactly,
+    /// so upper and lower case letters are treated as different letters.
+    ///
+    /// After trimming, all words must be the same length, else this returns an error of type
+    /// [`WordleError::WordLength`].
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::GameResult;
+    /// use rs_wordle_solver::RandomGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::play_game_with_guesser;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let word_bank = WordBank::from_iterator_raw(&["aBc ", "abc", "ABC"])?;
+    ///
+    /// assert_eq!(
+    ///     &word_bank as &[Arc<str>],
+    ///     &[Arc::from("aBc"), Arc::from("abc"), Arc::from("ABC")]
+    /// );
+    ///
+    /// let result = play_game_with_guesser("ABC", 3, RandomGuesser::new(word_bank))?;
+    /// assert!(matches!(result, GameResult::Success(_)));
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    ///
+    /// [`get_result_for_guess()`]: crate::get_result_for_guess
+    pub fn from_iterator_raw<S>(words: impl IntoIterator<Item = S>) -> Result<Self, WordleError>
+    where
+        S: AsRef<str>,
+    {
+        WordBank::from_iterator_with_options(
+            words,
+            &LoadOptions {
+                lowercase: false,
+                ..LoadOptions::default()
+            },
+        )
+    }
+
+    /// Constructs a new `WordBank` struct using the words from the given iterator, as in
+    /// [`Self::from_iterator()`], while also keeping the trimmed original form of each word.
+    ///
+    /// Guessing and matching still use the normalized (lower case) words, but the original form
+    /// can be looked up with [`Self::original()`], e.g. to display a guess. If several words have
+    /// the same normalized form, the first original form is kept.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::WordBank;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let word_bank = WordBank::from_iterator_preserving_case(&["Rome", " Oslo "])?;
+    ///
+    /// assert_eq!(&word_bank as &[Arc<str>], &[Arc::from("rome"), Arc::from("oslo")]);
+    /// assert_eq!(word_bank.original("rome"), Some("Rome"));
+    /// assert_eq!(word_bank.original("oslo"), Some("Oslo"));
+    /// assert_eq!(word_bank.original("Rome"), None);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn from_iterator_preserving_case<S>(
+        words: impl IntoIterator<Item = S>,
+    ) -> Result<Self, WordleError>
+    where
+        S: AsRef<str>,
+    {
+        let options = LoadOptions::default();
+        let mut word_length = 0;
+        let mut all_words = Vec::new();
+        let mut original_words = HashMap::new();
+        for word in words {
+            let word = word.as_ref();
+            let normalized: Option<Result<Arc<str>, WordleError>>;
+            (word_length, normalized) =
+                WordBank::parse_word_to_arc(word_length, word, &options, None);
+            if let Some(normalized) = normalized {
+                let normalized = normalized?;
+                original_words
+                    .entry(Arc::clone(&normalized))
+                    .or_insert_with(|| Arc::from(word.trim()));
+                all_words.push(normalized);
+            }
+        }
+        Ok(WordBank {
+            all_words,
+            word_length,
+            original_words,
+        })
+    }
+
+    /// Constructs a new `WordBank` struct using the words from the given iterator, as in
+    /// [`Self::from_iterator()`], but reports which word had the wrong length.
+    ///
+    /// Like [`Self::from_iterator()`], this stops reading at the first word that can't be added.
+    /// If a word's length differs from the first word's length, this returns a
+    /// [`WordleError::WordLengthAt`] error with the index of that word in the iterator. Empty
+    /// words are skipped, but still count towards the index.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::WordBank;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let word_bank = WordBank::try_from_iter(["abc", "", "DEF"])?;
+    /// assert_eq!(&word_bank as &[Arc<str>], &[Arc::from("abc"), Arc::from("def")]);
+    ///
+    /// assert_eq!(
+    ///     WordBank::try_from_iter(["abc", "", "defg", "ghi"]).unwrap_err(),
+    ///     WordleError::WordLengthAt {
+    ///         expected: 3,
+    ///         got: 4,
+    ///         index: 2
+    ///     }
+    /// );
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn try_from_iter<S>(words: impl IntoIterator<Item = S>) -> Result<Self, WordleError>
+    where
+        S: AsRef<str>,
+    {
+        WordBank::parse_words(words, &LoadOptions::default(), true)
+    }
+
+    /// Cleans and parses the given word to an `Arc<str>` according to the `options`, while
+    /// filtering out empty lines and returning an error if the word's length differs from
+    /// `word_length` (if non-zero). The error is a [`WordleError::WordLengthAt`] if the word's
+    /// `index` is given, else a [`WordleError::WordLength`].
     ///
     /// Returns the new `word_length` to use (if `word_length` was zero before), and the parsed
     /// word.
     fn parse_word_to_arc(
         word_length: usize,
         word: &str,
+        options: &LoadOptions,
+        index: Option<usize>,
     ) -> (usize, Option<Result<Arc<str>, WordleError>>) {
-        let normalized: Arc<str> = Arc::from(word.trim().to_lowercase().as_str());
-        let this_word_length = normalized.len();
+        let word = if options.trim { word.trim() } else { word };
+        let word: Cow<str> = if options.lowercase {
+            Cow::Owned(word.to_lowercase())
+        } else {
+            Cow::Borrowed(word)
+        };
+        let normalized: Arc<str> = match normalize_unicode(&word) {
+            Ok(normalized) => Arc::from(normalized.as_ref()),
+            Err(err) => return (word_length, Some(Err(err))),
+        };
+        let this_word_length = normalized.chars().count();
         if this_word_length == 0 {
             return (word_length, None);
         }
         if word_length != 0 && word_length != this_word_length {
-            return (word_length, Some(Err(WordleError::WordLength(word_length))));
+            let err = match index {
+                Some(index) => WordleError::WordLengthAt {
+                    expected: word_length,
+                    got: this_word_length,
+                    index,
+                },
+                None => WordleError::WordLength(word_length),
+            };
+            return (word_length, Some(Err(err)));
         }
         (this_word_length, Some(Ok(normalized)))
     }
 
+    /// Returns the original form of the given normalized word, if this bank was constructed with
+    /// [`Self::from_iterator_preserving_case()`] and contains the word. See that function for an
+    /// example.
+    pub fn original(&self, normalized: &str) -> Option<&str> {
+        self.original_words
+            .get(normalized)
+            .map(|word| word.as_ref())
+    }
+
+    /// Computes the [`WordMasks`] for each word in this bank, in the same order as the words.
+    /// See [`WordMasks::filter()`] for an example.
+    pub fn word_masks(&self) -> Vec<WordMasks> {
+        self.all_words
+            .iter()
+            .map(|word| WordMasks::new(Arc::clone(word)))
+            .collect()
+    }
+
     /// Returns the number of possible words.
     #[inline]
     pub fn len(&self) -> usize {
@@ -156,11 +477,124 @@ impl WordBank {
         self.all_words.is_empty()
     }
 
-    /// Returns the length of each word in the word bank.
+    /// Returns the length of each word in the word bank, in characters.
     #[inline]
     pub fn word_length(&self) -> usize {
         self.word_length
     }
+
+    /// Removes all words for which the predicate returns false, keeping the remaining words in
+    /// their original order. The word length is unchanged, even if all words are removed.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::WordBank;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let mut word_bank = WordBank::from_iterator(&["abc", "bcd", "cde"])?;
+    /// word_bank.retain(|word| !word.starts_with('b'));
+    ///
+    /// assert_eq!(&word_bank as &[Arc<str>], &[Arc::from("abc"), Arc::from("cde")]);
+    /// assert_eq!(word_bank.word_length(), 3);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&str) -> bool,
+    {
+        self.all_words.retain(|word| predicate(word));
+        if !self.original_words.is_empty() {
+            let remaining_words: HashSet<&Arc<str>> = self.all_words.iter().collect();
+            self.original_words
+                .retain(|word, _| remaining_words.contains(word));
+        }
+    }
+
+    /// Returns a copy of this bank without the given words. Words are compared exactly, so they
+    /// should be normalized the same way as the words in this bank (e.g. lower case). Words that
+    /// aren't in this bank are ignored.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::WordBank;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let word_bank = WordBank::from_iterator(&["abc", "bcd", "cde"])?;
+    /// let filtered_bank = word_bank.without_words(&["bcd", "xyz"]);
+    ///
+    /// assert_eq!(&filtered_bank as &[Arc<str>], &[Arc::from("abc"), Arc::from("cde")]);
+    /// assert_eq!(word_bank.len(), 3);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn without_words(&self, words: &[&str]) -> WordBank {
+        let words_to_remove: HashSet<&str> = words.iter().copied().collect();
+        let mut bank = self.clone();
+        bank.retain(|word| !words_to_remove.contains(word));
+        bank
+    }
+
+    /// Lazily iterates over the words in this bank that satisfy the given restrictions, in their
+    /// original order.
+    ///
+    /// ```
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::details::WordRestrictionsBuilder;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let word_bank = WordBank::from_iterator(&["abc", "bcd", "cbe", "dbf"])?;
+    /// let restrictions = WordRestrictionsBuilder::new(3)
+    ///     .with_correct('b', 1)
+    ///     .with_absent('d')
+    ///     .build()?;
+    ///
+    /// let matches: Vec<&str> = word_bank.matching(&restrictions).map(|word| word.as_ref()).collect();
+    ///
+    /// assert_eq!(matches, vec!["abc", "cbe"]);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn matching<'a>(
+        &'a self,
+        restrictions: &'a WordRestrictions,
+    ) -> impl Iterator<Item = &'a Arc<str>> + 'a {
+        self.all_words
+            .iter()
+            .filter(|word| restrictions.is_satisfied_by(word))
+    }
+}
+
+/// Word lists with at least this many words are counted and tracked in parallel.
+const PARALLEL_CONSTRUCTION_THRESHOLD: usize = 1000;
+
+/// Composes the word into Unicode NFC form, so that each accented letter is a single `char`.
+///
+/// Returns a [`WordleError::IoError`] if the word still contains a combining character, since it
+/// can't be compared letter by letter.
+#[cfg(feature = "unicode")]
+fn normalize_unicode(word: &str) -> Result<Cow<'_, str>, WordleError> {
+    use unicode_normalization::char::is_combining_mark;
+    use unicode_normalization::UnicodeNormalization;
+
+    let normalized: String = word.nfc().collect();
+    if normalized.chars().any(is_combining_mark) {
+        return Err(WordleError::IoError(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("word contains a combining character: {}", word),
+        )));
+    }
+    Ok(Cow::Owned(normalized))
+}
+
+/// Returns the word unchanged. Enable the `unicode` feature to normalize it.
+#[cfg(not(feature = "unicode"))]
+#[inline]
+fn normalize_unicode(word: &str) -> Result<Cow<'_, str>, WordleError> {
+    Ok(Cow::Borrowed(word))
+}
+
+/// Removes the UTF-8 byte order mark from the start of the given line, if present.
+#[inline]
+fn strip_byte_order_mark(line: &str) -> &str {
+    line.strip_prefix('\u{FEFF}').unwrap_or(line)
 }
 
 impl Deref for WordBank {
@@ -173,6 +607,181 @@ impl Deref for WordBank {
     }
 }
 
+/// A [`WordBank`] where each word also has a weight, indicating how likely it is to be the answer.
+///
+/// Words are not required to have normalized weights.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightedWordBank {
+    bank: WordBank,
+    weights: HashMap<Arc<str>, f64>,
+}
+
+impl WeightedWordBank {
+    /// Constructs a new `WeightedWordBank` from a reader of word frequencies, where recently used
+    /// answers are less likely to be the answer.
+    ///
+    /// The reader should provide one `word,frequency` pair on each line. Each word is normalized as
+    /// in [`WordBank::from_reader()`], and its frequency is used as its initial weight.
+    ///
+    /// `used_words` should be ordered from most to least recently used. The weight of the `i`th
+    /// (zero-based) word in this list is multiplied by `1 - decay^(i + 1)`, so `decay` should be
+    /// between `0` and `1`. A higher `decay` makes recently used words less likely, and a `decay`
+    /// of `0` ignores the used words entirely. Used words that aren't in the reader are ignored.
+    ///
+    /// Returns a [`WordleError::IoError`] if a line can't be parsed, or a
+    /// [`WordleError::WordLength`] error if the words have different lengths.
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use rs_wordle_solver::WeightedWordBank;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let frequencies = Cursor::new("abc,10\nbcd,10\ncde,5\n");
+    /// let bank = WeightedWordBank::from_frequency_and_used(frequencies, &["abc"], 0.5)?;
+    ///
+    /// assert_eq!(bank.weight("abc"), 5.0);
+    /// assert_eq!(bank.weight("bcd"), 10.0);
+    /// assert_eq!(bank.weight("cde"), 5.0);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn from_frequency_and_used<R, S>(
+        frequency_reader: R,
+        used_words: &[S],
+        decay: f64,
+    ) -> Result<Self, WordleError>
+    where
+        R: io::BufRead,
+        S: AsRef<str>,
+    {
+        let mut weighted_bank = WeightedWordBank::from_reader(frequency_reader)?;
+        let mut decay_multiplier = 1.0;
+        for used_word in used_words {
+            decay_multiplier *= decay;
+            let normalized = used_word.as_ref().trim().to_lowercase();
+            let normalized = match normalize_unicode(&normalized) {
+                Ok(normalized) => normalized,
+                // Such words can't be in the bank.
+                Err(_) => continue,
+            };
+            if let Some(weight) = weighted_bank.weights.get_mut(normalized.as_ref()) {
+                *weight *= 1.0 - decay_multiplier;
+            }
+        }
+        Ok(weighted_bank)
+    }
+
+    /// Constructs a new `WeightedWordBank` from a reader of `word,weight` pairs, one per line.
+    ///
+    /// Each word is normalized as in [`WordBank::from_reader()`]. Weights are typically answer
+    /// frequencies, but any finite, non-negative values can be used.
+    ///
+    /// Returns a [`WordleError::IoError`] if a line can't be parsed or its weight is negative or
+    /// not finite, or a [`WordleError::WordLengthAt`] error with the index of the first line whose
+    /// word has a different length.
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use rs_wordle_solver::WeightedWordBank;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WeightedWordBank::from_reader(Cursor::new("abc,3\nBCD,1.5\n"))?;
+    ///
+    /// assert_eq!(bank.len(), 2);
+    /// assert_eq!(bank.weight("abc"), 3.0);
+    /// assert_eq!(bank.weight("bcd"), 1.5);
+    /// assert_eq!(bank.weight("xyz"), 0.0);
+    /// assert!(WeightedWordBank::from_reader(Cursor::new("abc,-1\n")).is_err());
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn from_reader<R: io::BufRead>(reader: R) -> Result<Self, WordleError> {
+        let options = LoadOptions::default();
+        let mut word_length = 0;
+        let mut words: Vec<Arc<str>> = Vec::new();
+        let mut weights: HashMap<Arc<str>, f64> = HashMap::new();
+        for (index, maybe_line) in reader.lines().enumerate() {
+            let line = maybe_line?;
+            let line = if index == 0 {
+                strip_byte_order_mark(&line)
+            } else {
+                &line
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (word, weight) = line
+                .rsplit_once(',')
+                .and_then(|(word, weight)| {
+                    weight
+                        .trim()
+                        .parse::<f64>()
+                        .ok()
+                        .map(|weight| (word, weight))
+                })
+                .ok_or_else(|| {
+                    WordleError::IoError(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("expected a line of the form 'word,weight', got: {}", line),
+                    ))
+                })?;
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(WordleError::IoError(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "expected a finite, non-negative weight on line {}, got: {}",
+                        index, line
+                    ),
+                )));
+            }
+            let normalized: Option<Result<Arc<str>, WordleError>>;
+            (word_length, normalized) =
+                WordBank::parse_word_to_arc(word_length, word, &options, Some(index));
+            let word = match normalized {
+                Some(word) => word?,
+                None => continue,
+            };
+            weights.insert(Arc::clone(&word), weight);
+            words.push(word);
+        }
+        Ok(WeightedWordBank {
+            bank: WordBank {
+                all_words: words,
+                word_length,
+                original_words: HashMap::new(),
+            },
+            weights,
+        })
+    }
+
+    /// Returns the weight of the given word, or `0.0` if the word isn't in this bank.
+    #[inline]
+    pub fn weight(&self, word: &str) -> f64 {
+        self.weights.get(word).copied().unwrap_or(0.0)
+    }
+
+    /// Returns the underlying [`WordBank`].
+    #[inline]
+    pub fn word_bank(&self) -> &WordBank {
+        &self.bank
+    }
+
+    /// Returns the weight of every word in this bank.
+    #[inline]
+    pub fn weights(&self) -> &HashMap<Arc<str>, f64> {
+        &self.weights
+    }
+}
+
+impl Deref for WeightedWordBank {
+    type Target = WordBank;
+
+    /// Derefs the underlying [`WordBank`].
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.bank
+    }
+}
+
 /// Counts the number of words that contain each letter anywhere, as well as by the location of
 /// each letter.
 ///
@@ -201,12 +810,61 @@ pub struct WordCounter {
 
 impl WordCounter {
     /// Creates a new word counter based on the given word list.
-    #[inline]
+    ///
+    /// Long word lists are counted in parallel.
     pub fn new<S>(words: &[S]) -> WordCounter
     where
-        S: AsRef<str>,
+        S: AsRef<str> + Sync,
     {
-        WordCounter::from_iter(words)
+        if words.len() < PARALLEL_CONSTRUCTION_THRESHOLD {
+            return WordCounter::from_iter(words);
+        }
+        words
+            .par_iter()
+            .fold(WordCounter::empty, |mut counter, word| {
+                counter.add_word(word.as_ref());
+                counter
+            })
+            .reduce(WordCounter::empty, WordCounter::merge)
+    }
+
+    fn empty() -> WordCounter {
+        WordCounter {
+            num_words: 0,
+            num_words_by_ll: HashMap::new(),
+            num_words_by_letter: HashMap::new(),
+        }
+    }
+
+    /// Increments the counts for each of the word's letters.
+    fn add_word(&mut self, word: &str) {
+        self.num_words += 1;
+        for (index, letter) in word.chars().enumerate() {
+            *self
+                .num_words_by_ll
+                .entry(LocatedLetter::new(letter, index as u8))
+                .or_insert(0) += 1;
+            if index == 0
+                || word
+                    .chars()
+                    .take(index)
+                    .all(|other_letter| other_letter != letter)
+            {
+                *self.num_words_by_letter.entry(letter).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Combines the counts from two counters of separate word lists.
+    fn merge(mut self, other: WordCounter) -> WordCounter {
+        self.num_words += other.num_words;
+        for (ll, count) in other.num_words_by_ll {
+            *self.num_words_by_ll.entry(ll).or_insert(0) += count;
+        }
+        for (letter, count) in other.num_words_by_letter {
+            *self.num_words_by_letter.entry(letter).or_insert(0) += count;
+        }
+        self
     }
 
     /// Retrieves the count of words with the given letter at the given location.
@@ -254,6 +912,53 @@ impl WordCounter {
     pub fn num_words(&self) -> u32 {
         self.num_words
     }
+
+    /// Removes a word from this counter, decrementing the counts for each of its letters.
+    ///
+    /// The word must have previously been counted by this counter, else the counts will be
+    /// incorrect.
+    ///
+    /// ```
+    /// use rs_wordle_solver::details::WordCounter;
+    /// use rs_wordle_solver::details::LocatedLetter;
+    ///
+    /// let all_words = vec!["aba", "bbd", "efg"];
+    /// let mut counter = WordCounter::from_iter(&all_words);
+    ///
+    /// counter.remove_word("bbd");
+    ///
+    /// assert_eq!(counter.num_words(), 2);
+    /// assert_eq!(counter.num_words_with_letter('b'), 1);
+    /// assert_eq!(counter.num_words_with_located_letter(
+    ///     &LocatedLetter::new('b', 0)), 0);
+    /// ```
+    pub fn remove_word(&mut self, word: &str) {
+        self.num_words = self.num_words.saturating_sub(1);
+        for (index, letter) in word.chars().enumerate() {
+            decrement_count(
+                &mut self.num_words_by_ll,
+                LocatedLetter::new(letter, index as u8),
+            );
+            if index == 0
+                || word
+                    .chars()
+                    .take(index)
+                    .all(|other_letter| other_letter != letter)
+            {
+                decrement_count(&mut self.num_words_by_letter, letter);
+            }
+        }
+    }
+}
+
+/// Decrements the count for the given key, removing it once it reaches zero.
+fn decrement_count<K: Eq + Hash>(counts: &mut HashMap<K, u32>, key: K) {
+    if let Some(count) = counts.get_mut(&key) {
+        *count = count.saturating_sub(1);
+        if *count == 0 {
+            counts.remove(&key);
+        }
+    }
 }
 
 impl<S> FromIterator<S> for WordCounter
@@ -274,31 +979,77 @@ where
     where
         T: IntoIterator<Item = S>,
     {
-        let mut num_words_by_ll: HashMap<LocatedLetter, u32> = HashMap::new();
-        let mut num_words_by_letter: HashMap<char, u32> = HashMap::new();
-        let mut num_words = 0;
+        let mut counter = WordCounter::empty();
         for word in iter.into_iter() {
-            num_words += 1;
-            for (index, letter) in word.as_ref().char_indices() {
-                *num_words_by_ll
-                    .entry(LocatedLetter::new(letter, index as u8))
-                    .or_insert(0) += 1;
-                if index == 0
-                    || word
-                        .as_ref()
-                        .chars()
-                        .take(index)
-                        .all(|other_letter| other_letter != letter)
-                {
-                    *num_words_by_letter.entry(letter).or_insert(0) += 1;
-                }
+            counter.add_word(word.as_ref());
+        }
+        counter
+    }
+}
+
+/// The words that contain each letter, as tracked by a [`WordTracker`].
+#[derive(Default)]
+struct WordIndex {
+    words_by_letter: HashMap<char, Vec<Arc<str>>>,
+    words_by_located_letter: HashMap<LocatedLetter, Vec<Arc<str>>>,
+}
+
+impl WordIndex {
+    fn serial(words: &[Arc<str>]) -> WordIndex {
+        let mut index = WordIndex::default();
+        for word in words {
+            index.add_word(word);
+        }
+        index
+    }
+
+    /// Indexes the words in parallel. The words for each letter remain in their original order.
+    fn parallel(words: &[Arc<str>]) -> WordIndex {
+        words
+            .par_iter()
+            .fold(WordIndex::default, |mut index, word| {
+                index.add_word(word);
+                index
+            })
+            .reduce(WordIndex::default, WordIndex::merge)
+    }
+
+    fn add_word(&mut self, word: &Arc<str>) {
+        let word_ref = word.as_ref();
+        for (index, letter) in word_ref.chars().enumerate() {
+            self.words_by_located_letter
+                .entry(LocatedLetter::new(letter, index as u8))
+                .or_default()
+                .push(Arc::clone(word));
+            if index == 0
+                || word_ref
+                    .chars()
+                    .take(index)
+                    .all(|other_letter| letter != other_letter)
+            {
+                self.words_by_letter
+                    .entry(letter)
+                    .or_default()
+                    .push(Arc::clone(word));
             }
         }
-        WordCounter {
-            num_words,
-            num_words_by_ll,
-            num_words_by_letter,
+    }
+
+    /// Appends the words from an index of the words after this index's words.
+    fn merge(mut self, other: WordIndex) -> WordIndex {
+        for (letter, words) in other.words_by_letter {
+            self.words_by_letter
+                .entry(letter)
+                .or_default()
+                .extend(words);
+        }
+        for (ll, words) in other.words_by_located_letter {
+            self.words_by_located_letter
+                .entry(ll)
+                .or_default()
+                .extend(words);
         }
+        self
     }
 }
 
@@ -345,35 +1096,34 @@ impl<'w> WordTracker<'w> {
     /// assert_eq!(tracker.all_words(), &all_words);
     /// ```
     pub fn new<'w_in: 'w>(all_words: &'w_in [Arc<str>]) -> WordTracker<'w> {
-        let mut words_by_letter: HashMap<char, Vec<Arc<str>>> = HashMap::new();
-        let mut words_by_located_letter: HashMap<LocatedLetter, Vec<Arc<str>>> = HashMap::new();
-        for word in all_words.iter() {
-            let word_ref = word.as_ref();
-            for (index, letter) in word_ref.char_indices() {
-                words_by_located_letter
-                    .entry(LocatedLetter::new(letter, index as u8))
-                    .or_default()
-                    .push(Arc::clone(word));
-                if index == 0
-                    || word_ref
-                        .chars()
-                        .take(index)
-                        .all(|other_letter| letter != other_letter)
-                {
-                    words_by_letter
-                        .entry(letter)
-                        .or_default()
-                        .push(Arc::clone(word));
-                }
-            }
-        }
+        let index = if all_words.len() < PARALLEL_CONSTRUCTION_THRESHOLD {
+            WordIndex::serial(all_words)
+        } else {
+            WordIndex::parallel(all_words)
+        };
         WordTracker {
             all_words,
-            words_by_letter,
-            words_by_located_letter,
+            words_by_letter: index.words_by_letter,
+            words_by_located_letter: index.words_by_located_letter,
         }
     }
 
+    /// Constructs a new `WordTracker` from the given slice of words. This is the same as
+    /// [`Self::new()`].
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::details::WordTracker;
+    ///
+    /// let all_words = vec![Arc::from("aba"), Arc::from("bcd"), Arc::from("efg")];
+    /// let tracker = WordTracker::from_slice(&all_words);
+    ///
+    /// assert_eq!(tracker.all_words(), &all_words);
+    /// ```
+    pub fn from_slice<'w_in: 'w>(all_words: &'w_in [Arc<str>]) -> WordTracker<'w> {
+        Self::new(all_words)
+    }
+
     /// Retrieves the full list of words stored in this word tracker.
     ///
     /// ```
@@ -434,6 +1184,37 @@ impl<'w> WordTracker<'w> {
             .unwrap_or_default()
     }
 
+    /// Returns an [`Iterator`] over words that don't have the given letter at the given location.
+    /// These words may still have the letter at other locations.
+    ///
+    /// The words are not returned in any particular order.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::details::WordTracker;
+    /// use rs_wordle_solver::details::LocatedLetter;
+    ///
+    /// let all_words = [Arc::from("bba"), Arc::from("bcd"), Arc::from("efg")];
+    /// let tracker = WordTracker::new(&all_words);
+    ///
+    /// let mut words = Vec::from_iter(
+    ///     tracker.words_with_located_letter_absent(LocatedLetter::new('b', 1)));
+    /// words.sort();
+    /// assert_eq!(words, vec![&Arc::from("bcd"), &Arc::from("efg")]);
+    /// assert_eq!(
+    ///     tracker.words_with_located_letter_absent(LocatedLetter::new('z', 0)).count(),
+    ///     3);
+    /// ```
+    pub fn words_with_located_letter_absent(
+        &self,
+        ll: LocatedLetter,
+    ) -> impl Iterator<Item = &Arc<str>> {
+        self.words_by_located_letter
+            .iter()
+            .filter(move |(other_ll, _)| other_ll.location == ll.location && **other_ll != ll)
+            .flat_map(|(_, words)| words.iter())
+    }
+
     /// Returns an [`Iterator`] over words that have the given letter.
     ///
     /// ```
@@ -515,6 +1296,197 @@ impl<'w> WordTracker<'w> {
     }
 }
 
+/// Returns the bit for the given letter in a [`WordMasks`] letter mask, if it is a lower case
+/// ASCII letter.
+pub(crate) fn letter_bit(letter: char) -> Option<u32> {
+    if letter.is_ascii_lowercase() {
+        Some(1 << (letter as u32 - 'a' as u32))
+    } else {
+        None
+    }
+}
+
+/// Returns the mask of all the letters in the word, or `None` if any of its letters aren't lower
+/// case ASCII letters.
+pub(crate) fn letters_mask(word: &str) -> Option<u32> {
+    word.chars()
+        .try_fold(0, |letters, letter| Some(letters | letter_bit(letter)?))
+}
+
+/// Returns `true` iff the word satisfies the given restrictions, using the word's
+/// [`letters_mask()`] and the restrictions' masks if both could be computed.
+pub(crate) fn matches_masks(
+    word: &str,
+    letters: Option<u32>,
+    restrictions: &WordRestrictions,
+    masks: Option<&RestrictionMasks>,
+) -> bool {
+    match (letters, masks) {
+        (Some(letters), Some(masks)) => {
+            masks.allow(word, letters)
+                && (!masks.depends_on_counts || restrictions.is_satisfied_by(word))
+        }
+        _ => restrictions.is_satisfied_by(word),
+    }
+}
+
+/// A precomputed bitmask of the letters in a word, for quickly checking the word against
+/// [`WordRestrictions`].
+///
+/// The mask has one bit for each lower case ASCII letter, starting with `'a'` as the lowest bit.
+/// Checking which letters must or must not be in the word, or in each location, then only needs
+/// bitwise operations. When the restrictions depend on how many times a letter appears, or when
+/// the word or the restrictions include other characters, this falls back to
+/// [`WordRestrictions::is_satisfied_by()`].
+///
+/// ```
+/// use std::sync::Arc;
+/// use rs_wordle_solver::GuessResult;
+/// use rs_wordle_solver::LetterResult;
+/// use rs_wordle_solver::details::WordMasks;
+/// use rs_wordle_solver::details::WordRestrictions;
+///
+/// let restrictions = WordRestrictions::from_result(&GuessResult {
+///     guess: "abc",
+///     results: vec![
+///         LetterResult::Correct,
+///         LetterResult::PresentNotHere,
+///         LetterResult::NotPresent,
+///     ],
+/// });
+/// let masks = WordMasks::new(Arc::from("adb"));
+///
+/// assert_eq!(masks.letters(), Some(0b1011));
+/// assert!(masks.matches(&restrictions));
+/// assert!(!WordMasks::new(Arc::from("abd")).matches(&restrictions));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct WordMasks {
+    word: Arc<str>,
+    letters: Option<u32>,
+}
+
+impl WordMasks {
+    /// Computes the masks for the given word.
+    pub fn new(word: Arc<str>) -> WordMasks {
+        WordMasks {
+            letters: letters_mask(&word),
+            word,
+        }
+    }
+
+    /// Returns the word these masks were computed for.
+    #[inline]
+    pub fn word(&self) -> &Arc<str> {
+        &self.word
+    }
+
+    /// Returns the mask of the letters in the word, or `None` if any of its letters aren't lower
+    /// case ASCII letters.
+    #[inline]
+    pub fn letters(&self) -> Option<u32> {
+        self.letters
+    }
+
+    /// Returns `true` iff the word satisfies the given restrictions, as in
+    /// [`WordRestrictions::is_satisfied_by()`].
+    ///
+    /// This computes the restrictions' masks each time. To check many words against the same
+    /// restrictions, use [`Self::filter()`] instead.
+    pub fn matches(&self, restrictions: &WordRestrictions) -> bool {
+        matches_masks(
+            &self.word,
+            self.letters,
+            restrictions,
+            restrictions.to_masks().as_ref(),
+        )
+    }
+
+    /// Returns the words that satisfy the given restrictions, in the same order as `word_masks`.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// use rs_wordle_solver::details::WordMasks;
+    /// use rs_wordle_solver::details::WordRestrictions;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "bcd", "xyz"])?;
+    /// let word_masks = bank.word_masks();
+    /// let restrictions = WordRestrictions::from_result(&get_result_for_guess("abd", "abc")?);
+    ///
+    /// let matching: Vec<&Arc<str>> = WordMasks::filter(&word_masks, &restrictions).collect();
+    ///
+    /// assert_eq!(matching, vec![&Arc::from("abd")]);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn filter<'a>(
+        word_masks: &'a [WordMasks],
+        restrictions: &'a WordRestrictions,
+    ) -> impl Iterator<Item = &'a Arc<str>> + 'a {
+        let masks = restrictions.to_masks();
+        word_masks
+            .iter()
+            .filter(move |word_masks| {
+                matches_masks(
+                    &word_masks.word,
+                    word_masks.letters,
+                    restrictions,
+                    masks.as_ref(),
+                )
+            })
+            .map(WordMasks::word)
+    }
+}
+
+/// The masks for checking words against a set of [`WordRestrictions`]. See
+/// [`WordRestrictions::to_masks()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RestrictionMasks {
+    /// The letters that must be in the word.
+    pub(crate) required: u32,
+    /// The letters that must not be in the word.
+    pub(crate) absent: u32,
+    /// The letter that must be at each location, or 0 if that isn't known.
+    pub(crate) here: Vec<u32>,
+    /// The letters that must not be at each location.
+    pub(crate) not_here: Vec<u32>,
+    /// Whether the restrictions depend on how many times a letter appears in the word.
+    pub(crate) depends_on_counts: bool,
+}
+
+impl RestrictionMasks {
+    /// Creates empty masks for words of the given length.
+    pub(crate) fn new(word_length: usize) -> RestrictionMasks {
+        RestrictionMasks {
+            required: 0,
+            absent: 0,
+            here: vec![0; word_length],
+            not_here: vec![0; word_length],
+            depends_on_counts: false,
+        }
+    }
+
+    /// Returns `true` iff the word is allowed by these masks, ignoring how many times each letter
+    /// appears.
+    ///
+    /// The word must only contain lower case ASCII letters, and `letters` must be its
+    /// [`letters_mask()`].
+    fn allow(&self, word: &str, letters: u32) -> bool {
+        word.len() == self.here.len()
+            && letters & self.required == self.required
+            && letters & self.absent == 0
+            && word
+                .bytes()
+                .zip(self.here.iter().zip(self.not_here.iter()))
+                .all(|(letter, (here, not_here))| {
+                    let bit = 1 << (letter - b'a');
+                    (*here == 0 || bit == *here) && bit & not_here == 0
+                })
+    }
+}
+
 /// Efficiently tracks all possible words and all unguessed words as zero-cost slices within a
 /// single array of all words.
 #[derive(Clone, Debug)]
@@ -531,6 +1503,10 @@ pub struct GroupedWords {
     // 3. Words that have not been guessed, but are not possible. Then index:
     first_guessed_impossible_word: usize,
     // 4. Words that have been guessed, and are not possible.
+    // The letter mask for each word in `all_words`, in the same order. These are computed on first
+    // use, so this is empty until then.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    letter_masks: Vec<Option<u32>>,
 }
 
 impl GroupedWords {
@@ -543,6 +1519,7 @@ impl GroupedWords {
             first_unguessed_possible_word: 0,
             num_possible_words: num_words,
             first_guessed_impossible_word: num_words,
+            letter_masks: Vec::new(),
         }
     }
 
@@ -559,6 +1536,11 @@ impl GroupedWords {
         &self.all_words[self.first_unguessed_possible_word..self.first_guessed_impossible_word]
     }
 
+    /// The slice of all words that have been guessed, and are still possible.
+    pub fn guessed_possible_words(&self) -> &[Arc<str>] {
+        &self.all_words[0..self.first_unguessed_possible_word]
+    }
+
     /// The slice of all possible words.
     pub fn possible_words(&self) -> &[Arc<str>] {
         &self.all_words[0..self.num_possible_words]
@@ -577,25 +1559,123 @@ impl GroupedWords {
         {
             // If it's a possible word, put it in section 1.
             if position < self.num_possible_words {
-                self.all_words
-                    .swap(position, self.first_unguessed_possible_word);
+                self.swap_words(position, self.first_unguessed_possible_word);
                 self.first_unguessed_possible_word += 1;
             } else {
                 // If it's an impossible word, put it in section 4.
-                self.all_words
-                    .swap(position, self.first_guessed_impossible_word - 1);
+                self.swap_words(position, self.first_guessed_impossible_word - 1);
                 self.first_guessed_impossible_word -= 1;
             }
         }
     }
 
     /// Filters out possible words for which the filter returns false.
-    pub fn filter_possible_words<F>(&mut self, filter: F)
+    ///
+    /// Returns the words that were filtered out, in no particular order.
+    pub fn filter_possible_words<F>(&mut self, filter: F) -> Vec<Arc<str>>
     where
         F: Fn(&str) -> bool,
     {
+        self.retain_possible_words(|_, word| filter(word))
+    }
+
+    /// Filters the possible words as in [`Self::filter_possible_words()`], but checks the words in
+    /// parallel if there are more than `parallelisation_limit` possible words.
+    ///
+    /// The words end up in the same order either way.
+    pub fn filter_possible_words_with_limit<F>(
+        &mut self,
+        filter: F,
+        parallelisation_limit: usize,
+    ) -> Vec<Arc<str>>
+    where
+        F: Fn(&str) -> bool + Sync,
+    {
+        if self.num_possible_words <= parallelisation_limit {
+            return self.filter_possible_words(filter);
+        }
+        let is_possible: Vec<bool> = self.all_words[0..self.num_possible_words]
+            .par_iter()
+            .map(|word| filter(word))
+            .collect();
+        self.retain_possible_words(|index, _| is_possible[index])
+    }
+
+    /// Filters out possible words that don't satisfy the given restrictions, using each word's
+    /// letter mask to skip most of the work of [`WordRestrictions::is_satisfied_by()`].
+    ///
+    /// The words are checked in parallel if there are more than `parallelisation_limit` possible
+    /// words.
+    ///
+    /// Returns the words that were filtered out, in no particular order.
+    pub fn filter_possible_words_matching(
+        &mut self,
+        restrictions: &WordRestrictions,
+        parallelisation_limit: usize,
+    ) -> Vec<Arc<str>> {
+        let restriction_masks = match restrictions.to_masks() {
+            Some(restriction_masks) => restriction_masks,
+            None => {
+                return self.filter_possible_words_with_limit(
+                    |word| restrictions.is_satisfied_by(word),
+                    parallelisation_limit,
+                )
+            }
+        };
+        if self.letter_masks.len() != self.all_words.len() {
+            self.letter_masks = self
+                .all_words
+                .iter()
+                .map(|word| letters_mask(word))
+                .collect();
+        }
+        let letter_masks = &self.letter_masks;
+        let is_possible_word = |(index, word): (usize, &Arc<str>)| {
+            matches_masks(
+                word,
+                letter_masks[index],
+                restrictions,
+                Some(&restriction_masks),
+            )
+        };
+        let possible_words = &self.all_words[0..self.num_possible_words];
+        let is_possible: Vec<bool> = if self.num_possible_words <= parallelisation_limit {
+            possible_words
+                .iter()
+                .enumerate()
+                .map(is_possible_word)
+                .collect()
+        } else {
+            possible_words
+                .par_iter()
+                .enumerate()
+                .map(is_possible_word)
+                .collect()
+        };
+        self.retain_possible_words(|index, _| is_possible[index])
+    }
+
+    /// Swaps the words at the two indices, along with their masks if those have been computed.
+    fn swap_words(&mut self, a: usize, b: usize) {
+        self.all_words.swap(a, b);
+        if !self.letter_masks.is_empty() {
+            self.letter_masks.swap(a, b);
+        }
+    }
+
+    /// Moves each possible word for which `keep` returns false to the relevant impossible section.
+    ///
+    /// `keep` is given the index each word was at before this was called. Each word is checked
+    /// while it is still at that index, since words are only swapped with ones already checked.
+    ///
+    /// Returns the words that were moved.
+    fn retain_possible_words<F>(&mut self, keep: F) -> Vec<Arc<str>>
+    where
+        F: Fn(usize, &str) -> bool,
+    {
+        let mut eliminated = Vec::new();
         if self.num_possible_words - self.first_unguessed_possible_word == 0 {
-            return;
+            return eliminated;
         }
 
         // Iterate backwards so that, in the common case, we swap the minimum number of words.
@@ -603,11 +1683,12 @@ impl GroupedWords {
         loop {
             let word = &self.all_words[i];
 
-            if !filter(word.as_ref()) {
+            if !keep(i, word.as_ref()) {
+                eliminated.push(Arc::clone(word));
                 // Move this word from section 2 (possible unguessed words) to section 3 (impossible
                 // unguessed words).
                 self.num_possible_words -= 1;
-                self.all_words.swap(i, self.num_possible_words);
+                self.swap_words(i, self.num_possible_words);
             }
 
             if i == self.first_unguessed_possible_word {
@@ -617,29 +1698,29 @@ impl GroupedWords {
         }
         // See if there are any guessed possible words to check. This is rare.
         if i == 0 {
-            return;
+            return eliminated;
         }
 
         i -= 1;
         loop {
             let word = &self.all_words[i];
 
-            if !filter(word.as_ref()) {
+            if !keep(i, word.as_ref()) {
+                eliminated.push(Arc::clone(word));
                 // We're going to bump the word to the end of section 1, then end of section 2, then end of section 3.
                 self.first_guessed_impossible_word -= 1;
                 self.num_possible_words -= 1;
                 self.first_unguessed_possible_word -= 1;
-                self.all_words.swap(i, self.first_unguessed_possible_word);
-                self.all_words
-                    .swap(self.first_unguessed_possible_word, self.num_possible_words);
-                self.all_words
-                    .swap(self.num_possible_words, self.first_guessed_impossible_word);
+                self.swap_words(i, self.first_unguessed_possible_word);
+                self.swap_words(self.first_unguessed_possible_word, self.num_possible_words);
+                self.swap_words(self.num_possible_words, self.first_guessed_impossible_word);
             }
             if i == 0 {
                 break;
             }
             i -= 1;
         }
+        eliminated
     }
 }
 
@@ -676,6 +1757,19 @@ mod tests {
     use super::*;
     use std::collections::HashSet;
 
+    #[test]
+    fn test_word_counter_remove_word() {
+        let mut counter = WordCounter::new(&["aba", "bbd", "efg", "dab"]);
+
+        counter.remove_word("bbd");
+        counter.remove_word("efg");
+
+        let expected = WordCounter::new(&["aba", "dab"]);
+        assert_eq!(counter.num_words, expected.num_words);
+        assert_eq!(counter.num_words_by_ll, expected.num_words_by_ll);
+        assert_eq!(counter.num_words_by_letter, expected.num_words_by_letter);
+    }
+
     #[test]
     fn test_grouped_words_new() -> Result<(), WordleError> {
         let words =
@@ -771,6 +1865,157 @@ mod tests {
         Ok(())
     }
 
+    fn assert_grouped_words_eq(actual: &GroupedWords, expected: &GroupedWords) {
+        assert_eq!(actual.all_words, expected.all_words);
+        assert_eq!(
+            actual.first_unguessed_possible_word,
+            expected.first_unguessed_possible_word
+        );
+        assert_eq!(actual.num_possible_words, expected.num_possible_words);
+        assert_eq!(
+            actual.first_guessed_impossible_word,
+            expected.first_guessed_impossible_word
+        );
+    }
+
+    #[test]
+    fn test_grouped_words_filter_possible_words_parallel_matches_serial() -> Result<(), WordleError>
+    {
+        let words = read_test_words("../data/1000-improved-words-shuffled.txt")?;
+        let mut serial = GroupedWords::new(words.clone());
+        let mut parallel = GroupedWords::new(words.clone());
+        let guesses = ["about", "bread", "crane"];
+        let filters: [fn(&str) -> bool; 3] = [
+            |word| !word.contains('z'),
+            |word| word.contains('e'),
+            |word| !word.starts_with('s'),
+        ];
+
+        for (guess, filter) in guesses.into_iter().zip(filters) {
+            // Guessed words that are still possible are kept in their own section.
+            serial.remove_guess_if_present(guess);
+            parallel.remove_guess_if_present(guess);
+            serial.filter_possible_words(filter);
+            parallel.filter_possible_words_with_limit(filter, 0);
+
+            assert_grouped_words_eq(&parallel, &serial);
+        }
+
+        let possible_words: HashSet<&Arc<str>> = parallel.possible_words().iter().collect();
+        let expected_possible_words: HashSet<&Arc<str>> = words
+            .iter()
+            .filter(|word| !word.contains('z') && word.contains('e') && !word.starts_with('s'))
+            .collect();
+        assert_eq!(possible_words, expected_possible_words);
+        let unguessed_words: HashSet<&Arc<str>> = parallel.unguessed_words().iter().collect();
+        assert!(unguessed_words.is_disjoint(&HashSet::from_iter(parallel.guessed_possible_words())));
+        assert_eq!(unguessed_words.len(), parallel.num_unguessed_words());
+        Ok(())
+    }
+
+    #[test]
+    fn test_grouped_words_filter_possible_words_returns_eliminated_words() -> Result<(), WordleError>
+    {
+        let words = WordBank::from_iterator(["the", "big", "dog", "cat", "bat"])?;
+        let mut serial_grouped_words = GroupedWords::new(words.clone());
+        let mut parallel_grouped_words = GroupedWords::new(words);
+
+        for grouped_words in [&mut serial_grouped_words, &mut parallel_grouped_words] {
+            grouped_words.remove_guess_if_present("big");
+            grouped_words.remove_guess_if_present("cat");
+        }
+        let mut serial_eliminated =
+            serial_grouped_words.filter_possible_words(|word| !word.contains('t'));
+        let mut parallel_eliminated =
+            parallel_grouped_words.filter_possible_words_with_limit(|word| !word.contains('t'), 0);
+        serial_eliminated.sort();
+        parallel_eliminated.sort();
+
+        let expected: Vec<Arc<str>> = vec![Arc::from("bat"), Arc::from("cat"), Arc::from("the")];
+        assert_eq!(serial_eliminated, expected);
+        assert_eq!(parallel_eliminated, expected);
+        assert!(serial_grouped_words
+            .filter_possible_words(|_| true)
+            .is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_grouped_words_filter_possible_words_with_limit_checks_guessed_words(
+    ) -> Result<(), WordleError> {
+        let words = WordBank::from_iterator(["the", "big", "dog", "cat", "bat"])?;
+        let mut grouped_words = GroupedWords::new(words.clone());
+
+        grouped_words.remove_guess_if_present("big");
+        grouped_words.remove_guess_if_present("cat");
+        grouped_words.filter_possible_words_with_limit(|word| word != "the", 0);
+        grouped_words.filter_possible_words_with_limit(|word| word != "cat", 0);
+
+        assert_eq!(grouped_words.guessed_possible_words(), &[Arc::from("big")]);
+        assert_eq!(
+            grouped_words
+                .possible_words()
+                .iter()
+                .collect::<HashSet<_>>(),
+            HashSet::from_iter(&[Arc::from("big"), Arc::from("dog"), Arc::from("bat")])
+        );
+        assert_eq!(
+            grouped_words
+                .unguessed_words()
+                .iter()
+                .collect::<HashSet<_>>(),
+            HashSet::from_iter(&[Arc::from("the"), Arc::from("dog"), Arc::from("bat")])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_grouped_words_filter_possible_words_matching() -> Result<(), WordleError> {
+        let words = WordBank::from_iterator(["the", "big", "dog", "cat", "bat", "tat", "bib"])?;
+        let mut expected_grouped_words = GroupedWords::new(words.clone());
+        let mut serial_grouped_words = GroupedWords::new(words.clone());
+        let mut parallel_grouped_words = GroupedWords::new(words);
+        let mut restrictions = WordRestrictions::new(3);
+
+        for (guess, objective) in [("big", "bat"), ("cat", "bat"), ("tat", "bat")] {
+            restrictions.update(&get_result_for_guess(objective, guess)?)?;
+            expected_grouped_words.remove_guess_if_present(guess);
+            serial_grouped_words.remove_guess_if_present(guess);
+            parallel_grouped_words.remove_guess_if_present(guess);
+
+            let mut expected_eliminated = expected_grouped_words
+                .filter_possible_words(|word| restrictions.is_satisfied_by(word));
+            let mut serial_eliminated =
+                serial_grouped_words.filter_possible_words_matching(&restrictions, usize::MAX);
+            let mut parallel_eliminated =
+                parallel_grouped_words.filter_possible_words_matching(&restrictions, 0);
+            expected_eliminated.sort();
+            serial_eliminated.sort();
+            parallel_eliminated.sort();
+
+            assert_eq!(serial_eliminated, expected_eliminated);
+            assert_eq!(parallel_eliminated, expected_eliminated);
+            assert_eq!(
+                serial_grouped_words.all_words,
+                expected_grouped_words.all_words
+            );
+            assert_eq!(
+                parallel_grouped_words.all_words,
+                expected_grouped_words.all_words
+            );
+            assert_eq!(
+                serial_grouped_words.letter_masks,
+                serial_grouped_words
+                    .all_words
+                    .iter()
+                    .map(|word| letters_mask(word))
+                    .collect::<Vec<_>>()
+            );
+        }
+        assert_eq!(serial_grouped_words.possible_words(), &[Arc::from("bat")]);
+        Ok(())
+    }
+
     #[test]
     fn test_grouped_words_display() -> Result<(), WordleError> {
         let words = WordBank::from_iterator(&[
@@ -792,4 +2037,48 @@ mod tests {
         );
         Ok(())
     }
+
+    fn read_test_words(path: &str) -> Result<WordBank, WordleError> {
+        WordBank::from_reader(io::BufReader::new(std::fs::File::open(path)?))
+    }
+
+    #[test]
+    fn test_word_counter_parallel_matches_serial() -> Result<(), WordleError> {
+        for path in [
+            "../data/1000-improved-words-shuffled.txt",
+            "../data/improved-words.txt",
+        ] {
+            let words = read_test_words(path)?;
+            assert!(words.len() >= PARALLEL_CONSTRUCTION_THRESHOLD);
+
+            let parallel = WordCounter::new(&words);
+            let serial = WordCounter::from_iter(words.iter());
+
+            assert_eq!(parallel.num_words, serial.num_words);
+            assert_eq!(parallel.num_words_by_ll, serial.num_words_by_ll);
+            assert_eq!(parallel.num_words_by_letter, serial.num_words_by_letter);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_word_tracker_parallel_matches_serial() -> Result<(), WordleError> {
+        for path in [
+            "../data/100-improved-words-shuffled.txt",
+            "../data/1000-improved-words-shuffled.txt",
+            "../data/improved-words.txt",
+        ] {
+            let words = read_test_words(path)?;
+
+            let parallel = WordIndex::parallel(&words);
+            let serial = WordIndex::serial(&words);
+
+            assert_eq!(parallel.words_by_letter, serial.words_by_letter);
+            assert_eq!(
+                parallel.words_by_located_letter,
+                serial.words_by_located_letter
+            );
+        }
+        Ok(())
+    }
 }
@@ -1,13 +1,28 @@
-use rayon::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use rand::SeedableRng;
 
 use crate::data::*;
+use crate::parallel::*;
+use crate::restrictions::LetterRestriction;
+use crate::restrictions::UpdateSummary;
 use crate::restrictions::WordRestrictions;
 use crate::results::*;
 use crate::scorers::WordScorer;
+use std::cmp::Ordering;
+#[cfg(not(feature = "std"))]
+use std::collections::hash_map::RandomState;
 use std::collections::HashMap;
-use std::num::NonZeroUsize;
+use std::collections::HashSet;
+use std::fmt;
+#[cfg(not(feature = "std"))]
+use std::hash::{BuildHasher, Hasher};
+use std::io;
 use std::result::Result;
+use std::sync::mpsc::Sender;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Indicates which set of words to guess from. See [`MaxScoreGuesser::new()`].
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
@@ -22,8 +37,87 @@ pub enum GuessFrom {
 /// Guesses words in order to solve a single Wordle.
 pub trait Guesser {
     /// Updates this guesser with information about a word.
+    ///
+    /// Returns a [`WordleError::WordLength`] error if the guess or the number of results differs
+    /// from the length of this guesser's words, or a [`WordleError::ContradictoryResults`] error if
+    /// the result conflicts with previous results.
     fn update(&mut self, result: &GuessResult) -> Result<(), WordleError>;
 
+    /// Updates this guesser as in [`Self::update()`], and returns the words that are no longer
+    /// possible as a result, in no particular order.
+    ///
+    /// Defaults to comparing the possible words before and after calling [`Self::update()`].
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::RandomGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "xyz"])?;
+    /// let mut guesser = RandomGuesser::new(bank);
+    ///
+    /// let result = get_result_for_guess("abd", "abc")?;
+    /// let mut eliminated = guesser.update_returning_eliminated(&result)?;
+    /// eliminated.sort();
+    ///
+    /// assert_eq!(eliminated, vec![Arc::from("abc"), Arc::from("xyz")]);
+    /// assert_eq!(guesser.possible_words(), &[Arc::from("abd")]);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    fn update_returning_eliminated(
+        &mut self,
+        result: &GuessResult,
+    ) -> Result<Vec<Arc<str>>, WordleError> {
+        let previous_words = self.possible_words().to_vec();
+        self.update(result)?;
+        let remaining_words: HashSet<&Arc<str>> = self.possible_words().iter().collect();
+        Ok(previous_words
+            .into_iter()
+            .filter(|word| !remaining_words.contains(word))
+            .collect())
+    }
+
+    /// Updates this guesser with information about a word, where some letters' results may not
+    /// be fully known. Only the restrictions that are certain are applied. See
+    /// [`WordRestrictions::update_partial()`].
+    ///
+    /// Defaults to calling [`Self::update()`] if every result is fully known, else returning a
+    /// [`WordleError::InvalidResults`] error, for guessers that don't support partial results.
+    ///
+    /// ```
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::PartialGuessResult;
+    /// use rs_wordle_solver::PartialLetterResult;
+    /// use rs_wordle_solver::RandomGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "bad", "cab", "def"])?;
+    /// let mut guesser = RandomGuesser::new(bank);
+    ///
+    /// // Unsure whether the "a" was green or yellow.
+    /// guesser.update_partial(&PartialGuessResult {
+    ///     guess: "xay",
+    ///     results: vec![
+    ///         PartialLetterResult::NotPresent,
+    ///         PartialLetterResult::PresentMaybeHere,
+    ///         PartialLetterResult::NotPresent,
+    ///     ],
+    /// })?;
+    ///
+    /// assert_eq!(guesser.possible_words().len(), 3);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    fn update_partial(&mut self, result: &PartialGuessResult) -> Result<(), WordleError> {
+        match result.to_guess_result() {
+            Some(full_result) => self.update(&full_result),
+            None => Err(WordleError::InvalidResults),
+        }
+    }
+
     /// Selects a new guess for the Wordle.
     ///
     /// Returns `None` if no known words are possible given the known restrictions imposed by
@@ -39,295 +133,2017 @@ pub trait Guesser {
 
     /// Provides read access to the remaining set of possible words in this guesser.
     fn possible_words(&self) -> &[Arc<str>];
+
+    /// Iterates over the remaining set of possible words in this guesser.
+    ///
+    /// Defaults to iterating over [`Self::possible_words()`]. Guessers that can compute their
+    /// possible words lazily may override this to avoid materializing the full set.
+    ///
+    /// ```
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::RandomGuesser;
+    /// use rs_wordle_solver::WordBank;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"]).unwrap();
+    /// let guesser = RandomGuesser::new(bank);
+    ///
+    /// assert_eq!(guesser.possible_words_iter().take(2).count(), 2);
+    /// ```
+    ///
+    /// This is not available on `dyn Guesser` trait objects.
+    fn possible_words_iter(&self) -> impl Iterator<Item = &Arc<str>>
+    where
+        Self: Sized,
+    {
+        self.possible_words().iter()
+    }
+
+    /// Returns the number of words that are still possible in this guesser.
+    ///
+    /// Defaults to the length of [`Self::possible_words()`].
+    fn remaining_count(&self) -> usize {
+        self.possible_words().len()
+    }
+
+    /// Records the given word as the next guess, as if it had been returned by
+    /// [`Self::select_next_guess()`], so that it won't be selected again. Call [`Self::update()`]
+    /// with the result of this guess as usual.
+    ///
+    /// This is useful for forcing a fixed opening guess, and then letting the guesser take over.
+    ///
+    /// Defaults to doing nothing, for guessers that don't track which words have been guessed.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "bcd", "xyz"])?;
+    /// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// guesser.guess_word("xyz")?;
+    /// guesser.update(&get_result_for_guess("abd", "xyz")?)?;
+    ///
+    /// assert_ne!(guesser.select_next_guess().unwrap().as_ref(), "xyz");
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    fn guess_word(&mut self, _word: &str) -> Result<(), WordleError> {
+        Ok(())
+    }
+
+    /// Returns the guesses that this guesser has been updated with, in the order they were made.
+    ///
+    /// Defaults to an empty slice, for guessers that don't track their guesses.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::RandomGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"])?;
+    /// let mut guesser = RandomGuesser::new(bank);
+    ///
+    /// guesser.update(&get_result_for_guess("abd", "bcd")?)?;
+    /// guesser.update(&get_result_for_guess("abd", "abc")?)?;
+    ///
+    /// assert_eq!(guesser.guess_history(), &[Arc::from("bcd"), Arc::from("abc")]);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    fn guess_history(&self) -> &[Arc<str>] {
+        &[]
+    }
+
+    /// Returns what was newly learned about the word from the most recent call to
+    /// [`Self::update()`] or [`Self::update_partial()`]. See [`WordRestrictions::changes_since()`].
+    ///
+    /// Defaults to an empty summary, for guessers that don't track what they have learned.
+    ///
+    /// ```
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::RandomGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// use rs_wordle_solver::details::LocatedLetter;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"])?;
+    /// let mut guesser = RandomGuesser::new(bank);
+    ///
+    /// guesser.update(&get_result_for_guess("abd", "bcd")?)?;
+    /// guesser.update(&get_result_for_guess("abd", "abc")?)?;
+    ///
+    /// // The first result already showed that "b" must be second, so only "a" is new.
+    /// let summary = guesser.last_update_summary();
+    /// assert_eq!(summary.newly_present, vec!['a']);
+    /// assert_eq!(summary.newly_placed, vec![LocatedLetter::new('a', 0)]);
+    /// assert!(summary.newly_absent.is_empty());
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    fn last_update_summary(&self) -> UpdateSummary {
+        UpdateSummary::default()
+    }
+}
+
+impl<G: Guesser + ?Sized> Guesser for Box<G> {
+    fn update(&mut self, result: &GuessResult) -> Result<(), WordleError> {
+        (**self).update(result)
+    }
+
+    fn update_returning_eliminated(
+        &mut self,
+        result: &GuessResult,
+    ) -> Result<Vec<Arc<str>>, WordleError> {
+        (**self).update_returning_eliminated(result)
+    }
+
+    fn update_partial(&mut self, result: &PartialGuessResult) -> Result<(), WordleError> {
+        (**self).update_partial(result)
+    }
+
+    fn select_next_guess(&mut self) -> Option<Arc<str>> {
+        (**self).select_next_guess()
+    }
+
+    fn select_next_guess_from(&mut self, from: GuessFrom) -> Option<Arc<str>> {
+        (**self).select_next_guess_from(from)
+    }
+
+    fn possible_words(&self) -> &[Arc<str>] {
+        (**self).possible_words()
+    }
+
+    fn remaining_count(&self) -> usize {
+        (**self).remaining_count()
+    }
+
+    fn guess_word(&mut self, word: &str) -> Result<(), WordleError> {
+        (**self).guess_word(word)
+    }
+
+    fn guess_history(&self) -> &[Arc<str>] {
+        (**self).guess_history()
+    }
+
+    fn last_update_summary(&self) -> UpdateSummary {
+        (**self).last_update_summary()
+    }
+}
+
+/// A [`Guesser`] that can be cloned as a boxed trait object.
+///
+/// This is implemented for every guesser that implements [`Clone`], including the built-in
+/// guessers. It allows storing different kinds of guessers together, and cloning each of them
+/// to play a new game.
+///
+/// ```
+/// use rs_wordle_solver::CloneableGuesser;
+/// use rs_wordle_solver::GameResult;
+/// use rs_wordle_solver::GuessFrom;
+/// use rs_wordle_solver::MaxScoreGuesser;
+/// use rs_wordle_solver::RandomGuesser;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::play_game_with_guesser;
+/// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"])?;
+/// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+/// let guessers: Vec<Box<dyn CloneableGuesser>> = vec![
+///     Box::new(RandomGuesser::new(bank.clone())),
+///     Box::new(MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer)),
+/// ];
+///
+/// for guesser in &guessers {
+///     for word in bank.iter() {
+///         let result = play_game_with_guesser(word, 3, guesser.clone())?;
+///         assert!(matches!(result, GameResult::Success(_)));
+///     }
+/// }
+/// # Ok::<(), WordleError>(())
+/// ```
+pub trait CloneableGuesser: Guesser {
+    /// Clones this guesser into a new box.
+    fn box_clone(&self) -> Box<dyn CloneableGuesser>;
+}
+
+impl<G: Guesser + Clone + 'static> CloneableGuesser for G {
+    fn box_clone(&self) -> Box<dyn CloneableGuesser> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn CloneableGuesser> {
+    fn clone(&self) -> Self {
+        (**self).box_clone()
+    }
+}
+
+/// Returns a [`WordleError::WordLength`] error if `word` is a different length to the given words.
+fn check_guess_length(words: &GroupedWords, word: &str) -> Result<(), WordleError> {
+    match words.all_words.first() {
+        Some(first_word) if first_word.chars().count() != word.chars().count() => {
+            Err(WordleError::WordLength(first_word.chars().count()))
+        }
+        _ => Ok(()),
+    }
 }
 
 /// Attempts to guess the given word within the maximum number of guesses, using the given word
 /// guesser.
 ///
+/// Returns an error if the guesser fails to update with the result of one of its guesses.
+///
 /// ```
 /// use rs_wordle_solver::GameResult;
 /// use rs_wordle_solver::RandomGuesser;
 /// use rs_wordle_solver::WordBank;
 /// use rs_wordle_solver::play_game_with_guesser;
+/// # use rs_wordle_solver::WordleError;
 ///
-/// let bank = WordBank::from_iterator(&["abc", "def", "ghi"]).unwrap();
+/// let bank = WordBank::from_iterator(&["abc", "def", "ghi"])?;
 /// let mut guesser = RandomGuesser::new(bank);
-/// let result = play_game_with_guesser("def", 4, guesser.clone());
+/// let result = play_game_with_guesser("def", 4, guesser.clone())?;
 ///
 /// assert!(matches!(result, GameResult::Success(_guesses)));
 ///
-/// let result = play_game_with_guesser("zzz", 4, guesser.clone());
+/// let result = play_game_with_guesser("zzz", 4, guesser.clone())?;
 ///
 /// assert!(matches!(result, GameResult::UnknownWord));
 ///
-/// let result = play_game_with_guesser("other", 4, guesser);
+/// let result = play_game_with_guesser("other", 4, guesser)?;
 ///
 /// assert!(matches!(result, GameResult::UnknownWord));
+/// # Ok::<(), WordleError>(())
 /// ```
 pub fn play_game_with_guesser<G: Guesser>(
     word_to_guess: &str,
     max_num_guesses: u32,
+    guesser: G,
+) -> Result<GameResult, WordleError> {
+    play_game_with_guesser_timed(word_to_guess, max_num_guesses, Duration::MAX, guesser)
+}
+
+/// Attempts to guess the given word within the maximum number of guesses and the given time
+/// budget, using the given word guesser.
+///
+/// The time budget is checked before and after selecting each guess, so a single slow guess is not
+/// interrupted. If the budget is exceeded, this returns [`GameResult::TimedOut`] with the turns
+/// that were completed in time.
+///
+/// Returns an error if the guesser fails to update with the result of one of its guesses.
+///
+/// ```
+/// use std::time::Duration;
+/// use rs_wordle_solver::GameResult;
+/// use rs_wordle_solver::RandomGuesser;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::play_game_with_guesser_timed;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let bank = WordBank::from_iterator(&["abc", "def", "ghi"])?;
+/// let guesser = RandomGuesser::new(bank);
+///
+/// let result = play_game_with_guesser_timed("def", 4, Duration::from_secs(60), guesser.clone())?;
+/// assert!(matches!(result, GameResult::Success(_guesses)));
+///
+/// let result = play_game_with_guesser_timed("def", 4, Duration::ZERO, guesser)?;
+/// assert!(matches!(result, GameResult::TimedOut(_guesses)));
+/// # Ok::<(), WordleError>(())
+/// ```
+pub fn play_game_with_guesser_timed<G: Guesser>(
+    word_to_guess: &str,
+    max_num_guesses: u32,
+    timeout: Duration,
     mut guesser: G,
-) -> GameResult {
+) -> Result<GameResult, WordleError> {
+    // Don't check the time unless needed, since not all platforms support it.
+    let start = (timeout != Duration::MAX).then(Instant::now);
+    let is_timed_out = || start.is_some_and(|start| start.elapsed() >= timeout);
     let mut turns: Vec<TurnData> = Vec::new();
+    let mut restrictions = WordRestrictions::new(word_to_guess.chars().count() as u8);
     for _ in 1..=max_num_guesses {
+        if is_timed_out() {
+            return Ok(GameResult::TimedOut(GameData { turns }));
+        }
         let maybe_guess = guesser.select_next_guess();
         if maybe_guess.is_none() {
-            return GameResult::UnknownWord;
+            return Ok(GameResult::UnknownWord);
+        }
+        if is_timed_out() {
+            return Ok(GameResult::TimedOut(GameData { turns }));
         }
         let guess = maybe_guess.unwrap();
         let num_possible_words_before_guess = guesser.possible_words().len();
         let result = get_result_for_guess(word_to_guess, guess.as_ref());
         if result.is_err() {
-            return GameResult::UnknownWord;
+            return Ok(GameResult::UnknownWord);
         }
         let result = result.unwrap();
         turns.push(TurnData {
             num_possible_words_before_guess,
             guess: Box::from(guess.as_ref()),
+            restrictions: Some(restrictions.clone()),
         });
         if result.results.iter().all(|lr| *lr == LetterResult::Correct) {
-            return GameResult::Success(GameData { turns });
+            return Ok(GameResult::Success(GameData { turns }));
         }
-        guesser.update(&result).unwrap();
+        restrictions.update(&result)?;
+        guesser.update(&result)?;
     }
-    GameResult::Failure(GameData { turns })
+    Ok(GameResult::Failure(GameData { turns }))
 }
 
-/// Guesses at random from the possible words that meet the restrictions.
+/// Plays an adversarial game, in the style of Absurdle, where there is no fixed word to guess.
 ///
-/// A sample benchmark against the `data/improved-words.txt` list performed as follows:
+/// Instead, after each guess the host picks the result that leaves the most words in the bank
+/// possible. Ties are broken in favour of results that don't win the game, and then by the lowest
+/// [`CompressedGuessResult`](crate::details::CompressedGuessResult) bits. The game is won once the
+/// host has only one possible word left and the guesser guesses it. This measures how well a
+/// guesser handles its worst cases.
 ///
-/// |Num guesses to win|Num games|
-/// |------------------|---------|
-/// |1|1|
-/// |2|106|
-/// |3|816|
-/// |4|1628|
-/// |5|1248|
-/// |6|518|
-/// |7|180|
-/// |8|67|
-/// |9|28|
-/// |10|7|
-/// |11|2|
-/// |12|1|
+/// Returns [`GameResult::Failure`] if the guesser doesn't win within `max_num_guesses`. Returns
+/// [`GameResult::UnknownWord`] if the guesser has no guess to make, or picks a guess that is a
+/// different length to the words in the bank. Returns an error if the guesser fails to update
+/// with the result of one of its guesses.
 ///
-/// **Average number of guesses:** 4.49 +/- 1.26
-#[derive(Debug, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct RandomGuesser {
-    words: GroupedWords,
-    restrictions: WordRestrictions,
+/// ```
+/// use rs_wordle_solver::GameResult;
+/// use rs_wordle_solver::GuessFrom;
+/// use rs_wordle_solver::MaxScoreGuesser;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::play_adversarial_game;
+/// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let bank = WordBank::from_iterator(&["abc", "abd", "abe"])?;
+/// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+/// let guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank.clone(), scorer);
+///
+/// // The host avoids the guessed word until only one word is left.
+/// let result = play_adversarial_game(&bank, 6, guesser)?;
+///
+/// assert!(matches!(result, GameResult::Success(data) if data.turns.len() == 3));
+/// # Ok::<(), WordleError>(())
+/// ```
+pub fn play_adversarial_game<G: Guesser>(
+    bank: &WordBank,
+    max_num_guesses: u32,
+    mut guesser: G,
+) -> Result<GameResult, WordleError> {
+    let mut turns: Vec<TurnData> = Vec::new();
+    let mut restrictions = WordRestrictions::new(bank.word_length() as u8);
+    let mut possible_words: Vec<Arc<str>> = bank.to_vec();
+    for _ in 1..=max_num_guesses {
+        let guess = match guesser.select_next_guess() {
+            Some(guess) if guess.chars().count() == bank.word_length() => guess,
+            _ => return Ok(GameResult::UnknownWord),
+        };
+        let num_possible_words_before_guess = possible_words.len();
+        let (results, remaining_words) = match most_possible_result(&guess, &possible_words)? {
+            Some(group) => group,
+            None => return Ok(GameResult::UnknownWord),
+        };
+        turns.push(TurnData {
+            num_possible_words_before_guess,
+            guess: Box::from(guess.as_ref()),
+            restrictions: Some(restrictions.clone()),
+        });
+        if results.iter().all(|lr| *lr == LetterResult::Correct) {
+            return Ok(GameResult::Success(GameData { turns }));
+        }
+        let result = GuessResult {
+            guess: &guess,
+            results,
+        };
+        restrictions.update(&result)?;
+        guesser.update(&result)?;
+        possible_words = remaining_words;
+    }
+    Ok(GameResult::Failure(GameData { turns }))
 }
 
-impl RandomGuesser {
-    /// Constructs a new `RandomGuesser` using the given word bank.
+/// A guess result, and the possible words that would give that result.
+type ResultGroup = (Vec<LetterResult>, Vec<Arc<str>>);
+
+/// Groups the possible words by the result they give for the guess, and returns the result that
+/// leaves the most words possible along with those words, as described by
+/// [`play_adversarial_game()`]. Returns `None` if there are no possible words.
+fn most_possible_result(
+    guess: &str,
+    possible_words: &[Arc<str>],
+) -> Result<Option<ResultGroup>, WordleError> {
+    let mut groups: HashMap<CompressedGuessResult, ResultGroup> = HashMap::new();
+    for word in possible_words {
+        let result = get_result_for_guess(word, guess)?;
+        groups
+            .entry(CompressedGuessResult::from_results(&result.results)?)
+            .or_insert_with(|| (result.results, Vec::new()))
+            .1
+            .push(Arc::clone(word));
+    }
+    Ok(groups
+        .into_iter()
+        .max_by(
+            |(a_bits, (a_results, a_words)), (b_bits, (b_results, b_words))| {
+                let is_win = |results: &[LetterResult]| {
+                    results.iter().all(|lr| *lr == LetterResult::Correct)
+                };
+                a_words
+                    .len()
+                    .cmp(&b_words.len())
+                    .then_with(|| is_win(b_results).cmp(&is_win(a_results)))
+                    .then_with(|| b_bits.to_bits().cmp(&a_bits.to_bits()))
+            },
+        )
+        .map(|(_, group)| group))
+}
+
+/// Plays a game against every word in the bank using clones of the given guesser, and returns how
+/// many words needed each number of guesses.
+///
+/// Each game is allowed up to as many guesses as there are words in the bank. Games that the
+/// guesser fails to solve are not included in the distribution. Games are played in parallel.
+///
+/// ```
+/// use rs_wordle_solver::MaxScoreGuesser;
+/// use rs_wordle_solver::GuessFrom;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+/// use rs_wordle_solver::strategy_depth_distribution;
+///
+/// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"]).unwrap();
+/// let scorer = MaxEliminationsScorer::new(bank.clone()).unwrap();
+/// let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+///
+/// let distribution = strategy_depth_distribution(&bank, &guesser);
+///
+/// assert_eq!(distribution.values().sum::<usize>(), bank.len());
+/// ```
+pub fn strategy_depth_distribution<G>(bank: &WordBank, base_guesser: &G) -> HashMap<u32, usize>
+where
+    G: Guesser + Clone + Sync,
+{
+    let max_num_guesses = bank.len() as u32;
+    bank.par_iter()
+        .filter_map(|objective| {
+            match play_game_with_guesser(objective, max_num_guesses, base_guesser.clone()) {
+                Ok(GameResult::Success(data)) => Some(data.num_turns() as u32),
+                _ => None,
+            }
+        })
+        .fold(HashMap::new, |mut distribution, num_guesses| {
+            *distribution.entry(num_guesses).or_insert(0) += 1;
+            distribution
+        })
+        .reduce(HashMap::new, |mut distribution, other| {
+            for (num_guesses, count) in other {
+                *distribution.entry(num_guesses).or_insert(0) += count;
+            }
+            distribution
+        })
+}
+
+/// Plays a game against each of the given objectives using clones of the given guesser, and
+/// returns the results in the same order as the objectives.
+///
+/// Any state in the base guesser is kept by each clone, so precomputing the first guess with
+/// [`MaxScoreGuesser::compute_scores_if_unknown()`] avoids recomputing it for every objective.
+/// Games are played in parallel.
+///
+/// Returns the first error from any game.
+///
+/// ```
+/// use rs_wordle_solver::GuessFrom;
+/// use rs_wordle_solver::MaxScoreGuesser;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::solve_all;
+/// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"])?;
+/// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+/// let mut base_guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+/// // Compute the first guess once, rather than once per game.
+/// base_guesser.compute_scores_if_unknown();
+///
+/// let results = solve_all(&["bcd", "abc", "zzz"], 4, &base_guesser)?;
+///
+/// assert!(results[0].is_success());
+/// assert!(results[1].is_success());
+/// assert!(!results[2].is_success());
+/// # Ok::<(), WordleError>(())
+/// ```
+pub fn solve_all<G, S>(
+    objectives: &[S],
+    max_num_guesses: u32,
+    base_guesser: &G,
+) -> Result<Vec<GameResult>, WordleError>
+where
+    G: Guesser + Clone + Sync,
+    S: AsRef<str> + Sync,
+{
+    objectives
+        .par_iter()
+        .map(|objective| {
+            play_game_with_guesser(objective.as_ref(), max_num_guesses, base_guesser.clone())
+        })
+        .collect()
+}
+
+/// Plays a game against each of the given answers using clones of the given guesser, and returns
+/// the statistics for all the games.
+///
+/// This evaluates a complete strategy at once: the [`GameStats`] give both the average case, via
+/// [`GameStats::mean_guesses()`], and the worst case, via [`GameStats::worst_case_guesses()`]. As
+/// with [`solve_all()`], any state in the base guesser is kept by each clone, and games are played
+/// in parallel.
+///
+/// Games that aren't solved within `max_num_guesses`, including any game in which the guesser
+/// returns an error, count as unsolved.
+///
+/// ```
+/// use rs_wordle_solver::GuessFrom;
+/// use rs_wordle_solver::MaxScoreGuesser;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::evaluate_strategy;
+/// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let bank = WordBank::from_iterator(&["abc", "abd", "bcd", "cde"])?;
+/// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+/// let mut base_guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+/// base_guesser.compute_scores_if_unknown();
+///
+/// let stats = evaluate_strategy(&["abc", "abd", "bcd", "cde"], &base_guesser, 6);
+///
+/// assert_eq!(stats.num_solved(), 4);
+/// assert!(stats.mean_guesses() < 3.0);
+/// assert!(stats.worst_case_guesses().unwrap() <= 3);
+/// # Ok::<(), WordleError>(())
+/// ```
+pub fn evaluate_strategy<G, S>(answers: &[S], base_guesser: &G, max_num_guesses: u32) -> GameStats
+where
+    G: Guesser + Clone + Sync,
+    S: AsRef<str> + Sync,
+{
+    answers
+        .par_iter()
+        .map(|answer| {
+            play_game_with_guesser(answer.as_ref(), max_num_guesses, base_guesser.clone())
+        })
+        .fold(GameStats::default, |mut stats, result| {
+            match result {
+                Ok(result) => stats.add_result(&result),
+                Err(_) => stats.num_unsolved += 1,
+            }
+            stats
+        })
+        .reduce(GameStats::default, |mut stats, other| {
+            for (num_guesses, num_games) in other.histogram {
+                *stats.histogram.entry(num_guesses).or_insert(0) += num_games;
+            }
+            stats.num_unsolved += other.num_unsolved;
+            stats
+        })
+}
+
+/// Plays a game against each of the given words using a new guesser from `guesser_factory`, and
+/// sends each word with its result to `tx` as soon as that game finishes.
+///
+/// Games are played in parallel, so results may be sent in any order. Each game is allowed up to
+/// as many guesses as there are words in the bank. Results for which the receiver has been dropped
+/// are discarded.
+///
+/// This blocks the calling thread until every game has finished. To handle results while the
+/// games are still running, call this from another thread, such as one spawned with
+/// [`std::thread::scope()`], and receive the results on the current thread.
+///
+/// Returns the first error from any game, after which no more games are started.
+///
+/// ```
+/// use std::sync::mpsc;
+/// use rs_wordle_solver::GuessFrom;
+/// use rs_wordle_solver::MaxScoreGuesser;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::run_benchmark_streaming;
+/// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"])?;
+/// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+/// let mut base_guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+/// // Compute the first guess once, rather than once per game.
+/// base_guesser.compute_scores_if_unknown();
+///
+/// let (tx, rx) = mpsc::channel();
+/// run_benchmark_streaming(&bank, || base_guesser.clone(), &bank, tx)?;
+///
+/// let mut solved_words: Vec<String> = rx
+///     .iter()
+///     .filter(|(_, result)| result.is_success())
+///     .map(|(word, _)| word)
+///     .collect();
+/// solved_words.sort();
+///
+/// assert_eq!(solved_words, vec!["abc", "abd", "bcd"]);
+/// # Ok::<(), WordleError>(())
+/// ```
+pub fn run_benchmark_streaming<G, F, S>(
+    bank: &WordBank,
+    guesser_factory: F,
+    words: &[S],
+    tx: Sender<(String, GameResult)>,
+) -> Result<(), WordleError>
+where
+    G: Guesser,
+    F: Fn() -> G + Sync,
+    S: AsRef<str> + Sync,
+{
+    let max_num_guesses = bank.len() as u32;
+    words.par_iter().try_for_each_with(tx, |tx, word| {
+        let word = word.as_ref();
+        let result = play_game_with_guesser(word, max_num_guesses, guesser_factory())?;
+        // The receiver may have stopped listening, in which case the result isn't needed.
+        let _ = tx.send((word.to_string(), result));
+        Ok(())
+    })
+}
+
+/// Verifies that the given sequence of guesses is a valid solution path for the objective.
+///
+/// Each guess is replayed against the objective, and its result is added to a set of
+/// [`WordRestrictions`]. The path is valid if every result is consistent with the previous ones,
+/// the objective satisfies the restrictions after each guess, and the final guess (and only the
+/// final guess) is the objective.
+///
+/// Returns a [`WordleError::WordLength`] error if any guess has a different length to the
+/// objective.
+///
+/// ```
+/// use rs_wordle_solver::verify_path;
+/// # use rs_wordle_solver::WordleError;
+///
+/// assert!(verify_path("abc", &["bcd", "adc", "abc"])?);
+/// assert!(!verify_path("abc", &["bcd", "adc"])?);
+/// assert!(verify_path("abc", &["abcd"]).is_err());
+/// # Ok::<(), WordleError>(())
+/// ```
+pub fn verify_path(objective: &str, guesses: &[&str]) -> Result<bool, WordleError> {
+    let mut restrictions = WordRestrictions::new(objective.chars().count() as u8);
+    for (index, guess) in guesses.iter().enumerate() {
+        let result = get_result_for_guess(objective, guess)?;
+        if restrictions.update(&result).is_err() || !restrictions.is_satisfied_by(objective) {
+            return Ok(false);
+        }
+        let is_last_guess = index == guesses.len() - 1;
+        if (*guess == objective) != is_last_guess {
+            return Ok(false);
+        }
+    }
+    Ok(!guesses.is_empty())
+}
+
+/// Selects the guesser's next guess, along with a short, human-readable reason for it.
+///
+/// The reason summarizes how many words are currently possible, how many words are expected to
+/// remain on average after this guess (assuming each possible word is equally likely to be the
+/// objective), and which letters of the guess are in some, but not all, of the possible words.
+///
+/// Returns `None` if the guesser has no guess to make. The average number of remaining words is
+/// left out if the words are too long for their results to be compressed.
+///
+/// ```
+/// use rs_wordle_solver::GuessFrom;
+/// use rs_wordle_solver::MaxScoreGuesser;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+/// use rs_wordle_solver::suggest_with_reason;
+///
+/// let bank = WordBank::from_iterator(&["cod", "wod", "mod", "cat"]).unwrap();
+/// let scorer = MaxEliminationsScorer::new(bank.clone()).unwrap();
+/// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+///
+/// let (guess, reason) = suggest_with_reason(&mut guesser).unwrap();
+///
+/// assert_eq!(guess.as_ref(), "cod");
+/// assert_eq!(
+///     reason,
+///     "narrows 4 possibilities to ~1.5 on average; could be the answer; tests letters c,o,d"
+/// );
+/// ```
+pub fn suggest_with_reason<G: Guesser>(guesser: &mut G) -> Option<(Arc<str>, String)> {
+    let guess = guesser.select_next_guess()?;
+    let possible_words = guesser.possible_words();
+    let num_possible_words = possible_words.len();
+    if num_possible_words == 1 {
+        return Some((guess, "the only remaining possibility".to_string()));
+    }
+
+    let mut reason = format!("narrows {num_possible_words} possibilities");
+    if num_possible_words > 0 {
+        if let Ok(num_words_by_result) = count_words_by_result(&guess, possible_words) {
+            let sum_of_squares: usize = num_words_by_result
+                .into_values()
+                .map(|count| count * count)
+                .sum();
+            let expected_remaining = sum_of_squares as f64 / num_possible_words as f64;
+            reason.push_str(&format!(" to ~{expected_remaining:.1} on average"));
+        }
+    }
+    if possible_words.contains(&guess) {
+        reason.push_str("; could be the answer");
+    }
+
+    let mut tested_letters: Vec<char> = Vec::new();
+    for letter in guess.chars() {
+        if tested_letters.contains(&letter) {
+            continue;
+        }
+        let num_containing = possible_words
+            .iter()
+            .filter(|word| word.contains(letter))
+            .count();
+        if num_containing > 0 && num_containing < num_possible_words {
+            tested_letters.push(letter);
+        }
+    }
+    if !tested_letters.is_empty() {
+        let letters: Vec<String> = tested_letters.iter().map(char::to_string).collect();
+        reason.push_str(&format!("; tests letters {}", letters.join(",")));
+    }
+    Some((guess, reason))
+}
+
+/// Guesses at random from the possible words that meet the restrictions.
+///
+/// A sample benchmark against the `data/improved-words.txt` list performed as follows:
+///
+/// |Num guesses to win|Num games|
+/// |------------------|---------|
+/// |1|1|
+/// |2|106|
+/// |3|816|
+/// |4|1628|
+/// |5|1248|
+/// |6|518|
+/// |7|180|
+/// |8|67|
+/// |9|28|
+/// |10|7|
+/// |11|2|
+/// |12|1|
+///
+/// **Average number of guesses:** 4.49 +/- 1.26
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RandomGuesser {
+    words: GroupedWords,
+    restrictions: WordRestrictions,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    rng: Option<StdRng>,
+    /// The unguessed words in a fixed random order, if guesses are chosen without replacement.
+    shuffled_words: Option<Vec<Arc<str>>>,
+    guess_history: Vec<Arc<str>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    last_update_summary: UpdateSummary,
+    parallelisation_limit: usize,
+}
+
+impl RandomGuesser {
+    /// Constructs a new `RandomGuesser` using the given word bank.
+    ///
+    /// This guesser always uses the `GuessFrom::PossibleWords` strategy by default.
+    ///
+    /// ```
+    /// use rs_wordle_solver::RandomGuesser;
+    /// use rs_wordle_solver::WordBank;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"]).unwrap();
+    /// let guesser = RandomGuesser::new(bank);
+    /// ```
+    pub fn new(bank: WordBank) -> RandomGuesser {
+        let word_length = bank.word_length();
+        RandomGuesser {
+            words: GroupedWords::new(bank),
+            restrictions: WordRestrictions::new(word_length as u8),
+            rng: None,
+            shuffled_words: None,
+            guess_history: Vec::new(),
+            last_update_summary: UpdateSummary::default(),
+            parallelisation_limit: default_parallelisation_limit(),
+        }
+    }
+
+    /// Constructs a new `RandomGuesser` that shuffles the word bank once with the given random
+    /// number generator, and then guesses words in that order. Guessing from
+    /// [`GuessFrom::PossibleWords`] skips any words that are no longer possible, while guessing
+    /// from [`GuessFrom::AllUnguessedWords`] only skips words that were already guessed, so it may
+    /// return impossible words. Unlike [`Self::new()`], this never guesses the same word twice, so
+    /// each game follows a single permutation of the bank.
+    ///
+    /// ```
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::RandomGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"])?;
+    /// let mut guesser = RandomGuesser::shuffled(bank, &mut StdRng::seed_from_u64(7));
+    ///
+    /// let mut guesses = Vec::new();
+    /// while let Some(guess) = guesser.select_next_guess_from(GuessFrom::AllUnguessedWords) {
+    ///     guesser.update(&get_result_for_guess("xyz", &guess)?)?;
+    ///     guesses.push(guess.to_string());
+    /// }
+    /// guesses.sort();
+    ///
+    /// assert_eq!(guesses, vec!["abc", "def", "ghi"]);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn shuffled<R: Rng + ?Sized>(bank: WordBank, rng: &mut R) -> RandomGuesser {
+        let mut shuffled_words = bank.to_vec();
+        shuffled_words.shuffle(rng);
+        RandomGuesser {
+            shuffled_words: Some(shuffled_words),
+            ..RandomGuesser::new(bank)
+        }
+    }
+
+    /// Seeds the random number generator used to select guesses, so that the same sequence of
+    /// updates always produces the same guesses. By default, guesses are selected using the
+    /// thread-local random number generator. Without the `std` feature, the guesser instead seeds
+    /// its own generator from the standard library's randomly seeded `HashMap` keys.
+    ///
+    /// The generator's state is not serialized, so a deserialized guesser reverts to the default.
+    ///
+    /// ```
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::RandomGuesser;
+    /// use rs_wordle_solver::WordBank;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi", "jkl"]).unwrap();
+    /// let mut guesser = RandomGuesser::new(bank.clone()).with_seed(7);
+    /// let mut other_guesser = RandomGuesser::new(bank).with_seed(7);
+    ///
+    /// assert_eq!(guesser.select_next_guess(), other_guesser.select_next_guess());
+    /// ```
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = Some(StdRng::seed_from_u64(seed));
+        self
+    }
+
+    /// Sets the parallelisation limit. The possible words are filtered in parallel after each
+    /// update if there are more than this many. The default setting is the result of
+    /// `std::thread::available_parallelism`, or `usize::MAX` if the `parallel` feature is
+    /// disabled.
+    ///
+    /// ```
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::RandomGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "xyz"])?;
+    /// let mut guesser = RandomGuesser::new(bank).with_parallelisation_limit(1);
+    ///
+    /// guesser.update(&get_result_for_guess("abd", "abc")?)?;
+    ///
+    /// assert_eq!(guesser.possible_words().len(), 1);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn with_parallelisation_limit(mut self, parallelisation_limit: usize) -> Self {
+        self.parallelisation_limit = parallelisation_limit;
+        self
+    }
+
+    /// Sets how repeated letters are marked in the results this guesser is updated with. The
+    /// default is [`DuplicateMode::Standard`]. See [`WordRestrictions::with_duplicate_mode()`].
+    ///
+    /// ```
+    /// use rs_wordle_solver::DuplicateMode;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::RandomGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::get_result_for_guess_with;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["aaxa", "aazz", "abcd"])?;
+    /// let mut guesser = RandomGuesser::new(bank).with_duplicate_mode(DuplicateMode::Simple);
+    ///
+    /// guesser.update(&get_result_for_guess_with("abcd", "aaxa", DuplicateMode::Simple)?)?;
+    ///
+    /// assert_eq!(guesser.select_next_guess().as_deref(), Some("abcd"));
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn with_duplicate_mode(mut self, duplicate_mode: DuplicateMode) -> Self {
+        self.restrictions = self.restrictions.with_duplicate_mode(duplicate_mode);
+        self
+    }
+
+    #[cfg(feature = "std")]
+    fn select_random_word(words: &[Arc<str>], rng: &mut Option<StdRng>) -> Option<Arc<str>> {
+        match rng {
+            Some(rng) => words.choose(rng),
+            None => words.choose(&mut rand::thread_rng()),
+        }
+        .map(Arc::clone)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn select_random_word(words: &[Arc<str>], rng: &mut Option<StdRng>) -> Option<Arc<str>> {
+        // There is no thread-local generator without `std`, so seed one for this guesser.
+        let rng = rng.get_or_insert_with(|| StdRng::seed_from_u64(random_seed()));
+        words.choose(rng).map(Arc::clone)
+    }
+
+    /// Updates the restrictions with the given function, and then removes any words that are no
+    /// longer possible. Returns the removed words.
+    fn update_with<F>(
+        &mut self,
+        guess: &str,
+        update_restrictions: F,
+    ) -> Result<Vec<Arc<str>>, WordleError>
+    where
+        F: FnOnce(&mut WordRestrictions) -> Result<(), WordleError>,
+    {
+        let previous_restrictions = self.restrictions.clone();
+        update_restrictions(&mut self.restrictions)?;
+        self.last_update_summary = self.restrictions.changes_since(&previous_restrictions);
+        self.guess_history.push(Arc::from(guess));
+        let eliminated = self
+            .words
+            .filter_possible_words_matching(&self.restrictions, self.parallelisation_limit);
+        if let Some(shuffled_words) = &mut self.shuffled_words {
+            shuffled_words.retain(|word| word.as_ref() != guess);
+        }
+        Ok(eliminated)
+    }
+}
+
+/// Returns a new random seed from the randomly seeded keys that the standard library uses for
+/// `HashMap`s. Unlike `rand::thread_rng()`, this works on every platform, even without a source
+/// of randomness. On such platforms, it may return the same seed each time.
+#[cfg(not(feature = "std"))]
+fn random_seed() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+impl Guesser for RandomGuesser {
+    fn update(&mut self, result: &GuessResult) -> Result<(), WordleError> {
+        self.update_returning_eliminated(result).map(|_| ())
+    }
+
+    fn update_returning_eliminated(
+        &mut self,
+        result: &GuessResult,
+    ) -> Result<Vec<Arc<str>>, WordleError> {
+        self.update_with(result.guess, |restrictions| restrictions.update(result))
+    }
+
+    fn update_partial(&mut self, result: &PartialGuessResult) -> Result<(), WordleError> {
+        self.update_with(result.guess, |restrictions| {
+            restrictions.update_partial(result)
+        })
+        .map(|_| ())
+    }
+
+    fn select_next_guess(&mut self) -> Option<Arc<str>> {
+        self.select_next_guess_from(GuessFrom::PossibleWords)
+    }
+
+    fn select_next_guess_from(&mut self, from: GuessFrom) -> Option<Arc<str>> {
+        if let Some(shuffled_words) = &self.shuffled_words {
+            return match from {
+                GuessFrom::AllUnguessedWords => shuffled_words.first(),
+                GuessFrom::PossibleWords => shuffled_words
+                    .iter()
+                    .find(|word| self.restrictions.is_satisfied_by(word)),
+            }
+            .map(Arc::clone);
+        }
+        match from {
+            GuessFrom::AllUnguessedWords => {
+                RandomGuesser::select_random_word(self.words.unguessed_words(), &mut self.rng)
+            }
+            GuessFrom::PossibleWords => {
+                RandomGuesser::select_random_word(self.words.possible_words(), &mut self.rng)
+            }
+        }
+    }
+
+    fn possible_words(&self) -> &[Arc<str>] {
+        self.words.possible_words()
+    }
+
+    fn guess_word(&mut self, word: &str) -> Result<(), WordleError> {
+        check_guess_length(&self.words, word)?;
+        self.words.remove_guess_if_present(word);
+        if let Some(shuffled_words) = &mut self.shuffled_words {
+            shuffled_words.retain(|shuffled_word| shuffled_word.as_ref() != word);
+        }
+        Ok(())
+    }
+
+    fn guess_history(&self) -> &[Arc<str>] {
+        &self.guess_history
+    }
+
+    fn last_update_summary(&self) -> UpdateSummary {
+        self.last_update_summary.clone()
+    }
+}
+
+/// Represents a guess with a 'score' estimating how useful the guess is. Higher scores are better.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScoredGuess {
+    pub score: i64,
+    pub guess: Arc<str>,
+}
+
+/// A [`ScoredGuess`] with a human-readable explanation of its score, if the scorer provides one.
+/// See [`MaxScoreGuesser::select_top_n_guesses_detailed()`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DetailedGuess {
+    pub score: i64,
+    pub guess: Arc<str>,
+    /// The scorer's explanation of the score. See [`WordScorer::explain()`].
+    pub explanation: Option<String>,
+}
+
+/// A secondary ranking for guesses with equal scores. See [`MaxScoreGuesser::with_tiebreak()`].
+#[derive(Clone)]
+struct Tiebreak(Arc<dyn Fn(&str) -> i64 + Send + Sync>);
+
+impl fmt::Debug for Tiebreak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Tiebreak")
+    }
+}
+
+/// Selects the next guess that maximizes the score according to the owned scorer.
+///
+/// See [`WordScorer`] for more information about possible scoring algorithms.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxScoreGuesser<T>
+where
+    T: WordScorer + Clone + Sync,
+{
+    default_guess_mode: GuessFrom,
+    grouped_words: GroupedWords,
+    restrictions: WordRestrictions,
+    scorer: T,
+    parallelisation_limit: usize,
+    repeated_letter_penalty: i64,
+    allow_reguesses: bool,
+    precise_ranking: bool,
+    hard_mode: bool,
+    hard_mode_words: Vec<Arc<str>>,
+    all_unguessed_word_scores: Option<Vec<f64>>,
+    possible_word_scores: Option<Vec<f64>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    tiebreak: Option<Tiebreak>,
+    guess_history: Vec<Arc<str>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    last_update_summary: UpdateSummary,
+}
+
+impl<T> MaxScoreGuesser<T>
+where
+    T: WordScorer + Clone + Sync,
+{
+    /// Constructs a new `MaxScoreGuesser` that will guess the word with the maximum score
+    /// according to the given [`WordScorer`]. This will only score and guess from the words in the
+    /// `word_bank` according to the given `guess_from` strategy.
+    ///
+    /// If in doubt, you probably want to use `GuessFrom::AllUnguessedWords` for better performance.
+    ///
+    /// See [`WordScorer`] for more information about possible scoring algorithms.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["azz", "bzz", "czz", "abc"]).unwrap();
+    /// let scorer = MaxEliminationsScorer::new(bank.clone()).unwrap();
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// assert_eq!(guesser.select_next_guess(), Some(Arc::from("abc")));
+    /// ```
+    pub fn new(guess_mode: GuessFrom, word_bank: WordBank, scorer: T) -> MaxScoreGuesser<T> {
+        let word_length = word_bank.word_length();
+        Self {
+            default_guess_mode: guess_mode,
+            grouped_words: GroupedWords::new(word_bank),
+            restrictions: WordRestrictions::new(word_length as u8),
+            scorer,
+            parallelisation_limit: default_parallelisation_limit(),
+            repeated_letter_penalty: 0,
+            allow_reguesses: false,
+            precise_ranking: false,
+            hard_mode: false,
+            hard_mode_words: Vec::new(),
+            all_unguessed_word_scores: None,
+            possible_word_scores: None,
+            tiebreak: None,
+            guess_history: Vec::new(),
+            last_update_summary: UpdateSummary::default(),
+        }
+    }
+
+    /// Constructs a new `MaxScoreGuesser` like [`Self::new()`], but starting from the given
+    /// restrictions, as if they had been learned from previous guesses.
+    ///
+    /// This immediately filters the possible words, and updates the scorer with an empty latest
+    /// guess. This is handy for variants that start with some letters excluded, or for resuming a
+    /// game from persisted restrictions.
+    ///
+    /// Returns a [`WordleError::InvalidResults`] error if the restrictions are for a different
+    /// word length than the bank, or the scorer's error if it fails to update.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::details::WordRestrictionsBuilder;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "bcd", "cde"])?;
+    /// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    /// let no_vowels = WordRestrictionsBuilder::new(3)
+    ///     .with_absent('a')
+    ///     .with_absent('e')
+    ///     .build()?;
+    ///
+    /// let guesser =
+    ///     MaxScoreGuesser::new_with_restrictions(GuessFrom::PossibleWords, bank, scorer, no_vowels)?;
+    ///
+    /// assert_eq!(guesser.possible_words(), &[Arc::from("bcd")]);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn new_with_restrictions(
+        guess_mode: GuessFrom,
+        word_bank: WordBank,
+        scorer: T,
+        restrictions: WordRestrictions,
+    ) -> Result<MaxScoreGuesser<T>, WordleError> {
+        let mut guesser = Self::new(guess_mode, word_bank, scorer);
+        guesser.restrictions.merge(&restrictions)?;
+        guesser
+            .grouped_words
+            .filter_possible_words_matching(&guesser.restrictions, guesser.parallelisation_limit);
+        guesser.update_hard_mode_words();
+        guesser.scorer.update(
+            "",
+            &guesser.restrictions,
+            guesser.grouped_words.possible_words(),
+        )?;
+        Ok(guesser)
+    }
+
+    /// Sets the parallelisation limit. Various internal operations may be parallelised if operating
+    /// on lists larger than this limit. The default setting is the result of
+    /// `std::thread::available_parallelism`, or `usize::MAX` if the `parallel` feature is
+    /// disabled.
+    pub fn with_parallelisation_limit(mut self, parallelisation_limit: usize) -> Self {
+        self.parallelisation_limit = parallelisation_limit;
+        self
+    }
+
+    /// Sets how repeated letters are marked in the results this guesser is updated with. The
+    /// default is [`DuplicateMode::Standard`]. See [`WordRestrictions::with_duplicate_mode()`].
+    ///
+    /// This only changes which words remain possible. The scorer still predicts the results of
+    /// each guess with the [`DuplicateMode::Standard`] rules, so its guesses may be less well
+    /// chosen for other modes.
+    pub fn with_duplicate_mode(mut self, duplicate_mode: DuplicateMode) -> Self {
+        self.restrictions = self.restrictions.with_duplicate_mode(duplicate_mode);
+        self
+    }
+
+    /// Sets a penalty to subtract from a word's score for each repeated letter in that word, when
+    /// selecting a guess. Repeated letters are only penalised if the letter is not yet known to be
+    /// in the objective word, so the penalty relaxes as more letters are found. The default
+    /// penalty is `0`.
+    ///
+    /// This encourages guesses that check more distinct letters early in the game. Note that this
+    /// does not affect the scores returned by [`Self::get_or_compute_scores()`].
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["aab", "abc", "bcd"]).unwrap();
+    /// let scorer = MaxEliminationsScorer::new(bank.clone()).unwrap();
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer)
+    ///     .with_repeated_letter_penalty(2000);
+    ///
+    /// assert_eq!(guesser.select_next_guess(), Some(Arc::from("abc")));
+    /// ```
+    pub fn with_repeated_letter_penalty(mut self, penalty: i64) -> Self {
+        self.repeated_letter_penalty = penalty;
+        self
+    }
+
+    /// Sets whether words that have already been guessed may be guessed again. By default,
+    /// guessed words are excluded when guessing from [`GuessFrom::AllUnguessedWords`].
+    ///
+    /// This is useful for variants that allow repeat guesses. See also
+    /// [`Self::reguess_would_win()`].
+    pub fn with_reguesses_allowed(mut self, allow_reguesses: bool) -> Self {
+        self.allow_reguesses = allow_reguesses;
+        self
+    }
+
+    /// Sets whether to only guess words that are legal in Wordle's hard mode, i.e. words that reuse
+    /// all revealed hints (see [`WordRestrictions::is_hard_mode_guess()`]). The default is
+    /// `false`.
+    ///
+    /// This only affects guessing from [`GuessFrom::AllUnguessedWords`], since possible words
+    /// always reuse all revealed hints. If no unguessed words reuse all the hints, this falls back
+    /// to guessing from all unguessed words.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::GuessResult;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::LetterResult;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abcd", "abce", "abcf", "abcg", "defg", "aefg"])?;
+    /// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer)
+    ///     .with_hard_mode(true);
+    ///
+    /// guesser.update(&GuessResult {
+    ///     guess: "abcd",
+    ///     results: vec![
+    ///         LetterResult::Correct,
+    ///         LetterResult::Correct,
+    ///         LetterResult::Correct,
+    ///         LetterResult::NotPresent,
+    ///     ],
+    /// })?;
+    ///
+    /// // "defg" would split the remaining words best, but it doesn't reuse the hints.
+    /// assert!(guesser.select_next_guess().unwrap().starts_with("abc"));
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self.update_hard_mode_words();
+        self.all_unguessed_word_scores = None;
+        self
+    }
+
+    /// Sets whether to rank guesses using each word's precise score (see
+    /// [`WordScorer::score_word_precise()`]) instead of its integer score. The default is `false`.
+    ///
+    /// Some scorers compute fractional scores, and truncate them to integers. For large word
+    /// banks, this can make distinct scores tie. Precise ranking avoids this artificial tie. Scores
+    /// returned by this guesser are still truncated to integers.
+    pub fn with_precise_ranking(mut self, precise_ranking: bool) -> Self {
+        self.precise_ranking = precise_ranking;
+        self
+    }
+
+    /// Sets a secondary ranking for guesses with equal scores, such as how common each word is.
+    /// When selecting the top guesses, words with equal scores are ordered by this value, highest
+    /// first, and then by their order in the word bank. By default, ties are only ordered by the
+    /// word bank.
+    ///
+    /// This only affects [`Self::select_top_n_guesses()`] and related methods, not
+    /// [`Self::select_next_guess()`]. The tiebreak is not serialized.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxUniqueLetterFrequencyScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "bca", "cab"])?;
+    /// let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer)
+    ///     .with_tiebreak(|word| if word == "cab" { 1 } else { 0 });
+    ///
+    /// let top_guesses = guesser.select_top_n_guesses(3);
+    ///
+    /// assert_eq!(top_guesses[0].guess.as_ref(), "cab");
+    /// assert_eq!(top_guesses[1].guess.as_ref(), "abc");
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn with_tiebreak<F>(mut self, tiebreak: F) -> Self
+    where
+        F: Fn(&str) -> i64 + Send + Sync + 'static,
+    {
+        self.tiebreak = Some(Tiebreak(Arc::new(tiebreak)));
+        self
+    }
+
+    /// Sets the precomputed word scores based on the provided map. If the map is missing scores
+    /// for any words, they will be computed to fill the gaps. These scores will be used until the
+    /// next call to [`Self::update()`].
+    ///
+    /// This is handy to initialise the guesser with precomputed scores for the first guess. You
+    /// can retrieve this data by calling [`Self::get_or_compute_scores()`] on a newly constructed
+    /// guesser.
+    pub fn with_scores(mut self, scores: &HashMap<Arc<str>, i64>) -> Self {
+        let words_to_score = self.words_to_score(self.default_guess_mode);
+        let ordered_scores: Vec<f64> = words_to_score
+            .iter()
+            .map(|word| {
+                scores
+                    .get(word)
+                    .map(|score| *score as f64)
+                    .unwrap_or_else(|| {
+                        MaxScoreGuesser::score_word(word, &self.scorer, self.precise_ranking)
+                    })
+            })
+            .collect();
+        match self.default_guess_mode {
+            GuessFrom::AllUnguessedWords => {
+                self.all_unguessed_word_scores = Some(ordered_scores);
+            }
+            GuessFrom::PossibleWords => {
+                self.possible_word_scores = Some(ordered_scores);
+            }
+        }
+        self
+    }
+
+    /// Gets the score for each available guess, keyed by guess. This computes the scores if they
+    /// have not already been computed. The set of words is limited by the [`GuessFrom`] value used
+    /// in this guesser, and by the updates that have been provided so far.
+    pub fn get_or_compute_scores(&mut self) -> HashMap<Arc<str>, i64> {
+        self.compute_scores_if_unknown();
+        self.words_to_score(self.default_guess_mode)
+            .iter()
+            .zip(self.word_scores(self.default_guess_mode).unwrap().iter())
+            .map(|(word, score)| (Arc::clone(word), *score as i64))
+            .collect()
+    }
+
+    /// Returns the same scores as [`Self::get_or_compute_scores()`], sorted from highest to lowest
+    /// score, and then alphabetically by guess. Unlike the map, this has a deterministic order.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::ScoredGuess;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxUniqueLetterFrequencyScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["cab", "abc", "aab"]).unwrap();
+    /// let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// let guesses: Vec<Arc<str>> = guesser
+    ///     .scores_sorted()
+    ///     .into_iter()
+    ///     .map(|scored_guess| scored_guess.guess)
+    ///     .collect();
+    ///
+    /// assert_eq!(guesses, vec![Arc::from("abc"), Arc::from("cab"), Arc::from("aab")]);
+    /// ```
+    pub fn scores_sorted(&mut self) -> Vec<ScoredGuess> {
+        let mut scored_guesses: Vec<ScoredGuess> = self
+            .get_or_compute_scores()
+            .into_iter()
+            .map(|(guess, score)| ScoredGuess { score, guess })
+            .collect();
+        scored_guesses
+            .sort_unstable_by(|a, b| b.score.cmp(&a.score).then_with(|| a.guess.cmp(&b.guess)));
+        scored_guesses
+    }
+
+    /// Returns the scores that have already been computed for each available guess, keyed by
+    /// guess, or `None` if they haven't been computed yet. Unlike [`Self::get_or_compute_scores()`],
+    /// this never computes the scores.
+    ///
+    /// As with [`Self::get_or_compute_scores()`], the set of words is limited by the [`GuessFrom`]
+    /// value used in this guesser. The result can be passed to [`Self::with_scores()`] to restore
+    /// these scores, for example after saving them with [`write_scores()`].
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"]).unwrap();
+    /// let scorer = MaxEliminationsScorer::new(bank.clone()).unwrap();
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+    ///
+    /// assert_eq!(guesser.export_scores(), None);
+    ///
+    /// guesser.select_next_guess();
+    /// let scores = guesser.export_scores().unwrap();
+    ///
+    /// assert_eq!(scores.len(), 3);
+    /// ```
+    pub fn export_scores(&self) -> Option<HashMap<Arc<str>, i64>> {
+        Some(
+            self.words_to_score(self.default_guess_mode)
+                .iter()
+                .zip(self.word_scores(self.default_guess_mode)?.iter())
+                .map(|(word, score)| (Arc::clone(word), *score as i64))
+                .collect(),
+        )
+    }
+
+    /// Returns up-to the top `n` guesses for the wordle, based on the current state.
+    ///
+    /// Returns an empty vector if no known words are possible given the known restrictions imposed
+    /// by previous calls to [`Self::update()`].
+    pub fn select_top_n_guesses(&mut self, n: usize) -> Vec<ScoredGuess> {
+        self.select_top_n_guesses_from(n, self.default_guess_mode)
+    }
+
+    /// Returns the same guesses as [`Self::select_top_n_guesses()`], along with the scorer's
+    /// explanation of each score.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "xyz"]).unwrap();
+    /// let scorer = MaxEliminationsScorer::new(bank.clone()).unwrap();
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// let guesses = guesser.select_top_n_guesses_detailed(1);
+    ///
+    /// assert_eq!(guesses[0].guess.as_ref(), "abc");
+    /// assert_eq!(
+    ///     guesses[0].explanation.as_deref(),
+    ///     Some("leaves 1.00 of 3 possible words on average")
+    /// );
+    /// ```
+    pub fn select_top_n_guesses_detailed(&mut self, n: usize) -> Vec<DetailedGuess> {
+        self.select_top_n_guesses(n)
+            .into_iter()
+            .map(|scored_guess| DetailedGuess {
+                explanation: self.scorer.explain(&scored_guess.guess),
+                score: scored_guess.score,
+                guess: scored_guess.guess,
+            })
+            .collect()
+    }
+
+    /// Returns up-to the top `n` guesses for the wordle, based on the current state and the
+    /// provided [`GuessFrom`] option.
+    ///
+    /// Returns an empty vector if no known words are possible given the known restrictions imposed
+    /// by previous calls to [`Self::update()`].
+    pub fn select_top_n_guesses_from(&mut self, n: usize, from: GuessFrom) -> Vec<ScoredGuess> {
+        self.compute_scores_if_needed_from(from);
+        let word_scores = self.word_scores(from).unwrap();
+        let mut scored_words: Vec<(&Arc<str>, f64)> = word_scores
+            .iter()
+            .zip(self.words_to_score(from).iter())
+            .map(|(score, word)| (word, self.apply_repeated_letter_penalty(word, *score)))
+            .collect();
+
+        // Use a stable sort, because possible words come before impossible words, and we want to
+        // prioritise possible words if we're using GuessFrom::AllUnguessedWords.
+        self.sort_scored_words(&mut scored_words);
+        scored_words
+            .iter()
+            .take(n)
+            .map(|(word, score)| ScoredGuess {
+                score: *score as i64,
+                guess: Arc::clone(*word),
+            })
+            .collect()
+    }
+
+    /// Returns every word that could still be the objective, with its current score, sorted from
+    /// highest to lowest score.
+    ///
+    /// Unlike [`Self::select_top_n_guesses()`], this always lists the possible words, regardless of
+    /// the [`GuessFrom`] value used in this guesser.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "abe", "xyz"])?;
+    /// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// guesser.update(&get_result_for_guess("abd", "xyz")?)?;
+    ///
+    /// let mut possible_words: Vec<String> = guesser
+    ///     .possible_words_scored()
+    ///     .iter()
+    ///     .map(|scored_guess| scored_guess.guess.to_string())
+    ///     .collect();
+    /// possible_words.sort();
+    ///
+    /// assert_eq!(possible_words, vec!["abc", "abd", "abe"]);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn possible_words_scored(&mut self) -> Vec<ScoredGuess> {
+        self.select_top_n_guesses_from(usize::MAX, GuessFrom::PossibleWords)
+    }
+
+    /// Returns the number of distinct results that guessing the given word would produce across
+    /// the current possible words. See
+    /// [`count_result_patterns()`](crate::details::count_result_patterns).
+    ///
+    /// Returns `0` if the word is a different length to the words in this guesser, or the words
+    /// are too long for their results to be compressed.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["cod", "wod", "mod", "cat"]).unwrap();
+    /// let scorer = MaxEliminationsScorer::new(bank.clone()).unwrap();
+    /// let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// assert_eq!(guesser.pattern_count("cod"), 3);
+    /// assert_eq!(guesser.pattern_count("mwt"), 4);
+    /// assert_eq!(guesser.pattern_count("code"), 0);
+    /// ```
+    pub fn pattern_count(&self, word: &str) -> usize {
+        if check_guess_length(&self.grouped_words, word).is_err() {
+            return 0;
+        }
+        count_result_patterns(word, self.grouped_words.possible_words()).unwrap_or(0)
+    }
+
+    /// Returns how many of the current possible words would produce each distinct result if the
+    /// given word were guessed. This is the same grouping that the eliminations scorers use.
+    ///
+    /// Returns an empty map if the word is a different length to the words in this guesser, or the
+    /// words are too long for their results to be compressed.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::LetterResult;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["cod", "wod", "mod", "cat"]).unwrap();
+    /// let scorer = MaxEliminationsScorer::new(bank.clone()).unwrap();
+    /// let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// let distribution = guesser.result_distribution("cod");
+    ///
+    /// assert_eq!(distribution.len(), 3);
+    /// assert_eq!(distribution.values().sum::<usize>(), 4);
+    /// let (wod_and_mod, _) = distribution
+    ///     .iter()
+    ///     .find(|(_, count)| **count == 2)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     wod_and_mod.to_results(),
+    ///     vec![LetterResult::NotPresent, LetterResult::Correct, LetterResult::Correct]
+    /// );
+    /// assert!(guesser.result_distribution("code").is_empty());
+    /// ```
+    pub fn result_distribution(&self, word: &str) -> HashMap<CompressedGuessResult, usize> {
+        if check_guess_length(&self.grouped_words, word).is_err() {
+            return HashMap::new();
+        }
+        count_words_by_result(word, self.grouped_words.possible_words()).unwrap_or_default()
+    }
+
+    /// Returns up-to the top `n` guesses from the given candidates, scored with the current state
+    /// of this guesser's scorer.
+    ///
+    /// Candidates are scored exactly as given, regardless of whether they are in the word bank or
+    /// have already been guessed. This does not affect the cached scores used by
+    /// [`Self::select_top_n_guesses()`].
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxUniqueLetterFrequencyScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["aaaa", "baac", "xabc"])?;
+    /// let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+    ///
+    /// let candidates: Vec<Arc<str>> = vec![Arc::from("aaaa"), Arc::from("cabx")];
+    /// let top_guesses = guesser.select_top_n_guesses_among(1, &candidates);
+    ///
+    /// assert_eq!(top_guesses[0].guess.as_ref(), "cabx");
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn select_top_n_guesses_among(
+        &mut self,
+        n: usize,
+        candidates: &[Arc<str>],
+    ) -> Vec<ScoredGuess> {
+        let scores = Self::score_words(
+            candidates,
+            &self.scorer,
+            self.parallelisation_limit,
+            self.precise_ranking,
+        );
+        let mut scored_words: Vec<(&Arc<str>, f64)> = candidates
+            .iter()
+            .zip(scores)
+            .map(|(word, score)| (word, self.apply_repeated_letter_penalty(word, score)))
+            .collect();
+
+        // Use a stable sort, so that ties keep the order of the given candidates.
+        self.sort_scored_words(&mut scored_words);
+        scored_words
+            .iter()
+            .take(n)
+            .map(|(word, score)| ScoredGuess {
+                score: *score as i64,
+                guess: Arc::clone(*word),
+            })
+            .collect()
+    }
+
+    /// Computes the guess that this guesser would make after each possible result of its next
+    /// guess, keyed by that result.
+    ///
+    /// When used on a new guesser, this is a table of the best second guess after the first guess,
+    /// which can be stored to speed up repeated games. The all-correct result is not included,
+    /// since no further guess is needed. Returns an empty map if no words are possible, or the
+    /// words are too long for their results to be compressed.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// use rs_wordle_solver::details::CompressedGuessResult;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"])?;
+    /// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// let table = guesser.precompute_second_guess_table();
+    ///
+    /// let first_guess = guesser.select_next_guess().unwrap();
+    /// let result = get_result_for_guess("bcd", &first_guess)?;
+    /// assert_eq!(
+    ///     table.get(&CompressedGuessResult::from_results(&result.results)?),
+    ///     Some(&Arc::from("bcd"))
+    /// );
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn precompute_second_guess_table(&mut self) -> HashMap<CompressedGuessResult, Arc<str>> {
+        let first_guess = match self.select_next_guess() {
+            Some(guess) => guess,
+            None => return HashMap::new(),
+        };
+        let mut results_by_compressed: HashMap<CompressedGuessResult, Vec<LetterResult>> =
+            HashMap::new();
+        for word in self.grouped_words.possible_words() {
+            if *word == first_guess {
+                continue;
+            }
+            let result =
+                get_result_for_guess_with(word, &first_guess, self.restrictions.duplicate_mode())
+                    .unwrap();
+            let compressed = match CompressedGuessResult::from_results(&result.results) {
+                Ok(compressed) => compressed,
+                Err(_) => return HashMap::new(),
+            };
+            results_by_compressed
+                .entry(compressed)
+                .or_insert(result.results);
+        }
+        let this: &Self = self;
+        results_by_compressed
+            .into_par_iter()
+            .filter_map(|(compressed, results)| {
+                let mut guesser = this.clone();
+                guesser
+                    .update(&GuessResult {
+                        guess: &first_guess,
+                        results,
+                    })
+                    .ok()?;
+                guesser
+                    .select_next_guess()
+                    .map(|second_guess| (compressed, second_guess))
+            })
+            .collect()
+    }
+
+    /// Returns the unguessed words that reuse all revealed hints, i.e. the words that would be
+    /// legal guesses in Wordle's hard mode. See [`WordRestrictions::is_hard_mode_guess()`].
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::GuessResult;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::LetterResult;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"]).unwrap();
+    /// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// guesser.update(&GuessResult {
+    ///     guess: "abc",
+    ///     results: vec![
+    ///         LetterResult::Correct,
+    ///         LetterResult::Correct,
+    ///         LetterResult::NotPresent,
+    ///     ],
+    /// })?;
+    ///
+    /// assert_eq!(guesser.hard_mode_candidates(), vec![&Arc::from("abd")]);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn hard_mode_candidates(&self) -> Vec<&Arc<str>> {
+        self.grouped_words
+            .unguessed_words()
+            .iter()
+            .filter(|word| self.restrictions.is_hard_mode_guess(word))
+            .collect()
+    }
+
+    /// Merges what is known in `other` into this guesser's restrictions, and then updates the
+    /// possible words and the scorer to match.
+    ///
+    /// This combines the knowledge from an independent game with the same answer, without
+    /// replaying its guesses. The other game's guesses are not added to this guesser's history.
+    ///
+    /// Since [`WordRestrictions`] don't record the guesses themselves, the scorer is updated with
+    /// an empty guess. Scorers that only depend on the restrictions and possible words are fully
+    /// re-synced, but scorers that track the guesses (such as
+    /// [`MaxUniqueLetterFrequencyScorer`](crate::scorers::MaxUniqueLetterFrequencyScorer), which
+    /// favours letters that haven't been guessed yet) don't learn the other game's guesses.
+    ///
+    /// Returns a [`WordleError::InvalidResults`] error if the word lengths differ, or a
+    /// [`WordleError::ContradictoryResults`] error if the restrictions are incompatible. If the
+    /// scorer fails to update, its error is returned. In all cases, the guesser's restrictions
+    /// and possible words are left unchanged on error.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// use rs_wordle_solver::details::WordRestrictions;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// # use rs_wordle_solver::WordleError;
     ///
-    /// This guesser always uses the `GuessFrom::PossibleWords` strategy by default.
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "bcd", "bce"])?;
+    /// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    /// guesser.update(&get_result_for_guess("bce", "abc")?)?;
+    /// assert_eq!(guesser.possible_words().len(), 2);
     ///
-    /// ```
-    /// use rs_wordle_solver::RandomGuesser;
-    /// use rs_wordle_solver::WordBank;
+    /// // Someone else guessed "bcd" in their own game with the same answer.
+    /// let other = WordRestrictions::from_result(&get_result_for_guess("bce", "bcd")?);
+    /// guesser.merge_restrictions(&other)?;
     ///
-    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"]).unwrap();
-    /// let guesser = RandomGuesser::new(bank);
+    /// assert_eq!(guesser.possible_words(), &[Arc::from("bce")]);
+    /// # Ok::<(), WordleError>(())
     /// ```
-    pub fn new(bank: WordBank) -> RandomGuesser {
-        let word_length = bank.word_length();
-        RandomGuesser {
-            words: GroupedWords::new(bank),
-            restrictions: WordRestrictions::new(word_length as u8),
-        }
-    }
-
-    fn select_random_word(words: &[Arc<str>]) -> Option<Arc<str>> {
-        if words.is_empty() {
-            return None;
-        }
-        let random: usize = rand::random();
-        words.get(random % words.len()).map(Arc::clone)
-    }
-}
-
-impl Guesser for RandomGuesser {
-    fn update(&mut self, result: &GuessResult) -> Result<(), WordleError> {
-        self.restrictions.update(result)?;
-        self.words
-            .filter_possible_words(|word| self.restrictions.is_satisfied_by(word));
+    pub fn merge_restrictions(&mut self, other: &WordRestrictions) -> Result<(), WordleError> {
+        let mut restrictions = self.restrictions.clone();
+        restrictions.merge(other)?;
+        let mut grouped_words = self.grouped_words.clone();
+        grouped_words.filter_possible_words_matching(&restrictions, self.parallelisation_limit);
+        // Update the scorer before committing anything, so a failure leaves the guesser as it
+        // was.
+        self.scorer
+            .update("", &restrictions, grouped_words.possible_words())?;
+        self.restrictions = restrictions;
+        self.grouped_words = grouped_words;
+        self.all_unguessed_word_scores = None;
+        self.possible_word_scores = None;
+        self.update_hard_mode_words();
         Ok(())
     }
 
-    fn select_next_guess(&mut self) -> Option<Arc<str>> {
-        self.select_next_guess_from(GuessFrom::PossibleWords)
-    }
-
-    fn select_next_guess_from(&mut self, from: GuessFrom) -> Option<Arc<str>> {
-        match from {
-            GuessFrom::AllUnguessedWords => {
-                RandomGuesser::select_random_word(self.words.unguessed_words())
+    /// Updates the restrictions with the given function, and then updates the possible words and
+    /// the scorer to match. Returns the words that are no longer possible.
+    fn update_with<F>(
+        &mut self,
+        guess: &str,
+        update_restrictions: F,
+    ) -> Result<Vec<Arc<str>>, WordleError>
+    where
+        F: FnOnce(&mut WordRestrictions) -> Result<(), WordleError>,
+    {
+        let num_possible_words_before_update = self.grouped_words.num_possible_words();
+        let previous_scores = if self.scorer.is_incremental() {
+            Some((
+                self.scores_by_word(GuessFrom::AllUnguessedWords),
+                self.scores_by_word(GuessFrom::PossibleWords),
+            ))
+        } else {
+            None
+        };
+        self.all_unguessed_word_scores = None;
+        self.possible_word_scores = None;
+        let previous_restrictions = self.restrictions.clone();
+        update_restrictions(&mut self.restrictions)?;
+        self.last_update_summary = self.restrictions.changes_since(&previous_restrictions);
+        self.guess_history.push(Arc::from(guess));
+        self.grouped_words.remove_guess_if_present(guess);
+        let eliminated = self
+            .grouped_words
+            .filter_possible_words_matching(&self.restrictions, self.parallelisation_limit);
+        self.update_hard_mode_words();
+        self.scorer.update(
+            guess,
+            &self.restrictions,
+            self.grouped_words.possible_words(),
+        )?;
+        // If no words were eliminated, then incremental scorers will produce the same scores.
+        if let Some((all_unguessed_word_scores, possible_word_scores)) = previous_scores {
+            if self.grouped_words.num_possible_words() == num_possible_words_before_update {
+                self.all_unguessed_word_scores =
+                    self.reorder_scores(GuessFrom::AllUnguessedWords, all_unguessed_word_scores);
+                self.possible_word_scores =
+                    self.reorder_scores(GuessFrom::PossibleWords, possible_word_scores);
             }
-            GuessFrom::PossibleWords => RandomGuesser::select_random_word(self.possible_words()),
         }
+        Ok(eliminated)
     }
 
-    fn possible_words(&self) -> &[Arc<str>] {
-        self.words.possible_words()
+    /// Recomputes the unguessed words that are legal in hard mode, if hard mode is enabled.
+    fn update_hard_mode_words(&mut self) {
+        if !self.hard_mode {
+            self.hard_mode_words.clear();
+            return;
+        }
+        self.hard_mode_words = self
+            .grouped_words
+            .unguessed_words()
+            .iter()
+            .filter(|word| self.restrictions.is_hard_mode_guess(word))
+            .map(Arc::clone)
+            .collect();
     }
-}
-
-/// Represents a guess with a 'score' estimating how useful the guess is. Higher scores are better.
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct ScoredGuess {
-    pub score: i64,
-    pub guess: Arc<str>,
-}
-
-/// Selects the next guess that maximizes the score according to the owned scorer.
-///
-/// See [`WordScorer`] for more information about possible scoring algorithms.
-#[derive(Debug, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct MaxScoreGuesser<T>
-where
-    T: WordScorer + Clone + Sync,
-{
-    default_guess_mode: GuessFrom,
-    grouped_words: GroupedWords,
-    restrictions: WordRestrictions,
-    scorer: T,
-    parallelisation_limit: usize,
-    all_unguessed_word_scores: Option<Vec<i64>>,
-    possible_word_scores: Option<Vec<i64>>,
-}
 
-impl<T> MaxScoreGuesser<T>
-where
-    T: WordScorer + Clone + Sync,
-{
-    /// Constructs a new `MaxScoreGuesser` that will guess the word with the maximum score
-    /// according to the given [`WordScorer`]. This will only score and guess from the words in the
-    /// `word_bank` according to the given `guess_from` strategy.
+    /// Returns the already-guessed word that is confirmed to be the answer, if re-guessing it would
+    /// win the game.
     ///
-    /// If in doubt, you probably want to use `GuessFrom::AllUnguessedWords` for better performance.
-    ///
-    /// See [`WordScorer`] for more information about possible scoring algorithms.
+    /// This is only the case if re-guesses are allowed (see [`Self::with_reguesses_allowed()`]),
+    /// and the only remaining possible word has already been guessed.
     ///
     /// ```
     /// use std::sync::Arc;
     /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::GuessResult;
     /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::LetterResult;
     /// use rs_wordle_solver::MaxScoreGuesser;
     /// use rs_wordle_solver::WordBank;
     /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// # use rs_wordle_solver::WordleError;
     ///
-    /// let bank = WordBank::from_iterator(&["azz", "bzz", "czz", "abc"]).unwrap();
-    /// let scorer = MaxEliminationsScorer::new(bank.clone());
-    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "xyz"])?;
+    /// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer)
+    ///     .with_reguesses_allowed(true);
     ///
-    /// assert_eq!(guesser.select_next_guess(), Some(Arc::from("abc")));
+    /// assert_eq!(guesser.reguess_would_win(), None);
+    ///
+    /// guesser.update(&GuessResult {
+    ///     guess: "abc",
+    ///     results: vec![LetterResult::Correct; 3],
+    /// })?;
+    ///
+    /// assert_eq!(guesser.reguess_would_win(), Some(Arc::from("abc")));
+    /// # Ok::<(), WordleError>(())
     /// ```
-    pub fn new(guess_mode: GuessFrom, word_bank: WordBank, scorer: T) -> MaxScoreGuesser<T> {
-        let word_length = word_bank.word_length();
-        Self {
-            default_guess_mode: guess_mode,
-            grouped_words: GroupedWords::new(word_bank),
-            restrictions: WordRestrictions::new(word_length as u8),
-            scorer,
-            parallelisation_limit: std::thread::available_parallelism()
-                .map(NonZeroUsize::get)
-                .unwrap_or(1),
-            all_unguessed_word_scores: None,
-            possible_word_scores: None,
+    pub fn reguess_would_win(&self) -> Option<Arc<str>> {
+        if !self.allow_reguesses || self.grouped_words.num_possible_words() != 1 {
+            return None;
         }
+        self.grouped_words.guessed_possible_words().first().cloned()
     }
 
-    /// Sets the parallelisation limit. Various internal operations may be parallelised if operating
-    /// on lists larger than this limit. The default setting is the result of
-    /// `std::thread::available_parallelism`.
-    pub fn with_parallelisation_limit(mut self, parallelisation_limit: usize) -> Self {
-        self.parallelisation_limit = parallelisation_limit;
-        self
+    /// Returns `true` iff exactly one word is still possible, so the answer is known.
+    ///
+    /// See [`Self::determined_answer()`] for an example.
+    pub fn is_determined(&self) -> bool {
+        self.grouped_words.num_possible_words() == 1
     }
 
-    /// Sets the precomputed word scores based on the provided map. If the map is missing scores
-    /// for any words, they will be computed to fill the gaps. These scores will be used until the
-    /// next call to [`Self::update()`].
+    /// Returns the answer if it is known, i.e. if it is the only word that is still possible.
     ///
-    /// This is handy to initialise the guesser with precomputed scores for the first guess. You
-    /// can retrieve this data by calling [`Self::get_or_compute_scores()`] on a newly constructed
-    /// guesser.
-    pub fn with_scores(mut self, scores: &HashMap<Arc<str>, i64>) -> Self {
-        let words_to_score = self.words_to_score(self.default_guess_mode);
-        let ordered_scores: Vec<i64> = words_to_score
-            .iter()
-            .map(|word| {
-                scores
-                    .get(word)
-                    .copied()
-                    .unwrap_or_else(|| self.scorer.score_word(word))
-            })
-            .collect();
-        match self.default_guess_mode {
-            GuessFrom::AllUnguessedWords => {
-                self.all_unguessed_word_scores = Some(ordered_scores);
-            }
-            GuessFrom::PossibleWords => {
-                self.possible_word_scores = Some(ordered_scores);
-            }
+    /// This can be used to show the answer right away, without scoring the next guess.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "xyz"])?;
+    /// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// assert!(!guesser.is_determined());
+    /// assert_eq!(guesser.determined_answer(), None);
+    ///
+    /// guesser.update(&get_result_for_guess("abd", "abc")?)?;
+    ///
+    /// assert!(guesser.is_determined());
+    /// assert_eq!(guesser.determined_answer(), Some(&Arc::from("abd")));
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn determined_answer(&self) -> Option<&Arc<str>> {
+        match self.grouped_words.possible_words() {
+            [answer] => Some(answer),
+            _ => None,
         }
-        self
-    }
-
-    /// Gets the score for each available guess, keyed by guess. This computes the scores if they
-    /// have not already been computed. The set of words is limited by the [`GuessFrom`] value used
-    /// in this guesser, and by the updates that have been provided so far.
-    pub fn get_or_compute_scores(&mut self) -> HashMap<Arc<str>, i64> {
-        self.compute_scores_if_unknown();
-        self.words_to_score(self.default_guess_mode)
-            .iter()
-            .zip(self.word_scores(self.default_guess_mode).unwrap().iter())
-            .map(|(word, score)| (Arc::clone(word), *score))
-            .collect()
     }
 
-    /// Returns up-to the top `n` guesses for the wordle, based on the current state.
+    /// Returns a uniformly random word from the current set of possible words, or `None` if no
+    /// words are possible.
     ///
-    /// Returns an empty vector if no known words are possible given the known restrictions imposed
-    /// by previous calls to [`Self::update()`].
-    pub fn select_top_n_guesses(&mut self, n: usize) -> Vec<ScoredGuess> {
-        self.select_top_n_guesses_from(n, self.default_guess_mode)
-    }
-
-    /// Returns up-to the top `n` guesses for the wordle, based on the current state and the
-    /// provided [`GuessFrom`] option.
+    /// This is handy for generating test scenarios that are consistent with the guesses made so
+    /// far.
     ///
-    /// Returns an empty vector if no known words are possible given the known restrictions imposed
-    /// by previous calls to [`Self::update()`].
-    pub fn select_top_n_guesses_from(&mut self, n: usize, from: GuessFrom) -> Vec<ScoredGuess> {
-        self.compute_scores_if_needed_from(from);
-        let word_scores = self.word_scores(from).unwrap();
-        let mut scored_words: Vec<(&Arc<str>, i64)> = word_scores
-            .iter()
-            .zip(self.words_to_score(from).iter())
-            .map(|(score, word)| (word, *score))
-            .collect();
-
-        // Use a stable sort, because possible words come before impossible words, and we want to
-        // prioritise possible words if we're using GuessFrom::AllUnguessedWords.
-        if scored_words.len() >= self.parallelisation_limit {
-            scored_words.par_sort_by_key(|(_, score)| -score);
-        } else {
-            scored_words.sort_by_key(|(_, score)| -score);
-        }
-        scored_words
-            .iter()
-            .take(n)
-            .map(|(word, score)| ScoredGuess {
-                score: *score,
-                guess: Arc::clone(*word),
-            })
-            .collect()
+    /// ```
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"]).unwrap();
+    /// let scorer = MaxEliminationsScorer::new(bank.clone()).unwrap();
+    /// let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    /// let mut rng = StdRng::seed_from_u64(1);
+    ///
+    /// let answer = guesser.sample_possible_answer(&mut rng).unwrap();
+    /// assert!(guesser.possible_words().contains(&answer));
+    /// ```
+    pub fn sample_possible_answer(&self, rng: &mut impl Rng) -> Option<Arc<str>> {
+        self.grouped_words
+            .possible_words()
+            .choose(rng)
+            .map(Arc::clone)
     }
 
     /// Computes the word scores if they are not known, using the default [`GuessFrom`] provided on
@@ -345,7 +2161,7 @@ where
     /// # use rs_wordle_solver::scorers::MaxEliminationsScorer;
     ///
     /// let bank = WordBank::from_iterator(&["azz", "bzz", "czz", "abc"]).unwrap();
-    /// let scorer = MaxEliminationsScorer::new(bank.clone());
+    /// let scorer = MaxEliminationsScorer::new(bank.clone()).unwrap();
     /// let mut base_guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
     ///
     /// // Precompute the first scores.
@@ -376,7 +2192,7 @@ where
     /// # use rs_wordle_solver::scorers::MaxEliminationsScorer;
     ///
     /// let bank = WordBank::from_iterator(&["azz", "bzz", "czz", "abc"]).unwrap();
-    /// let scorer = MaxEliminationsScorer::new(bank.clone());
+    /// let scorer = MaxEliminationsScorer::new(bank.clone()).unwrap();
     /// let mut base_guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
     ///
     /// // Precompute the first scores.
@@ -397,6 +2213,7 @@ where
             self.words_to_score(from),
             &self.scorer,
             self.parallelisation_limit,
+            self.precise_ranking,
         ));
 
         match from {
@@ -413,24 +2230,80 @@ where
         words_to_score: &[Arc<str>],
         scorer: &T,
         parallelisation_limit: usize,
-    ) -> Vec<i64> {
+        precise_ranking: bool,
+    ) -> Vec<f64> {
         if words_to_score.len() >= parallelisation_limit {
             words_to_score
                 .par_iter()
-                .map(|word| scorer.score_word(word))
+                .map(|word| MaxScoreGuesser::score_word(word, scorer, precise_ranking))
                 .collect()
         } else {
             words_to_score
                 .iter()
-                .map(|word| scorer.score_word(word))
+                .map(|word| MaxScoreGuesser::score_word(word, scorer, precise_ranking))
                 .collect()
         }
     }
 
+    /// Scores the given word with this guesser's scorer and settings, as when ranking guesses.
+    pub(crate) fn score_guess(&self, word: &Arc<str>) -> f64 {
+        self.apply_repeated_letter_penalty(
+            word,
+            Self::score_word(word, &self.scorer, self.precise_ranking),
+        )
+    }
+
+    /// Returns the words that haven't been guessed yet, with the possible words first.
+    pub(crate) fn unguessed_words(&self) -> &[Arc<str>] {
+        self.grouped_words.unguessed_words()
+    }
+
+    /// Sorts the words by score, highest first, and then by the tiebreak if there is one. This is
+    /// a stable sort.
+    fn sort_scored_words(&self, scored_words: &mut [(&Arc<str>, f64)]) {
+        let compare = |(a_word, a): &(&Arc<str>, f64), (b_word, b): &(&Arc<str>, f64)| {
+            b.total_cmp(a).then_with(|| match &self.tiebreak {
+                Some(Tiebreak(tiebreak)) => tiebreak(b_word).cmp(&tiebreak(a_word)),
+                None => Ordering::Equal,
+            })
+        };
+        if scored_words.len() >= self.parallelisation_limit {
+            scored_words.par_sort_by(compare);
+        } else {
+            scored_words.sort_by(compare);
+        }
+    }
+
+    fn score_word(word: &Arc<str>, scorer: &T, precise_ranking: bool) -> f64 {
+        if precise_ranking {
+            scorer.score_word_precise(word)
+        } else {
+            scorer.score_word(word) as f64
+        }
+    }
+
+    /// Subtracts the repeated letter penalty from the given score, once for each repeated letter in
+    /// the word that is not yet known to be in the objective word.
+    fn apply_repeated_letter_penalty(&self, word: &str, score: f64) -> f64 {
+        if self.repeated_letter_penalty == 0 {
+            return score;
+        }
+        let num_unknown_repeats = word
+            .chars()
+            .enumerate()
+            .filter(|(index, letter)| {
+                word.chars().take(*index).any(|other| other == *letter)
+                    && self.restrictions.state_at(*letter, None)
+                        != Some(LetterRestriction::PresentMaybeHere)
+            })
+            .count() as i64;
+        score - (num_unknown_repeats * self.repeated_letter_penalty) as f64
+    }
+
     /// Retrieves the requested set of word scores, if they have been precomputed, else `None`.
     ///
     /// If present, these are in the same order as the words returned by [`Self::words_to_score()`].
-    fn word_scores(&self, from: GuessFrom) -> Option<&[i64]> {
+    fn word_scores(&self, from: GuessFrom) -> Option<&[f64]> {
         match from {
             GuessFrom::PossibleWords => {
                 if let Some(scores) = self.possible_word_scores.as_ref() {
@@ -454,6 +2327,35 @@ where
         }
     }
 
+    /// Returns the precomputed scores keyed by word, if they are known. Only the scores computed
+    /// specifically for the given [`GuessFrom`] are returned.
+    fn scores_by_word(&self, from: GuessFrom) -> Option<HashMap<Arc<str>, f64>> {
+        let scores = match from {
+            GuessFrom::AllUnguessedWords => self.all_unguessed_word_scores.as_ref(),
+            GuessFrom::PossibleWords => self.possible_word_scores.as_ref(),
+        }?;
+        Some(
+            self.words_to_score(from)
+                .iter()
+                .zip(scores.iter())
+                .map(|(word, score)| (Arc::clone(word), *score))
+                .collect(),
+        )
+    }
+
+    /// Orders the given scores to match the current words to score, if all are known.
+    fn reorder_scores(
+        &self,
+        from: GuessFrom,
+        maybe_scores: Option<HashMap<Arc<str>, f64>>,
+    ) -> Option<Vec<f64>> {
+        let scores = maybe_scores?;
+        self.words_to_score(from)
+            .iter()
+            .map(|word| scores.get(word).copied())
+            .collect()
+    }
+
     /// Retrieves the words that need scoring, in the same order as the precomputed scores, if any.
     ///
     /// Note that if there are two or fewer possible words remaining, this will always return only
@@ -464,6 +2366,12 @@ where
             _ if self.grouped_words.num_possible_words() <= 2 => {
                 self.grouped_words.possible_words()
             }
+            // All words are kept with possible words first, so re-guesses can use the full list.
+            GuessFrom::AllUnguessedWords if self.allow_reguesses => &self.grouped_words.all_words,
+            // Hard mode words are filtered from the unguessed words, so possible words are first.
+            GuessFrom::AllUnguessedWords if self.hard_mode && !self.hard_mode_words.is_empty() => {
+                &self.hard_mode_words
+            }
             GuessFrom::AllUnguessedWords => self.grouped_words.unguessed_words(),
             GuessFrom::PossibleWords => self.grouped_words.possible_words(),
         }
@@ -475,18 +2383,21 @@ where
     T: WordScorer + Clone + Sync,
 {
     fn update(&mut self, result: &GuessResult) -> Result<(), WordleError> {
-        self.all_unguessed_word_scores = None;
-        self.possible_word_scores = None;
-        self.grouped_words.remove_guess_if_present(result.guess);
-        self.restrictions.update(result)?;
-        self.grouped_words
-            .filter_possible_words(|word| self.restrictions.is_satisfied_by(word));
-        self.scorer.update(
-            result.guess,
-            &self.restrictions,
-            self.grouped_words.possible_words(),
-        )?;
-        Ok(())
+        self.update_returning_eliminated(result).map(|_| ())
+    }
+
+    fn update_returning_eliminated(
+        &mut self,
+        result: &GuessResult,
+    ) -> Result<Vec<Arc<str>>, WordleError> {
+        self.update_with(result.guess, |restrictions| restrictions.update(result))
+    }
+
+    fn update_partial(&mut self, result: &PartialGuessResult) -> Result<(), WordleError> {
+        self.update_with(result.guess, |restrictions| {
+            restrictions.update_partial(result)
+        })
+        .map(|_| ())
     }
 
     fn select_next_guess(&mut self) -> Option<Arc<str>> {
@@ -499,24 +2410,32 @@ where
         let word_scores = self.word_scores(from).unwrap();
         let words_to_score = self.words_to_score(from);
         let (best_index, _) = if words_to_score.len() > self.parallelisation_limit {
-            word_scores.par_iter().enumerate().reduce(
-                || (usize::MAX, &i64::MIN),
-                |(best_index, best_score), (index, score)| {
-                    if *score > *best_score {
-                        return (index, score);
-                    }
-                    // Use the lower index, because it is more likely to be a possible word.
-                    if *score == *best_score && index < best_index {
-                        return (index, score);
-                    }
-                    (best_index, best_score)
-                },
-            )
+            word_scores
+                .par_iter()
+                .enumerate()
+                .map(|(index, score)| {
+                    (
+                        index,
+                        self.apply_repeated_letter_penalty(&words_to_score[index], *score),
+                    )
+                })
+                .reduce(
+                    || (usize::MAX, f64::NEG_INFINITY),
+                    |(best_index, best_score), (index, score)| {
+                        match score.total_cmp(&best_score) {
+                            Ordering::Greater => (index, score),
+                            // Use the lower index, because it is more likely to be a possible word.
+                            Ordering::Equal if index < best_index => (index, score),
+                            _ => (best_index, best_score),
+                        }
+                    },
+                )
         } else {
-            let mut best_score = &i64::MIN;
+            let mut best_score = f64::NEG_INFINITY;
             let mut best_index = usize::MAX;
             word_scores.iter().enumerate().for_each(|(i, score)| {
-                if *score > *best_score {
+                let score = self.apply_repeated_letter_penalty(&words_to_score[i], *score);
+                if best_index == usize::MAX || score.total_cmp(&best_score).is_gt() {
                     best_score = score;
                     best_index = i;
                 }
@@ -533,4 +2452,92 @@ where
     fn possible_words(&self) -> &[Arc<str>] {
         self.grouped_words.possible_words()
     }
+
+    fn guess_word(&mut self, word: &str) -> Result<(), WordleError> {
+        check_guess_length(&self.grouped_words, word)?;
+        // Removing the guess reorders the words, so keep any precomputed scores in the new order.
+        let all_unguessed_word_scores = self.scores_by_word(GuessFrom::AllUnguessedWords);
+        let possible_word_scores = self.scores_by_word(GuessFrom::PossibleWords);
+        self.grouped_words.remove_guess_if_present(word);
+        self.update_hard_mode_words();
+        self.all_unguessed_word_scores =
+            self.reorder_scores(GuessFrom::AllUnguessedWords, all_unguessed_word_scores);
+        self.possible_word_scores =
+            self.reorder_scores(GuessFrom::PossibleWords, possible_word_scores);
+        Ok(())
+    }
+
+    fn guess_history(&self) -> &[Arc<str>] {
+        &self.guess_history
+    }
+
+    fn last_update_summary(&self) -> UpdateSummary {
+        self.last_update_summary.clone()
+    }
+}
+
+/// Writes the given scores as lines of the form `word,score`, from highest to lowest score.
+///
+/// These can be read back with [`read_scores()`], for example to save the scores returned by
+/// [`MaxScoreGuesser::get_or_compute_scores()`] for a slow scorer, and later restore them with
+/// [`MaxScoreGuesser::with_scores()`].
+///
+/// ```
+/// use std::collections::HashMap;
+/// use std::sync::Arc;
+/// use rs_wordle_solver::read_scores;
+/// use rs_wordle_solver::write_scores;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let scores: HashMap<Arc<str>, i64> = HashMap::from([(Arc::from("abc"), 10), (Arc::from("bcd"), -2)]);
+/// let mut buffer = Vec::new();
+/// write_scores(&scores, &mut buffer)?;
+///
+/// assert_eq!(buffer, b"abc,10\nbcd,-2\n");
+/// assert_eq!(read_scores(buffer.as_slice())?, scores);
+/// # Ok::<(), WordleError>(())
+/// ```
+pub fn write_scores<W: io::Write>(
+    scores: &HashMap<Arc<str>, i64>,
+    mut writer: W,
+) -> Result<(), WordleError> {
+    let mut sorted_scores: Vec<(&Arc<str>, &i64)> = scores.iter().collect();
+    sorted_scores.sort_unstable_by(|(word, score), (other_word, other_score)| {
+        other_score.cmp(score).then_with(|| word.cmp(other_word))
+    });
+    for (word, score) in sorted_scores {
+        writeln!(writer, "{},{}", word, score)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads scores that were written by [`write_scores()`]. Blank lines are skipped.
+///
+/// Returns a [`WordleError::IoError`] if any line is not of the form `word,score`.
+pub fn read_scores<R: io::BufRead>(reader: R) -> Result<HashMap<Arc<str>, i64>, WordleError> {
+    let mut scores = HashMap::new();
+    for maybe_line in reader.lines() {
+        let line = maybe_line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (word, score) = line
+            .rsplit_once(',')
+            .and_then(|(word, score)| {
+                score
+                    .trim()
+                    .parse::<i64>()
+                    .ok()
+                    .map(|score| (word.trim(), score))
+            })
+            .ok_or_else(|| {
+                WordleError::IoError(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("expected a line of the form 'word,score', got: {}", line),
+                ))
+            })?;
+        scores.insert(Arc::from(word), score);
+    }
+    Ok(scores)
 }
@@ -0,0 +1,179 @@
+use crate::engine::Guesser;
+use crate::results::*;
+use std::result::Result;
+use std::sync::Arc;
+
+/// Whether an interactive game has been won yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameStatus {
+    /// The last guess was correct.
+    Won,
+    /// The game is still going, and the solver is ready for its next guess.
+    InProgress,
+}
+
+/// Drives a [`Guesser`] one turn at a time, without reading from any particular input.
+///
+/// This is useful when the results of each guess come from somewhere other than a known
+/// objective, such as a person playing the game in a GUI or over a network.
+///
+/// ```
+/// use rs_wordle_solver::GameStatus;
+/// use rs_wordle_solver::InteractiveSolver;
+/// use rs_wordle_solver::LetterResult;
+/// use rs_wordle_solver::RandomGuesser;
+/// use rs_wordle_solver::WordBank;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"])?;
+/// let mut solver = InteractiveSolver::new(RandomGuesser::new(bank));
+///
+/// let guess = solver.next_guess().unwrap();
+/// let status = solver.submit_result(&[LetterResult::Correct; 3])?;
+///
+/// assert_eq!(status, GameStatus::Won);
+/// assert_eq!(solver.num_guesses(), 1);
+/// assert_eq!(solver.next_guess(), None);
+/// # Ok::<(), WordleError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct InteractiveSolver<G: Guesser> {
+    guesser: G,
+    current_guess: Option<Arc<str>>,
+    num_guesses: u32,
+    status: GameStatus,
+}
+
+impl<G: Guesser> InteractiveSolver<G> {
+    /// Constructs a new `InteractiveSolver` that selects guesses with the given guesser.
+    pub fn new(guesser: G) -> Self {
+        Self {
+            guesser,
+            current_guess: None,
+            num_guesses: 0,
+            status: GameStatus::InProgress,
+        }
+    }
+
+    /// Returns the guess to make next, or `None` if the game has been won or the guesser has run
+    /// out of possible words.
+    ///
+    /// Calling this repeatedly without submitting a result returns the same guess.
+    pub fn next_guess(&mut self) -> Option<Arc<str>> {
+        if self.status == GameStatus::Won {
+            return None;
+        }
+        if self.current_guess.is_none() {
+            self.current_guess = self.guesser.select_next_guess();
+        }
+        self.current_guess.clone()
+    }
+
+    /// Submits the results of the guess last returned by [`Self::next_guess()`], and returns
+    /// whether the game has been won.
+    ///
+    /// Returns a [`WordleError::WordLength`] error if the number of results doesn't match the
+    /// length of the guess, a [`WordleError::InvalidResults`] error if there is no outstanding
+    /// guess, or the guesser's error if it fails to update with these results, such as a
+    /// [`WordleError::ContradictoryResults`] error. If this returns an error, the guess is still
+    /// outstanding, so corrected results can be submitted.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GameStatus;
+    /// use rs_wordle_solver::InteractiveSolver;
+    /// use rs_wordle_solver::LetterResult;
+    /// use rs_wordle_solver::RandomGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "def"])?;
+    /// let mut solver = InteractiveSolver::new(RandomGuesser::new(bank));
+    ///
+    /// // There's no outstanding guess yet.
+    /// assert!(matches!(
+    ///     solver.submit_result(&[LetterResult::NotPresent; 3]),
+    ///     Err(WordleError::InvalidResults)
+    /// ));
+    ///
+    /// solver.next_guess();
+    /// let status = solver.submit_result(&[LetterResult::NotPresent; 3])?;
+    ///
+    /// assert_eq!(status, GameStatus::InProgress);
+    /// assert_eq!(solver.num_guesses(), 1);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn submit_result(&mut self, results: &[LetterResult]) -> Result<GameStatus, WordleError> {
+        let guess = self
+            .current_guess
+            .clone()
+            .ok_or(WordleError::InvalidResults)?;
+        if results.len() != guess.chars().count() {
+            return Err(WordleError::WordLength(guess.chars().count()));
+        }
+        if results
+            .iter()
+            .all(|result| *result == LetterResult::Correct)
+        {
+            self.status = GameStatus::Won;
+        } else {
+            self.guesser.update(&GuessResult {
+                guess: &guess,
+                results: results.to_vec(),
+            })?;
+        }
+        self.current_guess = None;
+        self.num_guesses += 1;
+        Ok(self.status)
+    }
+
+    /// Determines the results of the outstanding guess against the given objective using
+    /// [`get_result_for_guess()`], and submits them with [`Self::submit_result()`].
+    ///
+    /// ```
+    /// use rs_wordle_solver::GameStatus;
+    /// use rs_wordle_solver::InteractiveSolver;
+    /// use rs_wordle_solver::RandomGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"])?;
+    /// let mut solver = InteractiveSolver::new(RandomGuesser::new(bank));
+    ///
+    /// while solver.next_guess().is_some() {
+    ///     solver.submit_result_for_objective("bcd")?;
+    /// }
+    ///
+    /// assert_eq!(solver.status(), GameStatus::Won);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn submit_result_for_objective(
+        &mut self,
+        objective: &str,
+    ) -> Result<GameStatus, WordleError> {
+        let guess = self
+            .current_guess
+            .clone()
+            .ok_or(WordleError::InvalidResults)?;
+        let result = get_result_for_guess(objective, &guess)?;
+        self.submit_result(&result.results)
+    }
+
+    /// The number of guesses whose results have been submitted.
+    #[inline]
+    pub fn num_guesses(&self) -> u32 {
+        self.num_guesses
+    }
+
+    /// Whether the game has been won.
+    #[inline]
+    pub fn status(&self) -> GameStatus {
+        self.status
+    }
+
+    /// The underlying guesser.
+    #[inline]
+    pub fn guesser(&self) -> &G {
+        &self.guesser
+    }
+}
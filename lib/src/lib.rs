@@ -14,7 +14,7 @@
 //! // Play the game.
 //! let max_number_of_guesses = 3;
 //! let objective = "bcd";
-//! let result = play_game_with_guesser(objective, max_number_of_guesses, guesser);
+//! let result = play_game_with_guesser(objective, max_number_of_guesses, guesser)?;
 //!
 //! assert!(matches!(result, GameResult::Success(_guesses_made)));
 //! # Ok::<(), WordleError>(())
@@ -56,17 +56,39 @@
 //! If you want to implement your own algorithm, the easiest place to start is likely by
 //! implementing the [`scorers::WordScorer`] trait, and using this with [`MaxScoreGuesser`]. There
 //! are additional helpful utilities for implementing your own algorithms in the [`details`] mod.
+//!
+//! ## Features
+//!
+//! * `parallel` (default): Runs expensive computations, such as scoring large lists of words, on
+//!   multiple threads. Disable this to build for targets without threads, such as
+//!   `wasm32-unknown-unknown`.
+//! * `serde`: Implements `Serialize` and `Deserialize` for the guessers and related types.
+//! * `unicode`: Normalizes each word in a [`WordBank`] to Unicode NFC form, so accented letters
+//!   are always a single `char`, and rejects words with combining characters that can't be
+//!   composed.
 
 mod data;
 mod engine;
+mod interactive;
+mod multi_board;
+mod parallel;
 mod restrictions;
 mod results;
+mod tree;
 
+pub use data::LoadOptions;
+pub use data::WeightedWordBank;
 pub use data::WordBank;
 pub use engine::*;
+pub use interactive::{GameStatus, InteractiveSolver};
+pub use multi_board::{play_multi_game, MultiBoardGuesser};
+pub use restrictions::UpdateSummary;
 pub use results::{
-    get_result_for_guess, GameData, GameResult, GuessResult, LetterResult, TurnData, WordleError,
+    get_result_for_guess, get_result_for_guess_with, parse_result_string, DuplicateMode, GameData,
+    GameResult, GameStats, GuessResult, GuessResultBuf, LetterResult, PartialGuessResult,
+    PartialLetterResult, ShareTheme, TurnData, WordleError,
 };
+pub use tree::DecisionTree;
 
 /// Scorers for determining which word is the best guess.
 ///
@@ -79,8 +101,12 @@ pub mod scorers;
 pub mod details {
     pub use crate::data::LocatedLetter;
     pub use crate::data::WordCounter;
+    pub use crate::data::WordMasks;
     pub use crate::data::WordTracker;
     pub use crate::restrictions::*;
+    pub use crate::results::ambiguous_word_pairs;
+    pub use crate::results::count_result_patterns;
+    pub use crate::results::guesses_reducing_below;
     pub use crate::results::CompressedGuessResult;
     pub use crate::results::MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT;
 }
@@ -0,0 +1,277 @@
+use crate::data::WordBank;
+use crate::engine::*;
+use crate::parallel::*;
+use crate::restrictions::WordRestrictions;
+use crate::results::*;
+use crate::scorers::WordScorer;
+use std::collections::HashSet;
+use std::result::Result;
+use std::sync::Arc;
+
+/// Guesses for several boards at once, such as in Dordle (2 boards) or Quordle (4 boards), where
+/// each guess is played on every board.
+///
+/// Each board is tracked by its own [`MaxScoreGuesser`]. Each guess is the word with the highest
+/// total score across all boards that are not yet solved, except that if any board is down to one
+/// possible word, that word is guessed.
+///
+/// This is generic over the [`WordScorer`] rather than over any [`Guesser`], because the
+/// [`Guesser`] trait has no way to score an arbitrary word, so the scores of different boards
+/// couldn't be summed.
+///
+/// ```
+/// use rs_wordle_solver::GuessFrom;
+/// use rs_wordle_solver::MultiBoardGuesser;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::get_result_for_guess;
+/// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let bank = WordBank::from_iterator(&["abc", "abd", "bcd", "cde"])?;
+/// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+/// let mut guesser = MultiBoardGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer, 2);
+///
+/// let guess = guesser.select_next_guess().unwrap();
+/// guesser.update(&[
+///     get_result_for_guess("abd", &guess)?,
+///     get_result_for_guess("cde", &guess)?,
+/// ])?;
+///
+/// assert!(guesser.possible_words(0).iter().any(|word| word.as_ref() == "abd"));
+/// assert!(guesser.possible_words(1).iter().any(|word| word.as_ref() == "cde"));
+/// # Ok::<(), WordleError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultiBoardGuesser<T>
+where
+    T: WordScorer + Clone + Sync,
+{
+    guess_from: GuessFrom,
+    boards: Vec<MaxScoreGuesser<T>>,
+    is_finished: Vec<bool>,
+}
+
+impl<T> MultiBoardGuesser<T>
+where
+    T: WordScorer + Clone + Sync,
+{
+    /// Constructs a new `MultiBoardGuesser` for the given number of boards, each of which starts
+    /// with a copy of the given word bank and scorer.
+    ///
+    /// Candidate guesses are chosen according to `guess_from`. With
+    /// [`GuessFrom::PossibleWords`], the candidates are the words that are possible on at least
+    /// one unsolved board.
+    pub fn new(
+        guess_from: GuessFrom,
+        word_bank: WordBank,
+        scorer: T,
+        num_boards: usize,
+    ) -> MultiBoardGuesser<T> {
+        let board = MaxScoreGuesser::new(guess_from, word_bank, scorer);
+        MultiBoardGuesser {
+            guess_from,
+            boards: vec![board; num_boards],
+            is_finished: vec![false; num_boards],
+        }
+    }
+
+    /// The number of boards being played.
+    pub fn num_boards(&self) -> usize {
+        self.boards.len()
+    }
+
+    /// Returns true iff the given board has been solved.
+    pub fn is_solved(&self, board: usize) -> bool {
+        self.is_finished[board]
+    }
+
+    /// Provides read access to the remaining set of possible words for the given board.
+    pub fn possible_words(&self, board: usize) -> &[Arc<str>] {
+        self.boards[board].possible_words()
+    }
+
+    /// Updates each board with the result of the latest guess on that board. Results for boards
+    /// that are already solved are ignored.
+    ///
+    /// Returns a [`WordleError::InvalidResults`] error if the number of results doesn't match
+    /// the number of boards, or if any result is inconsistent with the previous results for its
+    /// board.
+    pub fn update(&mut self, results: &[GuessResult]) -> Result<(), WordleError> {
+        if results.len() != self.boards.len() {
+            return Err(WordleError::InvalidResults);
+        }
+        for (index, result) in results.iter().enumerate() {
+            if self.is_finished[index] {
+                continue;
+            }
+            if result
+                .results
+                .iter()
+                .all(|letter_result| *letter_result == LetterResult::Correct)
+            {
+                self.is_finished[index] = true;
+                continue;
+            }
+            self.boards[index].update(result)?;
+        }
+        Ok(())
+    }
+
+    /// Selects the next guess to play on every board.
+    ///
+    /// Returns `None` if all boards are solved, or if no words are possible on any unsolved
+    /// board.
+    pub fn select_next_guess(&mut self) -> Option<Arc<str>> {
+        let active_boards: Vec<&MaxScoreGuesser<T>> = self
+            .boards
+            .iter()
+            .zip(self.is_finished.iter())
+            .filter(|(board, is_finished)| !**is_finished && !board.possible_words().is_empty())
+            .map(|(board, _)| board)
+            .collect();
+        if active_boards.is_empty() {
+            return None;
+        }
+        // A board with only one possible word can be solved right away.
+        if let Some(board) = active_boards
+            .iter()
+            .find(|board| board.possible_words().len() == 1)
+        {
+            return Some(Arc::clone(&board.possible_words()[0]));
+        }
+        let candidates: Vec<Arc<str>> = match self.guess_from {
+            GuessFrom::AllUnguessedWords => active_boards[0].unguessed_words().to_vec(),
+            GuessFrom::PossibleWords => {
+                let mut seen: HashSet<&Arc<str>> = HashSet::new();
+                active_boards
+                    .iter()
+                    .flat_map(|board| board.possible_words().iter())
+                    .filter(|word| seen.insert(*word))
+                    .map(Arc::clone)
+                    .collect()
+            }
+        };
+        let scores: Vec<f64> = candidates
+            .par_iter()
+            .map(|word| {
+                active_boards
+                    .iter()
+                    .map(|board| board.score_guess(word))
+                    .sum()
+            })
+            .collect();
+        let mut best: Option<(usize, f64)> = None;
+        for (index, score) in scores.into_iter().enumerate() {
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((index, score));
+            }
+        }
+        best.map(|(index, _)| Arc::clone(&candidates[index]))
+    }
+}
+
+/// Attempts to solve every board within the maximum number of guesses, where each board has the
+/// objective at the same index in `objectives`. Returns a [`GameResult`] for each board.
+///
+/// The turns recorded for each board stop once that board is solved. A board's result is
+/// [`GameResult::UnknownWord`] if its objective is not in the guesser's word bank. If the number of
+/// objectives doesn't match the number of boards, every result is [`GameResult::UnknownWord`].
+///
+/// Returns an error if a board fails to update with the result of one of the guesses.
+///
+/// ```
+/// use rs_wordle_solver::GameResult;
+/// use rs_wordle_solver::GuessFrom;
+/// use rs_wordle_solver::MultiBoardGuesser;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::play_multi_game;
+/// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let bank = WordBank::from_iterator(&["abc", "abd", "bcd", "cde"])?;
+/// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+/// let guesser = MultiBoardGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer, 2);
+///
+/// let results = play_multi_game(&["abd", "zzz"], 6, guesser)?;
+///
+/// assert!(matches!(results[0], GameResult::Success(_)));
+/// assert!(matches!(results[1], GameResult::UnknownWord));
+/// # Ok::<(), WordleError>(())
+/// ```
+pub fn play_multi_game<T>(
+    objectives: &[&str],
+    max_num_guesses: u32,
+    mut guesser: MultiBoardGuesser<T>,
+) -> Result<Vec<GameResult>, WordleError>
+where
+    T: WordScorer + Clone + Sync,
+{
+    if objectives.len() != guesser.num_boards() {
+        return Ok(vec![GameResult::UnknownWord; objectives.len()]);
+    }
+    let mut game_results: Vec<Option<GameResult>> = vec![None; objectives.len()];
+    let mut turns: Vec<Vec<TurnData>> = vec![Vec::new(); objectives.len()];
+    let mut restrictions: Vec<WordRestrictions> = objectives
+        .iter()
+        .map(|objective| WordRestrictions::new(objective.chars().count() as u8))
+        .collect();
+    for _ in 1..=max_num_guesses {
+        let guess = match guesser.select_next_guess() {
+            Some(guess) => guess,
+            None => {
+                for game_result in game_results.iter_mut() {
+                    game_result.get_or_insert(GameResult::UnknownWord);
+                }
+                break;
+            }
+        };
+        let mut results = Vec::with_capacity(objectives.len());
+        for (index, objective) in objectives.iter().enumerate() {
+            let result = match get_result_for_guess(objective, &guess) {
+                Ok(result) => result,
+                Err(_) => {
+                    game_results[index].get_or_insert(GameResult::UnknownWord);
+                    // Stop the guesser from considering this board.
+                    guesser.is_finished[index] = true;
+                    GuessResult {
+                        guess: &guess,
+                        results: Vec::new(),
+                    }
+                }
+            };
+            if game_results[index].is_none() {
+                turns[index].push(TurnData {
+                    guess: Box::from(guess.as_ref()),
+                    num_possible_words_before_guess: guesser.possible_words(index).len(),
+                    restrictions: Some(restrictions[index].clone()),
+                });
+                if result
+                    .results
+                    .iter()
+                    .all(|letter_result| *letter_result == LetterResult::Correct)
+                {
+                    game_results[index] = Some(GameResult::Success(GameData {
+                        turns: std::mem::take(&mut turns[index]),
+                    }));
+                } else {
+                    restrictions[index].update(&result)?;
+                }
+            }
+            results.push(result);
+        }
+        guesser.update(&results)?;
+        for (index, game_result) in game_results.iter_mut().enumerate() {
+            if game_result.is_none() && guesser.possible_words(index).is_empty() {
+                *game_result = Some(GameResult::UnknownWord);
+            }
+        }
+        if game_results.iter().all(Option::is_some) {
+            break;
+        }
+    }
+    Ok(game_results
+        .into_iter()
+        .zip(turns)
+        .map(|(game_result, turns)| game_result.unwrap_or(GameResult::Failure(GameData { turns })))
+        .collect())
+}
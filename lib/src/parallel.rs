@@ -0,0 +1,129 @@
+//! Parallel iteration that falls back to serial iteration when the `parallel` feature is disabled.
+//!
+//! With the feature enabled, this re-exports `rayon`'s traits. Without it, this provides
+//! serial equivalents of the few parts of rayon's API that this crate uses, so the same code
+//! compiles either way.
+
+#[cfg(feature = "parallel")]
+pub(crate) use rayon::prelude::*;
+
+#[cfg(not(feature = "parallel"))]
+pub(crate) use serial::*;
+
+/// The default limit above which lists are processed in parallel.
+#[cfg(feature = "parallel")]
+pub(crate) fn default_parallelisation_limit() -> usize {
+    std::thread::available_parallelism()
+        .map(std::num::NonZeroUsize::get)
+        .unwrap_or(1)
+}
+
+/// The default limit above which lists are processed in parallel. Without the `parallel`
+/// feature, nothing is processed in parallel.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn default_parallelisation_limit() -> usize {
+    usize::MAX
+}
+
+#[cfg(not(feature = "parallel"))]
+mod serial {
+    use std::cmp::Ordering;
+    use std::iter;
+
+    /// Wraps a serial iterator with methods that match rayon's parallel iterators.
+    pub(crate) struct SerialIter<I>(I);
+
+    impl<I: Iterator> SerialIter<I> {
+        pub(crate) fn map<B, F>(self, f: F) -> SerialIter<iter::Map<I, F>>
+        where
+            F: FnMut(I::Item) -> B,
+        {
+            SerialIter(self.0.map(f))
+        }
+
+        pub(crate) fn filter_map<B, F>(self, f: F) -> SerialIter<iter::FilterMap<I, F>>
+        where
+            F: FnMut(I::Item) -> Option<B>,
+        {
+            SerialIter(self.0.filter_map(f))
+        }
+
+        pub(crate) fn enumerate(self) -> SerialIter<iter::Enumerate<I>> {
+            SerialIter(self.0.enumerate())
+        }
+
+        /// Folds all items into a single value, starting from `identity()`.
+        pub(crate) fn fold<T, ID, F>(self, identity: ID, f: F) -> SerialIter<iter::Once<T>>
+        where
+            ID: Fn() -> T,
+            F: FnMut(T, I::Item) -> T,
+        {
+            SerialIter(iter::once(self.0.fold(identity(), f)))
+        }
+
+        /// Reduces all items into a single value, starting from `identity()`.
+        pub(crate) fn reduce<ID, F>(self, identity: ID, op: F) -> I::Item
+        where
+            ID: Fn() -> I::Item,
+            F: FnMut(I::Item, I::Item) -> I::Item,
+        {
+            self.0.fold(identity(), op)
+        }
+
+        /// Calls `op` on each item with a mutable reference to `init`, stopping at the first
+        /// error.
+        pub(crate) fn try_for_each_with<T, E, F>(mut self, mut init: T, mut op: F) -> Result<(), E>
+        where
+            F: FnMut(&mut T, I::Item) -> Result<(), E>,
+        {
+            self.0.try_for_each(|item| op(&mut init, item))
+        }
+
+        pub(crate) fn position_any<P>(mut self, predicate: P) -> Option<usize>
+        where
+            P: FnMut(I::Item) -> bool,
+        {
+            self.0.position(predicate)
+        }
+
+        pub(crate) fn collect<C>(self) -> C
+        where
+            C: FromIterator<I::Item>,
+        {
+            self.0.collect()
+        }
+    }
+
+    pub(crate) trait IntoParallelIterator: IntoIterator + Sized {
+        fn into_par_iter(self) -> SerialIter<Self::IntoIter> {
+            SerialIter(self.into_iter())
+        }
+    }
+
+    impl<T: IntoIterator> IntoParallelIterator for T {}
+
+    pub(crate) trait IntoParallelRefIterator<T> {
+        fn par_iter(&self) -> SerialIter<std::slice::Iter<'_, T>>;
+    }
+
+    impl<T> IntoParallelRefIterator<T> for [T] {
+        fn par_iter(&self) -> SerialIter<std::slice::Iter<'_, T>> {
+            SerialIter(self.iter())
+        }
+    }
+
+    pub(crate) trait ParallelSliceMut<T> {
+        fn par_sort_by<F>(&mut self, compare: F)
+        where
+            F: FnMut(&T, &T) -> Ordering;
+    }
+
+    impl<T> ParallelSliceMut<T> for [T] {
+        fn par_sort_by<F>(&mut self, compare: F)
+        where
+            F: FnMut(&T, &T) -> Ordering,
+        {
+            self.sort_by(compare);
+        }
+    }
+}
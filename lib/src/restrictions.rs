@@ -1,10 +1,16 @@
+use crate::data::letter_bit;
 use crate::data::LocatedLetter;
+use crate::data::RestrictionMasks;
+use crate::results::DuplicateMode;
 use crate::results::GuessResult;
 use crate::results::LetterResult;
+use crate::results::PartialGuessResult;
+use crate::results::PartialLetterResult;
 use crate::results::WordleError;
 use std::collections::btree_map::Entry;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::fmt;
 use std::iter::zip;
 use std::result::Result;
 
@@ -17,8 +23,46 @@ enum LocatedLetterState {
     NotHere,
 }
 
+/// Describes why a letter's known restrictions can't be updated, before it is known which letter
+/// this applies to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Contradiction {
+    location: Option<usize>,
+    reason: &'static str,
+}
+
+impl Contradiction {
+    fn new(reason: &'static str) -> Contradiction {
+        Contradiction {
+            location: None,
+            reason,
+        }
+    }
+
+    fn at(location: usize, reason: &'static str) -> Contradiction {
+        Contradiction {
+            location: Some(location),
+            reason,
+        }
+    }
+
+    /// Converts this into a [`WordleError::ContradictoryResults`] error for the given letter.
+    fn for_letter(self, letter: char) -> WordleError {
+        WordleError::ContradictoryResults {
+            letter,
+            location: self.location,
+            reason: self.reason,
+        }
+    }
+}
+
+const NOT_ENOUGH_LOCATIONS: Contradiction = Contradiction {
+    location: None,
+    reason: "there are not enough locations left for it to appear this many times",
+};
+
 /// Indicates information about a letter that is in the word.
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct PresentLetter {
     /// If known, the letter must appear exactly this many times in the word.
@@ -64,6 +108,14 @@ impl PresentLetter {
         self.min_count
     }
 
+    /// Returns the number of locations this letter is known to be at.
+    fn count_here(&self) -> u8 {
+        self.located_state
+            .iter()
+            .filter(|state| **state == LocatedLetterState::Here)
+            .count() as u8
+    }
+
     /// Returns the number of `Here` or `Unknown` letters.
     #[inline(always)]
     pub fn num_here_or_unknown(&self) -> u8 {
@@ -75,13 +127,18 @@ impl PresentLetter {
     /// If the required count for this letter is known, then this may fill any remaining `Unknown`
     /// locations with either `Here` or `NotHere` accordingly.
     ///
-    /// This returns a [`WordleError::InvalidResults`] error if this letter is already known not to
-    /// be at the given index.
-    pub fn set_must_be_at(&mut self, index: usize) -> Result<(), WordleError> {
+    /// This returns a [`Contradiction`] if this letter is already known not to be at the given
+    /// index.
+    pub fn set_must_be_at(&mut self, index: usize) -> Result<(), Contradiction> {
         let previous = self.located_state[index];
         match previous {
             LocatedLetterState::Here => return Ok(()),
-            LocatedLetterState::NotHere => return Err(WordleError::InvalidResults),
+            LocatedLetterState::NotHere => {
+                return Err(Contradiction::at(
+                    index,
+                    "it is already known not to be in this location",
+                ))
+            }
             _ => {}
         }
         self.located_state[index] = LocatedLetterState::Here;
@@ -109,13 +166,18 @@ impl PresentLetter {
     /// If setting this leaves only as many `Here` and `Unknown` locations as the value of
     /// `min_count`, then this sets the `Unknown` locations to `Here`.
     ///
-    /// This returns a [`WordleError::InvalidResults`] error if this letter is already known to be
-    /// at the given index.
-    pub fn set_must_not_be_at(&mut self, index: usize) -> Result<(), WordleError> {
+    /// This returns a [`Contradiction`] if this letter is already known to be at the given
+    /// index.
+    pub fn set_must_not_be_at(&mut self, index: usize) -> Result<(), Contradiction> {
         let previous = self.located_state[index];
         match previous {
             LocatedLetterState::NotHere => return Ok(()),
-            LocatedLetterState::Here => return Err(WordleError::InvalidResults),
+            LocatedLetterState::Here => {
+                return Err(Contradiction::at(
+                    index,
+                    "it is already known to be in this location",
+                ))
+            }
             _ => {}
         }
         self.located_state[index] = LocatedLetterState::NotHere;
@@ -133,23 +195,27 @@ impl PresentLetter {
 
     /// Sets the maximum number of times this letter can appear in the word.
     ///
-    /// Returns a [`WordleError::InvalidResults`] error if the required count is already set to a
-    /// different value, or if the `min_count` is known to be higher than the provided value.
-    pub fn set_required_count(&mut self, count: u8) -> Result<(), WordleError> {
+    /// Returns a [`Contradiction`] if the required count is already set to a different value, or if
+    /// the `min_count` is known to be higher than the provided value.
+    pub fn set_required_count(&mut self, count: u8) -> Result<(), Contradiction> {
         if let Some(existing_count) = self.maybe_required_count {
             if existing_count != count {
-                return Err(WordleError::InvalidResults);
+                return Err(Contradiction::new(
+                    "it is already known to appear a different number of times",
+                ));
             } else {
                 return Ok(());
             }
         }
         if self.min_count > count {
-            return Err(WordleError::InvalidResults);
+            return Err(Contradiction::new(
+                "it is already known to appear more times than this",
+            ));
         }
         self.min_count = count;
         let num_here_or_unknown = self.num_here_or_unknown();
         if num_here_or_unknown < count {
-            return Err(WordleError::InvalidResults);
+            return Err(NOT_ENOUGH_LOCATIONS);
         }
         self.maybe_required_count = Some(count);
         if self.num_here == count {
@@ -163,9 +229,9 @@ impl PresentLetter {
     /// If count is higher than the current min count, this bumps it up to the provided value and
     /// modifies the known data as needed.
     ///
-    /// Returns a [`WordleError::InvalidResults`] error if it would be impossible for `count`
-    /// locations to be marked `Here` given what is already known about the word.
-    pub fn possibly_bump_min_count(&mut self, count: u8) -> Result<(), WordleError> {
+    /// Returns a [`Contradiction`] if it would be impossible for `count` locations to be marked
+    /// `Here` given what is already known about the word.
+    pub fn possibly_bump_min_count(&mut self, count: u8) -> Result<(), Contradiction> {
         if self.min_count >= count {
             return Ok(());
         }
@@ -173,7 +239,7 @@ impl PresentLetter {
         self.min_count = count;
         let max_possible_num_here = self.located_state.len() as u8 - self.num_not_here;
         if max_possible_num_here < count {
-            return Err(WordleError::InvalidResults);
+            return Err(NOT_ENOUGH_LOCATIONS);
         } else if max_possible_num_here == count && self.num_here < count {
             // If all possible unknowns must be here, set them.
             self.set_unknowns_to_here();
@@ -184,8 +250,8 @@ impl PresentLetter {
 
     /// Merges the information known in the other object into this one.
     ///
-    /// Returns a [`WordleError::InvalidResults`] error if they contain incompatible information.
-    pub fn merge(&mut self, other: &PresentLetter) -> Result<(), WordleError> {
+    /// Returns a [`Contradiction`] if they contain incompatible information.
+    pub fn merge(&mut self, other: &PresentLetter) -> Result<(), Contradiction> {
         if let Some(count) = other.maybe_required_count {
             self.set_required_count(count)?;
         } else if other.min_count > self.min_count {
@@ -218,7 +284,7 @@ impl PresentLetter {
         for state in &mut self.located_state {
             if *state == LocatedLetterState::Unknown {
                 *state = LocatedLetterState::NotHere;
-                self.num_here += 1;
+                self.num_not_here += 1;
             }
         }
     }
@@ -245,16 +311,56 @@ pub enum LetterRestriction {
     NotPresent,
 }
 
+/// The coloring of each guessed letter on an on-screen keyboard, as shown in Wordle after each
+/// guess. Each list of letters is sorted, and a letter appears in at most one list.
+///
+/// See [`WordRestrictions::keyboard_state()`].
+#[derive(Debug, PartialEq, Eq, Clone, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyboardState {
+    /// Letters whose location is known in at least one place.
+    pub green: Vec<char>,
+    /// Letters that are in the word, but whose location is not yet known.
+    pub yellow: Vec<char>,
+    /// Letters that are not in the word.
+    pub grey: Vec<char>,
+}
+
+/// What was newly learned about the word from an update to a set of [`WordRestrictions`].
+///
+/// See [`WordRestrictions::changes_since()`].
+#[derive(Debug, PartialEq, Eq, Clone, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UpdateSummary {
+    /// Letters that are newly known to be in the word, in sorted order.
+    pub newly_present: Vec<char>,
+    /// Letters that are newly known to be at a location, sorted by location.
+    pub newly_placed: Vec<LocatedLetter>,
+    /// Letters that are newly known not to be in the word, in sorted order.
+    pub newly_absent: Vec<char>,
+}
+
+impl UpdateSummary {
+    /// Returns `true` iff nothing new was learned.
+    pub fn is_empty(&self) -> bool {
+        self.newly_present.is_empty()
+            && self.newly_placed.is_empty()
+            && self.newly_absent.is_empty()
+    }
+}
+
 /// Defines letter restrictions that a word must adhere to, such as "the first letter of the word
 /// must be 'a'".
 ///
 /// Restrictions are derived from [`GuessResult`]s.
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WordRestrictions {
     word_length: u8,
     present_letters: BTreeMap<char, PresentLetter>,
     not_present_letters: BTreeSet<char>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    duplicate_mode: DuplicateMode,
 }
 
 impl WordRestrictions {
@@ -264,23 +370,71 @@ impl WordRestrictions {
             word_length,
             present_letters: BTreeMap::new(),
             not_present_letters: BTreeSet::new(),
+            duplicate_mode: DuplicateMode::Standard,
         }
     }
 
+    /// Sets how repeated letters are marked in the results that these restrictions are updated
+    /// with. The default is [`DuplicateMode::Standard`].
+    ///
+    /// With [`DuplicateMode::Simple`], every letter in a guess that is anywhere in the objective is
+    /// marked as present, so repeated hints don't say how many times the letter appears.
+    ///
+    /// ```
+    /// use rs_wordle_solver::DuplicateMode;
+    /// use rs_wordle_solver::details::WordRestrictions;
+    /// use rs_wordle_solver::get_result_for_guess_with;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let result = get_result_for_guess_with("abcd", "aaxa", DuplicateMode::Simple)?;
+    /// let mut restrictions = WordRestrictions::new(4).with_duplicate_mode(DuplicateMode::Simple);
+    /// restrictions.update(&result)?;
+    ///
+    /// assert!(restrictions.is_satisfied_by("abcd"));
+    /// assert!(!restrictions.is_satisfied_by("axcd"));
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn with_duplicate_mode(mut self, duplicate_mode: DuplicateMode) -> Self {
+        self.duplicate_mode = duplicate_mode;
+        self
+    }
+
+    /// Returns how repeated letters are marked in the results that these restrictions are updated
+    /// with.
+    pub fn duplicate_mode(&self) -> DuplicateMode {
+        self.duplicate_mode
+    }
+
     /// Returns the restrictions imposed by the given result.
+    ///
+    /// Panics if the number of results differs from the length of the guess.
     pub fn from_result(result: &GuessResult) -> WordRestrictions {
-        let mut restrictions = WordRestrictions::new(result.guess.len() as u8);
+        let mut restrictions = WordRestrictions::new(result.guess.chars().count() as u8);
         restrictions.update(result).unwrap();
         restrictions
     }
 
-    /// Adds restrictions arising from the given result.
+    /// Adds restrictions arising from the given result, which is read according to
+    /// [`Self::duplicate_mode()`].
     ///
-    /// Returns a [`WordleError::InvalidResults`] error if the result is incompatible with the
-    /// existing restrictions.
+    /// Returns a [`WordleError::WordLength`] error if the guess or the number of results differs
+    /// from the word length, or a [`WordleError::ContradictoryResults`] error describing the first
+    /// conflict if the result is incompatible with the existing restrictions.
     pub fn update(&mut self, guess_result: &GuessResult) -> Result<(), WordleError> {
+        let word_length = self.word_length as usize;
+        if guess_result.guess.chars().count() != word_length
+            || guess_result.results.len() != word_length
+        {
+            return Err(WordleError::WordLength(word_length));
+        }
+        if self.duplicate_mode == DuplicateMode::Simple {
+            return self.update_simple(
+                guess_result.guess,
+                guess_result.results.iter().map(|result| (*result).into()),
+            );
+        }
         for ((index, letter), result) in zip(
-            guess_result.guess.char_indices(),
+            guess_result.guess.chars().enumerate(),
             guess_result.results.iter(),
         ) {
             match result {
@@ -298,22 +452,150 @@ impl WordRestrictions {
         Ok(())
     }
 
+    /// Adds only the restrictions that are certain from the given partial result.
+    ///
+    /// [`PartialLetterResult::PresentMaybeHere`] marks its letter as present without restricting
+    /// where it is, and [`PartialLetterResult::Unknown`] adds no restrictions for its letter's
+    /// location. A letter's exact count is only set if it was marked
+    /// [`PartialLetterResult::NotPresent`] and none of its results are unknown, and never in
+    /// [`DuplicateMode::Simple`]. Fully known results add the same restrictions as in
+    /// [`Self::update()`].
+    ///
+    /// Returns a [`WordleError::WordLength`] error if the guess or the number of results differs
+    /// from the word length, or a [`WordleError::ContradictoryResults`] error if the result is
+    /// incompatible with the existing restrictions.
+    ///
+    /// ```
+    /// use rs_wordle_solver::PartialGuessResult;
+    /// use rs_wordle_solver::PartialLetterResult;
+    /// use rs_wordle_solver::details::WordRestrictions;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let mut restrictions = WordRestrictions::new(3);
+    /// restrictions.update_partial(&PartialGuessResult {
+    ///     guess: "abc",
+    ///     results: vec![
+    ///         PartialLetterResult::PresentMaybeHere,
+    ///         PartialLetterResult::Unknown,
+    ///         PartialLetterResult::NotPresent,
+    ///     ],
+    /// })?;
+    ///
+    /// assert!(restrictions.is_satisfied_by("abd"));
+    /// assert!(restrictions.is_satisfied_by("dea"));
+    /// assert!(!restrictions.is_satisfied_by("bcd"));
+    /// assert!(!restrictions.is_satisfied_by("abc"));
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn update_partial(&mut self, guess_result: &PartialGuessResult) -> Result<(), WordleError> {
+        let word_length = self.word_length as usize;
+        if guess_result.guess.chars().count() != word_length
+            || guess_result.results.len() != word_length
+        {
+            return Err(WordleError::WordLength(word_length));
+        }
+        if let Some(full_result) = guess_result.to_guess_result() {
+            return self.update(&full_result);
+        }
+        if self.duplicate_mode == DuplicateMode::Simple {
+            return self.update_simple(guess_result.guess, guess_result.results.iter().copied());
+        }
+        let mut letters: BTreeSet<char> = BTreeSet::new();
+        for letter in guess_result.guess.chars() {
+            if !letters.insert(letter) {
+                continue;
+            }
+            let mut correct_locations = Vec::new();
+            let mut not_here_locations = Vec::new();
+            let mut num_times_present = 0u8;
+            let mut num_times_not_present = 0u8;
+            let mut num_times_unknown = 0u8;
+            for ((index, other_letter), result) in zip(
+                guess_result.guess.chars().enumerate(),
+                guess_result.results.iter(),
+            ) {
+                if other_letter != letter {
+                    continue;
+                }
+                match result {
+                    PartialLetterResult::Correct => {
+                        correct_locations.push(index);
+                        num_times_present += 1;
+                    }
+                    PartialLetterResult::PresentNotHere => {
+                        not_here_locations.push(index);
+                        num_times_present += 1;
+                    }
+                    PartialLetterResult::PresentMaybeHere => {
+                        num_times_present += 1;
+                    }
+                    PartialLetterResult::NotPresent => {
+                        not_here_locations.push(index);
+                        num_times_not_present += 1;
+                    }
+                    PartialLetterResult::Unknown => {
+                        num_times_unknown += 1;
+                    }
+                }
+            }
+            let is_count_known = num_times_not_present > 0 && num_times_unknown == 0;
+            if num_times_present == 0 {
+                if is_count_known {
+                    self.add_absent(letter)?;
+                } else if let Some(presence) = self.present_letters.get_mut(&letter) {
+                    for location in not_here_locations {
+                        presence
+                            .set_must_not_be_at(location)
+                            .map_err(|contradiction| contradiction.for_letter(letter))?;
+                    }
+                }
+                continue;
+            }
+            let presence = self.present_letter_mut(letter)?;
+            for location in &correct_locations {
+                presence
+                    .set_must_be_at(*location)
+                    .map_err(|contradiction| contradiction.for_letter(letter))?;
+            }
+            for location in not_here_locations {
+                presence
+                    .set_must_not_be_at(location)
+                    .map_err(|contradiction| contradiction.for_letter(letter))?;
+            }
+            let count_result = if is_count_known {
+                presence.set_required_count(num_times_present)
+            } else {
+                presence.possibly_bump_min_count(num_times_present)
+            };
+            count_result.map_err(|contradiction| contradiction.for_letter(letter))?;
+            for location in correct_locations {
+                self.set_other_letters_not_at(letter, location)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Adds the given restrictions to this restriction.
     ///
-    /// Returns a [`WordleError::InvalidResults`] error if the results are incompatible.
+    /// Returns a [`WordleError::InvalidResults`] error if the word lengths differ, or a
+    /// [`WordleError::ContradictoryResults`] error if the known letters are incompatible.
     pub fn merge(&mut self, other: &WordRestrictions) -> Result<(), WordleError> {
         if self.word_length != other.word_length {
             return Err(WordleError::InvalidResults);
         }
         for not_present_letter in &other.not_present_letters {
             if self.present_letters.contains_key(not_present_letter) {
-                return Err(WordleError::InvalidResults);
+                return Err(Contradiction::new("it is already known to be in the word")
+                    .for_letter(*not_present_letter));
             }
             self.not_present_letters.insert(*not_present_letter);
         }
         for (letter, presence) in &other.present_letters {
             if self.not_present_letters.contains(letter) {
-                return Err(WordleError::InvalidResults);
+                return Err(
+                    Contradiction::new("it is already known not to be in the word")
+                        .for_letter(*letter),
+                );
             }
             let mut result = Ok(());
             self.present_letters
@@ -322,17 +604,66 @@ impl WordRestrictions {
                     result = known_presence.merge(presence);
                 })
                 .or_insert_with(|| presence.clone());
-            result?;
+            result.map_err(|contradiction| contradiction.for_letter(*letter))?;
         }
         Ok(())
     }
 
+    /// Combines all the given restrictions into one, as if each were [merged](Self::merge) in
+    /// turn.
+    ///
+    /// This is useful for puzzles where the same guesses are applied to several boards that share
+    /// a single answer.
+    ///
+    /// Returns an error as for [`Self::merge()`] if any of the restrictions are incompatible, or a
+    /// [`WordleError::InvalidResults`] error if no restrictions are given.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessResult;
+    /// use rs_wordle_solver::LetterResult;
+    /// use rs_wordle_solver::details::WordRestrictions;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let first = WordRestrictions::from_result(&GuessResult {
+    ///     guess: "abc",
+    ///     results: vec![
+    ///         LetterResult::Correct,
+    ///         LetterResult::NotPresent,
+    ///         LetterResult::NotPresent,
+    ///     ],
+    /// });
+    /// let second = WordRestrictions::from_result(&GuessResult {
+    ///     guess: "ade",
+    ///     results: vec![
+    ///         LetterResult::Correct,
+    ///         LetterResult::NotPresent,
+    ///         LetterResult::Correct,
+    ///     ],
+    /// });
+    ///
+    /// let merged = WordRestrictions::merge_all([&first, &second])?;
+    ///
+    /// assert!(merged.is_satisfied_by("afe"));
+    /// assert!(!merged.is_satisfied_by("abe"));
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn merge_all<'a>(
+        restrictions: impl IntoIterator<Item = &'a WordRestrictions>,
+    ) -> Result<WordRestrictions, WordleError> {
+        let mut iter = restrictions.into_iter();
+        let mut merged = iter.next().ok_or(WordleError::InvalidResults)?.clone();
+        for other in iter {
+            merged.merge(other)?;
+        }
+        Ok(merged)
+    }
+
     /// Returns `true` iff the given word satisfies these restrictions.
     pub fn is_satisfied_by(&self, word: &str) -> bool {
-        word.len() == self.word_length as usize
+        word.chars().count() == self.word_length as usize
             && self.present_letters.iter().all(|(letter, presence)| {
                 let mut count_found = 0;
-                for (index, word_letter) in word.char_indices() {
+                for (index, word_letter) in word.chars().enumerate() {
                     if word_letter == *letter {
                         count_found += 1;
                         if presence.state(index) == LocatedLetterState::NotHere {
@@ -352,6 +683,73 @@ impl WordRestrictions {
                 .all(|letter| !self.not_present_letters.contains(&letter))
     }
 
+    /// Computes the masks for checking words against these restrictions with
+    /// [`WordMasks`](crate::details::WordMasks).
+    ///
+    /// Returns `None` if any known letter isn't a lower case ASCII letter.
+    pub(crate) fn to_masks(&self) -> Option<RestrictionMasks> {
+        let mut masks = RestrictionMasks::new(self.word_length as usize);
+        for letter in &self.not_present_letters {
+            masks.absent |= letter_bit(*letter)?;
+        }
+        for (letter, presence) in &self.present_letters {
+            let bit = letter_bit(*letter)?;
+            masks.required |= bit;
+            for (index, state) in presence.located_state.iter().enumerate() {
+                match state {
+                    LocatedLetterState::Here => masks.here[index] |= bit,
+                    LocatedLetterState::NotHere => masks.not_here[index] |= bit,
+                    LocatedLetterState::Unknown => {}
+                }
+            }
+            if presence.min_count() > 1 || presence.maybe_required_count().is_some() {
+                masks.depends_on_counts = true;
+            }
+        }
+        Some(masks)
+    }
+
+    /// Returns `true` iff the given word would be a legal guess in Wordle's hard mode, i.e. every
+    /// letter known to be in a location is in that location, and every letter known to be in the
+    /// word is used at least as many times as it is known to appear.
+    ///
+    /// This is looser than [`Self::is_satisfied_by()`]: the word may still contain letters that
+    /// are not present, or put present letters in locations where they are known not to be.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessResult;
+    /// use rs_wordle_solver::LetterResult;
+    /// use rs_wordle_solver::details::WordRestrictions;
+    ///
+    /// let restrictions = WordRestrictions::from_result(&GuessResult {
+    ///     guess: "abc",
+    ///     results: vec![
+    ///         LetterResult::Correct,
+    ///         LetterResult::PresentNotHere,
+    ///         LetterResult::NotPresent,
+    ///     ],
+    /// });
+    ///
+    /// assert!(restrictions.is_hard_mode_guess("abc"));
+    /// assert!(!restrictions.is_satisfied_by("abc"));
+    /// assert!(!restrictions.is_hard_mode_guess("bbc"));
+    /// assert!(!restrictions.is_hard_mode_guess("acc"));
+    /// ```
+    pub fn is_hard_mode_guess(&self, word: &str) -> bool {
+        word.chars().count() == self.word_length as usize
+            && self.present_letters.iter().all(|(letter, presence)| {
+                let mut count_found = 0;
+                for (index, word_letter) in word.chars().enumerate() {
+                    if word_letter == *letter {
+                        count_found += 1;
+                    } else if presence.state(index) == LocatedLetterState::Here {
+                        return false;
+                    }
+                }
+                count_found >= presence.min_count()
+            })
+    }
+
     /// Returns true iff the exact state of the given letter at the given location is already known.
     pub fn is_state_known(&self, ll: LocatedLetter) -> bool {
         if let Some(presence) = self.present_letters.get(&ll.letter) {
@@ -369,19 +767,197 @@ impl WordRestrictions {
     ///     * `PresentMaybeHere` -> The letter is present, but we don't know if it's here or not.
     ///     * `Here` -> The letter goes here.
     pub fn state(&self, ll: &LocatedLetter) -> Option<LetterRestriction> {
-        if let Some(presence) = self.present_letters.get(&ll.letter) {
-            return match presence.state(ll.location as usize) {
+        self.state_at(ll.letter, Some(ll.location))
+    }
+
+    /// Returns the current known state of this letter at the given location, like
+    /// [`Self::state()`]. If `location` is `None`, this only describes whether the letter is in
+    /// the word, so a present letter is always `PresentMaybeHere`.
+    ///
+    /// ```
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// use rs_wordle_solver::details::LetterRestriction;
+    /// use rs_wordle_solver::details::WordRestrictions;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let restrictions = WordRestrictions::from_result(&get_result_for_guess("abc", "abz")?);
+    ///
+    /// assert_eq!(restrictions.state_at('a', Some(0)), Some(LetterRestriction::Here));
+    /// assert_eq!(restrictions.state_at('a', None), Some(LetterRestriction::PresentMaybeHere));
+    /// assert_eq!(restrictions.state_at('z', None), Some(LetterRestriction::NotPresent));
+    /// assert_eq!(restrictions.state_at('c', None), None);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn state_at(&self, letter: char, location: Option<u8>) -> Option<LetterRestriction> {
+        if let Some(presence) = self.present_letters.get(&letter) {
+            let state = match location {
+                Some(location) => presence.state(location as usize),
+                None => LocatedLetterState::Unknown,
+            };
+            return match state {
                 LocatedLetterState::Here => Some(LetterRestriction::Here),
                 LocatedLetterState::NotHere => Some(LetterRestriction::PresentNotHere),
                 LocatedLetterState::Unknown => Some(LetterRestriction::PresentMaybeHere),
             };
         }
-        if self.not_present_letters.contains(&ll.letter) {
+        if self.not_present_letters.contains(&letter) {
             return Some(LetterRestriction::NotPresent);
         }
         None
     }
 
+    /// Returns the minimum number of times the given letter must appear in the word, and the exact
+    /// number of times if this is known. Letters that are known not to be in the word must appear
+    /// exactly zero times.
+    ///
+    /// ```
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// use rs_wordle_solver::details::WordRestrictions;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let restrictions = WordRestrictions::from_result(&get_result_for_guess("sheep", "eerie")?);
+    ///
+    /// // Two 'e's were found, but the third 'e' in the guess was not, so there are exactly two.
+    /// assert_eq!(restrictions.letter_count_bounds('e'), (2, Some(2)));
+    /// assert_eq!(restrictions.letter_count_bounds('r'), (0, Some(0)));
+    /// assert_eq!(restrictions.letter_count_bounds('z'), (0, None));
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn letter_count_bounds(&self, letter: char) -> (u8, Option<u8>) {
+        if let Some(presence) = self.present_letters.get(&letter) {
+            return (presence.min_count(), presence.maybe_required_count());
+        }
+        if self.not_present_letters.contains(&letter) {
+            return (0, Some(0));
+        }
+        (0, None)
+    }
+
+    /// Iterates over the letters that are known not to be in the word, in sorted order.
+    pub fn absent_letters(&self) -> impl Iterator<Item = char> + '_ {
+        self.not_present_letters.iter().copied()
+    }
+
+    /// Returns the keyboard coloring for each letter that is known to be in, or not in, the word.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessResult;
+    /// use rs_wordle_solver::LetterResult;
+    /// use rs_wordle_solver::details::KeyboardState;
+    /// use rs_wordle_solver::details::WordRestrictions;
+    ///
+    /// let restrictions = WordRestrictions::from_result(&GuessResult {
+    ///     guess: "abc",
+    ///     results: vec![
+    ///         LetterResult::Correct,
+    ///         LetterResult::PresentNotHere,
+    ///         LetterResult::NotPresent,
+    ///     ],
+    /// });
+    ///
+    /// assert_eq!(
+    ///     restrictions.keyboard_state(),
+    ///     KeyboardState {
+    ///         green: vec!['a'],
+    ///         yellow: vec!['b'],
+    ///         grey: vec!['c'],
+    ///     }
+    /// );
+    /// ```
+    pub fn keyboard_state(&self) -> KeyboardState {
+        let mut state = KeyboardState {
+            grey: self.not_present_letters.iter().copied().collect(),
+            ..KeyboardState::default()
+        };
+        for (letter, presence) in &self.present_letters {
+            if presence.located_state.contains(&LocatedLetterState::Here) {
+                state.green.push(*letter);
+            } else {
+                state.yellow.push(*letter);
+            }
+        }
+        state
+    }
+
+    /// Returns what is known in these restrictions that wasn't known in the `previous`
+    /// restrictions, such as the restrictions before an update.
+    ///
+    /// ```
+    /// use rs_wordle_solver::UpdateSummary;
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// use rs_wordle_solver::details::LocatedLetter;
+    /// use rs_wordle_solver::details::WordRestrictions;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let mut restrictions = WordRestrictions::from_result(&get_result_for_guess("abcd", "xbyz")?);
+    /// let previous = restrictions.clone();
+    /// restrictions.update(&get_result_for_guess("abcd", "ebcd")?)?;
+    ///
+    /// assert_eq!(
+    ///     restrictions.changes_since(&previous),
+    ///     UpdateSummary {
+    ///         newly_present: vec!['c', 'd'],
+    ///         newly_placed: vec![LocatedLetter::new('c', 2), LocatedLetter::new('d', 3)],
+    ///         newly_absent: vec!['e'],
+    ///     }
+    /// );
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn changes_since(&self, previous: &WordRestrictions) -> UpdateSummary {
+        let mut summary = UpdateSummary {
+            newly_absent: self
+                .not_present_letters
+                .difference(&previous.not_present_letters)
+                .copied()
+                .collect(),
+            ..UpdateSummary::default()
+        };
+        for (letter, presence) in &self.present_letters {
+            let previous_presence = previous.present_letters.get(letter);
+            if previous_presence.is_none() {
+                summary.newly_present.push(*letter);
+            }
+            for (index, state) in presence.located_state.iter().enumerate() {
+                let was_here = previous_presence
+                    .is_some_and(|previous| previous.state(index) == LocatedLetterState::Here);
+                if *state == LocatedLetterState::Here && !was_here {
+                    summary
+                        .newly_placed
+                        .push(LocatedLetter::new(*letter, index as u8));
+                }
+            }
+        }
+        summary
+            .newly_placed
+            .sort_by_key(|located_letter| located_letter.location);
+        summary
+    }
+
+    /// Returns the partially-known answer, with each letter whose location is known filled in, and
+    /// an underscore at every other location.
+    ///
+    /// ```
+    /// use rs_wordle_solver::get_result_for_guess;
+    /// use rs_wordle_solver::details::WordRestrictions;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let restrictions = WordRestrictions::from_result(&get_result_for_guess("maybe", "eaten")?);
+    ///
+    /// assert_eq!(restrictions.known_template(), "_a___");
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn known_template(&self) -> String {
+        let mut template = vec!['_'; self.word_length as usize];
+        for (letter, presence) in &self.present_letters {
+            for (index, state) in presence.located_state.iter().enumerate() {
+                if *state == LocatedLetterState::Here {
+                    template[index] = *letter;
+                }
+            }
+        }
+        template.into_iter().collect()
+    }
+
     fn set_letter_here(
         &mut self,
         letter: char,
@@ -389,31 +965,31 @@ impl WordRestrictions {
         result: &GuessResult,
     ) -> Result<(), WordleError> {
         if self.not_present_letters.contains(&letter) {
-            return Err(WordleError::InvalidResults);
+            return Err(
+                Contradiction::at(location, "it is already known not to be in the word")
+                    .for_letter(letter),
+            );
         }
         let presence = self
             .present_letters
             .entry(letter)
             .or_insert_with(|| PresentLetter::new(self.word_length));
-        presence.set_must_be_at(location)?;
+        presence
+            .set_must_be_at(location)
+            .map_err(|contradiction| contradiction.for_letter(letter))?;
 
         let (num_times_present, num_times_not_present) =
             WordRestrictions::count_num_times_in_guess(letter, result);
         // If the letter is present, but at least one result was `NotPresent`, then it means it's
         // only in the word as many times as it was given a `Correct` or `PresentNotHere` hint.
-        if num_times_not_present > 0 {
-            presence.set_required_count(num_times_present)?;
+        let count_result = if num_times_not_present > 0 {
+            presence.set_required_count(num_times_present)
         } else {
-            presence.possibly_bump_min_count(num_times_present)?;
-        }
+            presence.possibly_bump_min_count(num_times_present)
+        };
+        count_result.map_err(|contradiction| contradiction.for_letter(letter))?;
 
-        for (other_letter, other_presence) in self.present_letters.iter_mut() {
-            if letter == *other_letter {
-                continue;
-            }
-            other_presence.set_must_not_be_at(location)?;
-        }
-        Ok(())
+        self.set_other_letters_not_at(letter, location)
     }
 
     fn set_letter_present_not_here(
@@ -423,22 +999,28 @@ impl WordRestrictions {
         result: &GuessResult,
     ) -> Result<(), WordleError> {
         if self.not_present_letters.contains(&letter) {
-            return Err(WordleError::InvalidResults);
+            return Err(
+                Contradiction::at(location, "it is already known not to be in the word")
+                    .for_letter(letter),
+            );
         }
         let presence = self
             .present_letters
             .entry(letter)
             .or_insert_with(|| PresentLetter::new(self.word_length));
-        presence.set_must_not_be_at(location)?;
+        presence
+            .set_must_not_be_at(location)
+            .map_err(|contradiction| contradiction.for_letter(letter))?;
         let (num_times_present, num_times_not_present) =
             WordRestrictions::count_num_times_in_guess(letter, result);
         // If the letter is present, but at least one result was `NotPresent`, then it means it's
         // only in the word as many times as it was given a `Correct` or `PresentNotHere` hint.
-        if num_times_not_present > 0 {
+        let count_result = if num_times_not_present > 0 {
             presence.set_required_count(num_times_present)
         } else {
             presence.possibly_bump_min_count(num_times_present)
-        }
+        };
+        count_result.map_err(|contradiction| contradiction.for_letter(letter))
     }
 
     fn set_letter_not_present(
@@ -451,11 +1033,17 @@ impl WordRestrictions {
         if let Entry::Occupied(mut presence_entry) = self.present_letters.entry(letter) {
             let presence = presence_entry.get_mut();
             if presence.state(location) == LocatedLetterState::Here {
-                return Err(WordleError::InvalidResults);
+                return Err(Contradiction::at(
+                    location,
+                    "it is already known to be in this location",
+                )
+                .for_letter(letter));
             }
             let (num_times_present, _) = WordRestrictions::count_num_times_in_guess(letter, result);
-            presence.set_required_count(num_times_present)?;
-            return presence.set_must_not_be_at(location);
+            return presence
+                .set_required_count(num_times_present)
+                .and_then(|_| presence.set_must_not_be_at(location))
+                .map_err(|contradiction| contradiction.for_letter(letter));
         }
         if num_times_present == 0 {
             self.not_present_letters.insert(letter);
@@ -463,10 +1051,110 @@ impl WordRestrictions {
         Ok(())
     }
 
+    /// Adds the restrictions from a result that was marked with the [`DuplicateMode::Simple`]
+    /// rules. Each result only says whether its letter is at its location, and whether the letter
+    /// is in the word at all, so the letter's count is never bounded above.
+    fn update_simple(
+        &mut self,
+        guess: &str,
+        results: impl Iterator<Item = PartialLetterResult>,
+    ) -> Result<(), WordleError> {
+        for ((location, letter), result) in zip(guess.chars().enumerate(), results) {
+            match result {
+                PartialLetterResult::Correct => self.add_correct(letter, location)?,
+                PartialLetterResult::PresentNotHere => {
+                    self.add_present_not_here(letter, location)?
+                }
+                PartialLetterResult::PresentMaybeHere => {
+                    self.present_letter_mut(letter)?;
+                }
+                PartialLetterResult::NotPresent => self.add_absent(letter)?,
+                PartialLetterResult::Unknown => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the presence information for the given letter, adding it if needed. A newly added
+    /// letter is marked as not being anywhere that another letter is known to be.
+    fn present_letter_mut(&mut self, letter: char) -> Result<&mut PresentLetter, WordleError> {
+        if self.not_present_letters.contains(&letter) {
+            return Err(
+                Contradiction::new("it is already known not to be in the word").for_letter(letter),
+            );
+        }
+        if !self.present_letters.contains_key(&letter) {
+            let mut presence = PresentLetter::new(self.word_length);
+            for other_presence in self.present_letters.values() {
+                for (index, state) in other_presence.located_state.iter().enumerate() {
+                    if *state == LocatedLetterState::Here {
+                        presence
+                            .set_must_not_be_at(index)
+                            .map_err(|contradiction| contradiction.for_letter(letter))?;
+                    }
+                }
+            }
+            self.present_letters.insert(letter, presence);
+        }
+        Ok(self.present_letters.get_mut(&letter).unwrap())
+    }
+
+    fn check_location(&self, location: usize) -> Result<(), WordleError> {
+        if location >= self.word_length as usize {
+            return Err(WordleError::InvalidResults);
+        }
+        Ok(())
+    }
+
+    fn add_correct(&mut self, letter: char, location: usize) -> Result<(), WordleError> {
+        self.check_location(location)?;
+        self.present_letter_mut(letter)?
+            .set_must_be_at(location)
+            .map_err(|contradiction| contradiction.for_letter(letter))?;
+        self.set_other_letters_not_at(letter, location)
+    }
+
+    fn add_present_not_here(&mut self, letter: char, location: usize) -> Result<(), WordleError> {
+        self.check_location(location)?;
+        let presence = self.present_letter_mut(letter)?;
+        presence
+            .set_must_not_be_at(location)
+            .and_then(|_| presence.possibly_bump_min_count(1))
+            .map_err(|contradiction| contradiction.for_letter(letter))
+    }
+
+    fn add_absent(&mut self, letter: char) -> Result<(), WordleError> {
+        if self.present_letters.contains_key(&letter) {
+            return Err(
+                Contradiction::new("it is already known to be in the word").for_letter(letter)
+            );
+        }
+        self.not_present_letters.insert(letter);
+        Ok(())
+    }
+
+    /// Marks every present letter other than the given one as not being at the given location,
+    /// since the given letter is known to be there.
+    fn set_other_letters_not_at(
+        &mut self,
+        letter: char,
+        location: usize,
+    ) -> Result<(), WordleError> {
+        for (other_letter, other_presence) in self.present_letters.iter_mut() {
+            if letter != *other_letter {
+                other_presence.set_must_not_be_at(location).map_err(|_| {
+                    Contradiction::at(location, "another letter is already known to be here")
+                        .for_letter(letter)
+                })?;
+            }
+        }
+        Ok(())
+    }
+
     fn count_num_times_in_guess(letter: char, guess_result: &GuessResult) -> (u8, u8) {
         let mut num_times_present = 0u32;
         let mut num_times_not_present = 0u32;
-        for (index, other_letter) in guess_result.guess.char_indices() {
+        for (index, other_letter) in guess_result.guess.chars().enumerate() {
             if other_letter != letter {
                 continue;
             }
@@ -483,12 +1171,188 @@ impl WordRestrictions {
     }
 }
 
+impl fmt::Display for WordRestrictions {
+    /// Summarises the known restrictions, e.g.
+    /// `greens: {0:'a'}, yellows: {'b' not at [1, 2]}, absent: {'c'}, counts: {'a'=1, 'b'>=2}`.
+    ///
+    /// Yellows list each present letter that has more occurrences than known locations, and any
+    /// locations it is known not to be at. Counts list each letter's required count if that is
+    /// known, or its minimum count if that is more than one.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessResult;
+    /// use rs_wordle_solver::LetterResult;
+    /// use rs_wordle_solver::details::WordRestrictions;
+    ///
+    /// let restrictions = WordRestrictions::from_result(&GuessResult {
+    ///     guess: "abcd",
+    ///     results: vec![
+    ///         LetterResult::Correct,
+    ///         LetterResult::PresentNotHere,
+    ///         LetterResult::NotPresent,
+    ///         LetterResult::NotPresent,
+    ///     ],
+    /// });
+    ///
+    /// assert_eq!(
+    ///     restrictions.to_string(),
+    ///     "greens: {0:'a'}, yellows: {'b' not at [1]}, absent: {'c', 'd'}, counts: {}"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut greens: Vec<(usize, char)> = self
+            .present_letters
+            .iter()
+            .flat_map(|(letter, presence)| {
+                presence
+                    .located_state
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, state)| **state == LocatedLetterState::Here)
+                    .map(|(index, _)| (index, *letter))
+            })
+            .collect();
+        greens.sort_unstable();
+        let greens: Vec<String> = greens
+            .iter()
+            .map(|(index, letter)| format!("{}:{:?}", index, letter))
+            .collect();
+
+        let yellows: Vec<String> = self
+            .present_letters
+            .iter()
+            .filter(|(_, presence)| presence.min_count > presence.count_here())
+            .map(|(letter, presence)| {
+                let not_here: Vec<usize> = presence
+                    .located_state
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, state)| **state == LocatedLetterState::NotHere)
+                    .map(|(index, _)| index)
+                    .collect();
+                if not_here.is_empty() {
+                    format!("{:?}", letter)
+                } else {
+                    format!("{:?} not at {:?}", letter, not_here)
+                }
+            })
+            .collect();
+
+        let absent: Vec<String> = self
+            .not_present_letters
+            .iter()
+            .map(|letter| format!("{:?}", letter))
+            .collect();
+
+        let counts: Vec<String> = self
+            .present_letters
+            .iter()
+            .filter_map(|(letter, presence)| match presence.maybe_required_count {
+                Some(count) => Some(format!("{:?}={}", letter, count)),
+                None if presence.min_count > 1 => {
+                    Some(format!("{:?}>={}", letter, presence.min_count))
+                }
+                None => None,
+            })
+            .collect();
+
+        write!(
+            f,
+            "greens: {{{}}}, yellows: {{{}}}, absent: {{{}}}, counts: {{{}}}",
+            greens.join(", "),
+            yellows.join(", "),
+            absent.join(", "),
+            counts.join(", ")
+        )
+    }
+}
+
+/// Builds [`WordRestrictions`] from individually specified constraints, instead of from
+/// [`GuessResult`]s.
+///
+/// Any contradictory constraints cause [`Self::build()`] to return a
+/// [`WordleError::InvalidResults`] error, as do locations that are out of bounds for the word
+/// length.
+///
+/// ```
+/// use rs_wordle_solver::details::WordRestrictionsBuilder;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let restrictions = WordRestrictionsBuilder::new(5)
+///     .with_correct('a', 2)
+///     .with_present_not_here('b', 0)
+///     .with_absent('c')
+///     .build()?;
+///
+/// assert!(restrictions.is_satisfied_by("dbade"));
+/// assert!(!restrictions.is_satisfied_by("bdade"));
+/// assert!(!restrictions.is_satisfied_by("dbace"));
+///
+/// assert!(WordRestrictionsBuilder::new(5)
+///     .with_correct('a', 2)
+///     .with_absent('a')
+///     .build()
+///     .is_err());
+/// # Ok::<(), WordleError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct WordRestrictionsBuilder {
+    restrictions: WordRestrictions,
+    is_valid: bool,
+}
+
+impl WordRestrictionsBuilder {
+    /// Constructs a builder for words of the given length, with all letters unknown.
+    pub fn new(word_length: u8) -> WordRestrictionsBuilder {
+        WordRestrictionsBuilder {
+            restrictions: WordRestrictions::new(word_length),
+            is_valid: true,
+        }
+    }
+
+    /// Requires the given letter to be at the given location.
+    pub fn with_correct(self, letter: char, location: usize) -> Self {
+        self.apply(|restrictions| restrictions.add_correct(letter, location))
+    }
+
+    /// Requires the given letter to be in the word, but not at the given location.
+    pub fn with_present_not_here(self, letter: char, location: usize) -> Self {
+        self.apply(|restrictions| restrictions.add_present_not_here(letter, location))
+    }
+
+    /// Requires the given letter to not be in the word at all.
+    pub fn with_absent(self, letter: char) -> Self {
+        self.apply(|restrictions| restrictions.add_absent(letter))
+    }
+
+    /// Returns the restrictions, or a [`WordleError::InvalidResults`] error if any of the
+    /// constraints were contradictory or out of bounds.
+    pub fn build(self) -> Result<WordRestrictions, WordleError> {
+        if !self.is_valid {
+            return Err(WordleError::InvalidResults);
+        }
+        Ok(self.restrictions)
+    }
+
+    fn apply<F>(mut self, add_restriction: F) -> Self
+    where
+        F: FnOnce(&mut WordRestrictions) -> Result<(), WordleError>,
+    {
+        if self.is_valid && add_restriction(&mut self.restrictions).is_err() {
+            self.is_valid = false;
+        }
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::results::get_result_for_guess;
+    use crate::results::get_result_for_guess_with;
 
     #[test]
-    fn present_letter_constructor() -> Result<(), WordleError> {
+    fn present_letter_constructor() -> Result<(), Contradiction> {
         let letter = PresentLetter::new(3);
 
         assert_eq!(letter.state(0), LocatedLetterState::Unknown);
@@ -498,7 +1362,7 @@ mod tests {
     }
 
     #[test]
-    fn present_letter_set_here() -> Result<(), WordleError> {
+    fn present_letter_set_here() -> Result<(), Contradiction> {
         let mut letter = PresentLetter::new(3);
 
         letter.set_must_be_at(1)?;
@@ -510,7 +1374,7 @@ mod tests {
     }
 
     #[test]
-    fn present_letter_set_here_can_be_repeated() -> Result<(), WordleError> {
+    fn present_letter_set_here_can_be_repeated() -> Result<(), Contradiction> {
         let mut letter = PresentLetter::new(3);
 
         letter.set_must_be_at(1)?;
@@ -525,7 +1389,7 @@ mod tests {
     }
 
     #[test]
-    fn present_letter_set_not_here() -> Result<(), WordleError> {
+    fn present_letter_set_not_here() -> Result<(), Contradiction> {
         let mut letter = PresentLetter::new(3);
 
         letter.set_must_not_be_at(1)?;
@@ -537,7 +1401,7 @@ mod tests {
     }
 
     #[test]
-    fn present_letter_set_not_here_can_be_repeated() -> Result<(), WordleError> {
+    fn present_letter_set_not_here_can_be_repeated() -> Result<(), Contradiction> {
         let mut letter = PresentLetter::new(3);
 
         letter.set_must_not_be_at(1)?;
@@ -552,7 +1416,7 @@ mod tests {
     }
 
     #[test]
-    fn present_letter_infer_must_be_here() -> Result<(), WordleError> {
+    fn present_letter_infer_must_be_here() -> Result<(), Contradiction> {
         let mut letter = PresentLetter::new(3);
 
         letter.set_must_not_be_at(1)?;
@@ -565,7 +1429,7 @@ mod tests {
     }
 
     #[test]
-    fn present_letter_must_be_here_whole_word() -> Result<(), WordleError> {
+    fn present_letter_must_be_here_whole_word() -> Result<(), Contradiction> {
         let mut letter = PresentLetter::new(3);
 
         letter.set_must_be_at(0)?;
@@ -579,7 +1443,7 @@ mod tests {
     }
 
     #[test]
-    fn present_letter_max_count_then_here_fills_remainder_not_here() -> Result<(), WordleError> {
+    fn present_letter_max_count_then_here_fills_remainder_not_here() -> Result<(), Contradiction> {
         let mut letter = PresentLetter::new(3);
 
         letter.set_required_count(2)?;
@@ -604,7 +1468,7 @@ mod tests {
     }
 
     #[test]
-    fn present_letter_here_then_max_count_fills_remainder_not_here() -> Result<(), WordleError> {
+    fn present_letter_here_then_max_count_fills_remainder_not_here() -> Result<(), Contradiction> {
         let mut letter = PresentLetter::new(3);
 
         letter.set_must_be_at(1)?;
@@ -617,7 +1481,7 @@ mod tests {
     }
 
     #[test]
-    fn present_letter_max_count_then_not_here_fills_remainder_here() -> Result<(), WordleError> {
+    fn present_letter_max_count_then_not_here_fills_remainder_here() -> Result<(), Contradiction> {
         let mut letter = PresentLetter::new(3);
 
         letter.set_must_be_at(1)?;
@@ -631,51 +1495,127 @@ mod tests {
     }
 
     #[test]
-    fn present_letter_max_count_less_than_here_errors() -> Result<(), WordleError> {
-        let mut letter = PresentLetter::new(3);
+    fn present_letter_fill_not_here_counts_locations() -> Result<(), Contradiction> {
+        let mut letter = PresentLetter::new(5);
 
+        letter.set_required_count(1)?;
         letter.set_must_be_at(0)?;
-        letter.set_must_be_at(1)?;
-        assert!(matches!(
-            letter.set_required_count(1),
-            Err(WordleError::InvalidResults)
-        ));
+
+        assert_eq!(letter.num_here, 1);
+        assert_eq!(letter.num_not_here, 4);
+        assert_eq!(letter.num_here_or_unknown(), 1);
+
+        // The same knowledge, found in a different order.
+        let mut other_letter = PresentLetter::new(5);
+        for index in 1..5 {
+            other_letter.set_must_not_be_at(index)?;
+        }
+        other_letter.set_required_count(1)?;
+
+        assert_eq!(other_letter, letter);
         Ok(())
     }
 
     #[test]
-    fn present_letter_max_count_more_than_possible_errors() -> Result<(), WordleError> {
-        let mut letter = PresentLetter::new(3);
+    fn present_letter_triple_required_count() -> Result<(), Contradiction> {
+        let mut letter = PresentLetter::new(6);
+
+        letter.set_must_be_at(0)?;
+        letter.set_must_be_at(3)?;
+        letter.set_required_count(3)?;
+        letter.set_must_not_be_at(1)?;
+        letter.set_must_not_be_at(2)?;
+
+        assert_eq!(letter.state(4), LocatedLetterState::Unknown);
+        assert_eq!(letter.state(5), LocatedLetterState::Unknown);
+
+        letter.set_must_be_at(5)?;
+
+        assert_eq!(letter.state(4), LocatedLetterState::NotHere);
+        assert_eq!(letter.num_here, 3);
+        assert_eq!(letter.num_not_here, 3);
+        assert_eq!(letter.min_count(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn present_letter_quadruple_min_count_fills_here() -> Result<(), Contradiction> {
+        let mut letter = PresentLetter::new(6);
+
+        letter.set_must_be_at(1)?;
+        letter.set_must_not_be_at(0)?;
+        letter.possibly_bump_min_count(3)?;
+
+        assert_eq!(letter.maybe_required_count(), None);
+
+        letter.set_must_not_be_at(5)?;
+        letter.possibly_bump_min_count(4)?;
+
+        assert_eq!(letter.maybe_required_count(), Some(4));
+        for index in 1..5 {
+            assert_eq!(letter.state(index), LocatedLetterState::Here);
+        }
+        assert_eq!(letter.num_here, 4);
+        assert_eq!(letter.num_not_here, 2);
+        assert!(matches!(
+            letter.possibly_bump_min_count(5),
+            Err(Contradiction { location: None, .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn present_letter_max_count_less_than_here_errors() -> Result<(), Contradiction> {
+        let mut letter = PresentLetter::new(3);
+
+        letter.set_must_be_at(0)?;
+        letter.set_must_be_at(1)?;
+        assert!(matches!(
+            letter.set_required_count(1),
+            Err(Contradiction { location: None, .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn present_letter_max_count_more_than_possible_errors() -> Result<(), Contradiction> {
+        let mut letter = PresentLetter::new(3);
 
         letter.set_must_not_be_at(0)?;
         letter.set_must_not_be_at(1)?;
         assert!(matches!(
             letter.set_required_count(2),
-            Err(WordleError::InvalidResults)
+            Err(Contradiction { location: None, .. })
         ));
         Ok(())
     }
 
     #[test]
-    fn present_letter_here_after_not_here_errors() -> Result<(), WordleError> {
+    fn present_letter_here_after_not_here_errors() -> Result<(), Contradiction> {
         let mut letter = PresentLetter::new(3);
 
         letter.set_must_not_be_at(0)?;
         assert!(matches!(
             letter.set_must_be_at(0),
-            Err(WordleError::InvalidResults)
+            Err(Contradiction {
+                location: Some(0),
+                ..
+            })
         ));
         Ok(())
     }
 
     #[test]
-    fn present_letter_not_here_after_here_errors() -> Result<(), WordleError> {
+    fn present_letter_not_here_after_here_errors() -> Result<(), Contradiction> {
         let mut letter = PresentLetter::new(3);
 
         letter.set_must_be_at(0)?;
         assert!(matches!(
             letter.set_must_not_be_at(0),
-            Err(WordleError::InvalidResults)
+            Err(Contradiction {
+                location: Some(0),
+                ..
+            })
         ));
         Ok(())
     }
@@ -835,6 +1775,247 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn word_restrictions_triple_letter() -> Result<(), WordleError> {
+        let restrictions = WordRestrictions::from_result(&get_result_for_guess("erree", "eeeee")?);
+
+        assert_eq!(restrictions.letter_count_bounds('e'), (3, Some(3)));
+        assert!(restrictions.is_satisfied_by("erree"));
+        assert!(restrictions.is_satisfied_by("exxee"));
+        assert!(!restrictions.is_satisfied_by("erere"));
+        assert!(!restrictions.is_satisfied_by("erreee"));
+
+        let mut restrictions = WordRestrictions::new(6);
+        restrictions.update(&get_result_for_guess("errree", "eeeeee")?)?;
+        restrictions.update(&get_result_for_guess("errree", "reexer")?)?;
+
+        assert_eq!(restrictions.letter_count_bounds('e'), (3, Some(3)));
+        assert!(restrictions.is_satisfied_by("errree"));
+        assert!(!restrictions.is_satisfied_by("erreee"));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_simple_mode_repeated_letter() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::new(4).with_duplicate_mode(DuplicateMode::Simple);
+        restrictions.update(&get_result_for_guess_with(
+            "abcd",
+            "aaxa",
+            DuplicateMode::Simple,
+        )?)?;
+
+        assert_eq!(restrictions.letter_count_bounds('a'), (1, None));
+        assert!(restrictions.is_satisfied_by("abcd"));
+        assert!(restrictions.is_satisfied_by("abad"));
+        assert!(!restrictions.is_satisfied_by("aacd"));
+        assert!(!restrictions.is_satisfied_by("abxd"));
+
+        // With the standard rules, this result would need three 'a's, which contradicts the
+        // correct 'a'.
+        let mut standard = WordRestrictions::new(4);
+        assert!(matches!(
+            standard.update(&get_result_for_guess_with(
+                "abcd",
+                "aaxa",
+                DuplicateMode::Simple
+            )?),
+            Err(WordleError::ContradictoryResults { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_simple_mode_contradictions() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::new(2).with_duplicate_mode(DuplicateMode::Simple);
+        // A letter that is in the word is never marked not present.
+        assert_eq!(
+            restrictions.update(&GuessResult {
+                guess: "aa",
+                results: vec![LetterResult::Correct, LetterResult::NotPresent],
+            }),
+            Err(WordleError::ContradictoryResults {
+                letter: 'a',
+                location: None,
+                reason: "it is already known to be in the word",
+            })
+        );
+
+        let mut restrictions = WordRestrictions::new(2).with_duplicate_mode(DuplicateMode::Simple);
+        restrictions.update(&get_result_for_guess_with(
+            "ab",
+            "cc",
+            DuplicateMode::Simple,
+        )?)?;
+        assert!(restrictions
+            .update(&get_result_for_guess_with(
+                "cb",
+                "cd",
+                DuplicateMode::Simple
+            )?)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_simple_mode_update_partial() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::new(4).with_duplicate_mode(DuplicateMode::Simple);
+        restrictions.update_partial(&PartialGuessResult {
+            guess: "aaxa",
+            results: vec![
+                PartialLetterResult::Correct,
+                PartialLetterResult::PresentNotHere,
+                PartialLetterResult::Unknown,
+                PartialLetterResult::PresentMaybeHere,
+            ],
+        })?;
+
+        assert_eq!(restrictions.letter_count_bounds('a'), (1, None));
+        assert!(restrictions.is_satisfied_by("abcd"));
+        assert!(restrictions.is_satisfied_by("abxa"));
+        assert!(!restrictions.is_satisfied_by("aacd"));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_quadruple_letter() -> Result<(), WordleError> {
+        let mut restrictions =
+            WordRestrictions::from_result(&get_result_for_guess("eerree", "eeeeee")?);
+
+        assert_eq!(restrictions.letter_count_bounds('e'), (4, Some(4)));
+        assert!(restrictions.is_satisfied_by("eerree"));
+        assert!(restrictions.is_satisfied_by("eexxee"));
+        assert!(!restrictions.is_satisfied_by("eereee"));
+
+        restrictions.update(&get_result_for_guess("eerree", "reeree")?)?;
+
+        assert!(restrictions.is_satisfied_by("eerree"));
+        assert_eq!(restrictions.known_template(), "eerree");
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_merge_is_order_independent() -> Result<(), WordleError> {
+        let first = WordRestrictions::from_result(&get_result_for_guess("exxxx", "eeeee")?);
+        let second = WordRestrictions::from_result(&get_result_for_guess("exxxx", "aeeee")?);
+
+        let mut first_then_second = first.clone();
+        first_then_second.merge(&second)?;
+        let mut second_then_first = second.clone();
+        second_then_first.merge(&first)?;
+
+        assert_eq!(first_then_second, second_then_first);
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_update_partial_present_maybe_here() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::new(3);
+
+        restrictions.update_partial(&PartialGuessResult {
+            guess: "abc",
+            results: vec![
+                PartialLetterResult::PresentMaybeHere,
+                PartialLetterResult::NotPresent,
+                PartialLetterResult::NotPresent,
+            ],
+        })?;
+
+        assert!(restrictions.is_satisfied_by("ade"));
+        assert!(restrictions.is_satisfied_by("dae"));
+        // The exact count of "a" isn't known.
+        assert!(restrictions.is_satisfied_by("aae"));
+        assert!(!restrictions.is_satisfied_by("ebd"));
+        assert!(!restrictions.is_satisfied_by("def"));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_update_partial_unknown_skips_required_count() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::new(3);
+
+        restrictions.update_partial(&PartialGuessResult {
+            guess: "aab",
+            results: vec![
+                PartialLetterResult::Correct,
+                PartialLetterResult::Unknown,
+                PartialLetterResult::NotPresent,
+            ],
+        })?;
+
+        assert!(restrictions.is_satisfied_by("aac"));
+        assert!(restrictions.is_satisfied_by("acc"));
+        assert!(restrictions.is_satisfied_by("aca"));
+        assert!(!restrictions.is_satisfied_by("cac"));
+        assert!(!restrictions.is_satisfied_by("acb"));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_update_partial_unknown_letter() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::new(3);
+
+        restrictions.update_partial(&PartialGuessResult {
+            guess: "aab",
+            results: vec![
+                PartialLetterResult::Unknown,
+                PartialLetterResult::NotPresent,
+                PartialLetterResult::Correct,
+            ],
+        })?;
+
+        // "a" might not be in the word at all, or might be in the first location.
+        assert!(restrictions.is_satisfied_by("ccb"));
+        assert!(restrictions.is_satisfied_by("acb"));
+        assert!(!restrictions.is_satisfied_by("ccc"));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_update_partial_fully_known_matches_update() -> Result<(), WordleError> {
+        let result = get_result_for_guess("abbey", "babes")?;
+        let mut restrictions = WordRestrictions::new(5);
+
+        restrictions.update_partial(&PartialGuessResult::from(result.clone()))?;
+
+        assert_eq!(restrictions, WordRestrictions::from_result(&result));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_update_partial_contradiction() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::from_result(&get_result_for_guess("abc", "axx")?);
+
+        assert_eq!(
+            restrictions.update_partial(&PartialGuessResult {
+                guess: "aay",
+                results: vec![
+                    PartialLetterResult::NotPresent,
+                    PartialLetterResult::Unknown,
+                    PartialLetterResult::Unknown,
+                ],
+            }),
+            Err(WordleError::ContradictoryResults {
+                letter: 'a',
+                location: Some(0),
+                reason: "it is already known to be in this location",
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_update_partial_wrong_length() {
+        let mut restrictions = WordRestrictions::new(3);
+
+        assert_eq!(
+            restrictions.update_partial(&PartialGuessResult {
+                guess: "ab",
+                results: vec![PartialLetterResult::Unknown; 2],
+            }),
+            Err(WordleError::WordLength(3))
+        );
+    }
+
     #[test]
     fn word_restrictions_empty_then_merge() -> Result<(), WordleError> {
         let mut restrictions = WordRestrictions::new(4);
@@ -926,7 +2107,7 @@ mod tests {
 
         assert!(matches!(
             restrictions.merge(&other_restrictions),
-            Err(WordleError::InvalidResults)
+            Err(WordleError::ContradictoryResults { letter: 'c', .. })
         ));
         Ok(())
     }
@@ -956,7 +2137,7 @@ mod tests {
 
         assert!(matches!(
             restrictions.merge(&other_restrictions),
-            Err(WordleError::InvalidResults)
+            Err(WordleError::ContradictoryResults { letter: 'a', .. })
         ));
         Ok(())
     }
@@ -986,11 +2167,258 @@ mod tests {
 
         assert!(matches!(
             restrictions.merge(&other_restrictions),
+            Err(WordleError::ContradictoryResults { letter: 'b', .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_merge_all() -> Result<(), WordleError> {
+        let first = WordRestrictions::from_result(&GuessResult {
+            guess: "abcd",
+            results: vec![
+                LetterResult::Correct,
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+            ],
+        });
+        let second = WordRestrictions::from_result(&GuessResult {
+            guess: "aefg",
+            results: vec![
+                LetterResult::Correct,
+                LetterResult::PresentNotHere,
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+            ],
+        });
+        let third = WordRestrictions::from_result(&GuessResult {
+            guess: "hhex",
+            results: vec![
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+                LetterResult::Correct,
+                LetterResult::NotPresent,
+            ],
+        });
+
+        let merged = WordRestrictions::merge_all([&first, &second, &third])?;
+
+        assert!(merged.is_satisfied_by("aiei"));
+        assert!(merged.is_satisfied_by("ajek"));
+        assert!(!merged.is_satisfied_by("aeii"));
+        assert!(!merged.is_satisfied_by("abei"));
+        assert!(!merged.is_satisfied_by("ahei"));
+        assert!(!merged.is_satisfied_by("aiex"));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_merge_all_conflicting() {
+        let first = WordRestrictions::from_result(&GuessResult {
+            guess: "abcd",
+            results: vec![
+                LetterResult::Correct,
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+            ],
+        });
+        let second = WordRestrictions::from_result(&GuessResult {
+            guess: "bcde",
+            results: vec![
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+            ],
+        });
+        let conflicting = WordRestrictions::from_result(&GuessResult {
+            guess: "bbbb",
+            results: vec![
+                LetterResult::NotPresent,
+                LetterResult::Correct,
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+            ],
+        });
+
+        assert!(matches!(
+            WordRestrictions::merge_all([&first, &second, &conflicting]),
+            Err(WordleError::ContradictoryResults { letter: 'b', .. })
+        ));
+        assert!(matches!(
+            WordRestrictions::merge_all([]),
             Err(WordleError::InvalidResults)
         ));
+    }
+
+    #[test]
+    fn word_restrictions_multi_byte_letters() -> Result<(), WordleError> {
+        let restrictions = WordRestrictions::from_result(&GuessResult {
+            guess: "ñoña",
+            results: vec![
+                LetterResult::NotPresent,
+                LetterResult::PresentNotHere,
+                LetterResult::Correct,
+                LetterResult::NotPresent,
+            ],
+        });
+
+        assert_eq!(
+            restrictions.state(&LocatedLetter::new('ñ', 2)),
+            Some(LetterRestriction::Here)
+        );
+        assert_eq!(
+            restrictions.state(&LocatedLetter::new('o', 1)),
+            Some(LetterRestriction::PresentNotHere)
+        );
+        assert!(restrictions.is_satisfied_by("niño"));
+        assert!(!restrictions.is_satisfied_by("niña"));
+        assert!(!restrictions.is_satisfied_by("niñoo"));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_is_hard_mode_guess() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::new(4);
+
+        restrictions.update(&GuessResult {
+            guess: "abbc",
+            results: vec![
+                LetterResult::PresentNotHere,
+                LetterResult::PresentNotHere,
+                LetterResult::Correct,
+                LetterResult::NotPresent,
+            ],
+        })?;
+
+        // Reusing the same guess is allowed in hard mode.
+        assert!(restrictions.is_hard_mode_guess("abbc"));
+        assert!(restrictions.is_hard_mode_guess("bcba"));
+        assert!(restrictions.is_hard_mode_guess("zbba"));
+        assert!(!restrictions.is_satisfied_by("zbba"));
+        // Missing the 'b' in location 2.
+        assert!(!restrictions.is_hard_mode_guess("bacb"));
+        // Not enough 'b's.
+        assert!(!restrictions.is_hard_mode_guess("zzba"));
+        // Missing the 'a'.
+        assert!(!restrictions.is_hard_mode_guess("bbbz"));
+        assert!(!restrictions.is_hard_mode_guess("abbcd"));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_changes_since() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::new(4);
+        let empty = restrictions.clone();
+
+        restrictions.update(&GuessResult {
+            guess: "abbc",
+            results: vec![
+                LetterResult::PresentNotHere,
+                LetterResult::PresentNotHere,
+                LetterResult::Correct,
+                LetterResult::NotPresent,
+            ],
+        })?;
+        assert_eq!(
+            restrictions.changes_since(&empty),
+            UpdateSummary {
+                newly_present: vec!['a', 'b'],
+                newly_placed: vec![LocatedLetter::new('b', 2)],
+                newly_absent: vec!['c'],
+            }
+        );
+        assert!(restrictions.changes_since(&restrictions).is_empty());
+
+        let previous = restrictions.clone();
+        restrictions.update(&GuessResult {
+            guess: "dabe",
+            results: vec![
+                LetterResult::NotPresent,
+                LetterResult::Correct,
+                LetterResult::Correct,
+                LetterResult::NotPresent,
+            ],
+        })?;
+
+        // "a" moves from yellow to green, and "b" was already known to be here.
+        assert_eq!(
+            restrictions.changes_since(&previous),
+            UpdateSummary {
+                newly_present: vec![],
+                newly_placed: vec![LocatedLetter::new('a', 1)],
+                newly_absent: vec!['d', 'e'],
+            }
+        );
         Ok(())
     }
 
+    #[test]
+    fn word_restrictions_keyboard_state() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::new(4);
+
+        restrictions.update(&GuessResult {
+            guess: "abbc",
+            results: vec![
+                LetterResult::PresentNotHere,
+                LetterResult::PresentNotHere,
+                LetterResult::Correct,
+                LetterResult::NotPresent,
+            ],
+        })?;
+        restrictions.update(&GuessResult {
+            guess: "dcae",
+            results: vec![
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+                LetterResult::PresentNotHere,
+                LetterResult::NotPresent,
+            ],
+        })?;
+
+        let state = restrictions.keyboard_state();
+
+        assert_eq!(
+            state,
+            KeyboardState {
+                green: vec!['b'],
+                yellow: vec!['a'],
+                grey: vec!['c', 'd', 'e'],
+            }
+        );
+        for letter in &state.green {
+            assert!(!state.yellow.contains(letter));
+            assert!(!state.grey.contains(letter));
+        }
+        for letter in &state.yellow {
+            assert!(!state.grey.contains(letter));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_update_wrong_length_fails() {
+        let mut restrictions = WordRestrictions::new(4);
+
+        assert!(matches!(
+            restrictions.update(&GuessResult {
+                guess: "abcd",
+                results: vec![LetterResult::NotPresent; 3],
+            }),
+            Err(WordleError::WordLength(4))
+        ));
+        assert!(matches!(
+            restrictions.update(&GuessResult {
+                guess: "abc",
+                results: vec![LetterResult::NotPresent; 3],
+            }),
+            Err(WordleError::WordLength(4))
+        ));
+        assert_eq!(restrictions, WordRestrictions::new(4));
+    }
+
     #[test]
     fn word_restrictions_update_change_num_required_fails() -> Result<(), WordleError> {
         let mut restrictions = WordRestrictions::new(4);
@@ -1014,7 +2442,7 @@ mod tests {
                     LetterResult::Correct,
                 ]
             }),
-            Err(WordleError::InvalidResults)
+            Err(WordleError::ContradictoryResults { letter: 'a', .. })
         ));
         assert!(matches!(
             restrictions.clone().update(&GuessResult {
@@ -1026,8 +2454,179 @@ mod tests {
                     LetterResult::NotPresent,
                 ]
             }),
+            Err(WordleError::ContradictoryResults { letter: 'a', .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_builder_matches_from_result() -> Result<(), WordleError> {
+        let from_result = WordRestrictions::from_result(&GuessResult {
+            guess: "abcd",
+            results: vec![
+                LetterResult::Correct,
+                LetterResult::PresentNotHere,
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+            ],
+        });
+
+        let built = WordRestrictionsBuilder::new(4)
+            .with_correct('a', 0)
+            .with_present_not_here('b', 1)
+            .with_absent('c')
+            .with_absent('d')
+            .build()?;
+
+        for word in ["abbb", "axxb", "abxx", "xbax", "axbc", "axxx", "axxbb"] {
+            assert_eq!(
+                built.is_satisfied_by(word),
+                from_result.is_satisfied_by(word),
+                "{}",
+                word
+            );
+        }
+        assert!(built.is_satisfied_by("axxb"));
+        assert!(!built.is_satisfied_by("abxx"));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_builder_repeated_letter() -> Result<(), WordleError> {
+        let restrictions = WordRestrictionsBuilder::new(4)
+            .with_correct('a', 0)
+            .with_correct('a', 3)
+            .build()?;
+
+        assert!(restrictions.is_satisfied_by("abca"));
+        assert!(restrictions.is_satisfied_by("aaaa"));
+        assert!(!restrictions.is_satisfied_by("abcd"));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_builder_contradictions() {
+        assert!(matches!(
+            WordRestrictionsBuilder::new(3)
+                .with_absent('a')
+                .with_correct('a', 0)
+                .build(),
+            Err(WordleError::InvalidResults)
+        ));
+        assert!(matches!(
+            WordRestrictionsBuilder::new(3)
+                .with_present_not_here('a', 1)
+                .with_absent('a')
+                .build(),
+            Err(WordleError::InvalidResults)
+        ));
+        assert!(matches!(
+            WordRestrictionsBuilder::new(3)
+                .with_correct('a', 0)
+                .with_correct('b', 0)
+                .build(),
+            Err(WordleError::InvalidResults)
+        ));
+        assert!(matches!(
+            WordRestrictionsBuilder::new(3)
+                .with_correct('a', 1)
+                .with_present_not_here('a', 1)
+                .build(),
+            Err(WordleError::InvalidResults)
+        ));
+        // 'c' must go in the first two locations, but both are taken.
+        assert!(matches!(
+            WordRestrictionsBuilder::new(3)
+                .with_correct('a', 0)
+                .with_correct('b', 1)
+                .with_present_not_here('c', 2)
+                .build(),
+            Err(WordleError::InvalidResults)
+        ));
+        assert!(matches!(
+            WordRestrictionsBuilder::new(3).with_correct('a', 3).build(),
             Err(WordleError::InvalidResults)
         ));
+    }
+
+    #[test]
+    fn word_restrictions_letter_count_bounds() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::new(5);
+        assert_eq!(restrictions.letter_count_bounds('a'), (0, None));
+
+        restrictions.update(&get_result_for_guess("llama", "allay")?)?;
+        assert_eq!(restrictions.letter_count_bounds('a'), (2, Some(2)));
+        assert_eq!(restrictions.letter_count_bounds('l'), (2, None));
+        assert_eq!(restrictions.letter_count_bounds('y'), (0, Some(0)));
+        assert_eq!(restrictions.letter_count_bounds('m'), (0, None));
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_absent_letters() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::new(5);
+        assert_eq!(restrictions.absent_letters().count(), 0);
+
+        restrictions.update(&get_result_for_guess("water", "yucky")?)?;
+        restrictions.update(&get_result_for_guess("water", "babes")?)?;
+        assert_eq!(
+            restrictions.absent_letters().collect::<Vec<char>>(),
+            vec!['b', 'c', 'k', 's', 'u', 'y']
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_known_template() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::new(5);
+        assert_eq!(restrictions.known_template(), "_____");
+
+        restrictions.update(&get_result_for_guess("water", "party")?)?;
+        assert_eq!(restrictions.known_template(), "_a___");
+
+        restrictions.update(&get_result_for_guess("water", "wader")?)?;
+        // The 't' is present, and can only be in the remaining location.
+        assert_eq!(restrictions.known_template(), "water");
+        Ok(())
+    }
+
+    #[test]
+    fn word_restrictions_display_empty() {
+        assert_eq!(
+            WordRestrictions::new(5).to_string(),
+            "greens: {}, yellows: {}, absent: {}, counts: {}"
+        );
+    }
+
+    #[test]
+    fn word_restrictions_display_counts() -> Result<(), WordleError> {
+        let mut restrictions = WordRestrictions::new(5);
+        restrictions.update(&GuessResult {
+            guess: "aabbc",
+            results: vec![
+                LetterResult::PresentNotHere,
+                LetterResult::Correct,
+                LetterResult::PresentNotHere,
+                LetterResult::PresentNotHere,
+                LetterResult::NotPresent,
+            ],
+        })?;
+        restrictions.update(&GuessResult {
+            guess: "daeef",
+            results: vec![
+                LetterResult::NotPresent,
+                LetterResult::Correct,
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+            ],
+        })?;
+
+        assert_eq!(
+            restrictions.to_string(),
+            "greens: {0:'b', 1:'a', 4:'b'}, yellows: {'a' not at [0]}, \
+             absent: {'c', 'd', 'e', 'f'}, counts: {'a'>=2, 'b'=2}"
+        );
         Ok(())
     }
 }
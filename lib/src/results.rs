@@ -1,15 +1,22 @@
+use crate::data::WordBank;
+use crate::restrictions::WordRestrictions;
+use std::borrow::Borrow;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
+use std::sync::Arc;
 
-/// A compressed form of [LetterResult]s. Can only store vectors of up to 10 results.
+/// A compressed form of [LetterResult]s. Can only store vectors of up to
+/// [`MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT`] results.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub struct CompressedGuessResult {
-    data: u32,
+    data: u128,
 }
 
 const NUM_BITS_PER_LETTER_RESULT: usize = 2;
 pub const MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT: usize =
-    std::mem::size_of::<u32>() * 8 / NUM_BITS_PER_LETTER_RESULT;
+    std::mem::size_of::<u128>() * 8 / NUM_BITS_PER_LETTER_RESULT;
 
 impl CompressedGuessResult {
     /// Creates a compressed form of the given letter results.
@@ -27,16 +34,221 @@ impl CompressedGuessResult {
         let mut data = 0;
         let mut index = 0;
         for letter in letter_results {
-            data |= (*letter as u32) << index;
+            data |= (*letter as u128) << index;
             index += NUM_BITS_PER_LETTER_RESULT;
         }
         Ok(Self { data })
     }
+
+    /// Expands this back into the letter results it was created from.
+    ///
+    /// ```
+    /// use rs_wordle_solver::LetterResult;
+    /// use rs_wordle_solver::details::CompressedGuessResult;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let results = vec![
+    ///     LetterResult::Correct,
+    ///     LetterResult::NotPresent,
+    ///     LetterResult::PresentNotHere,
+    /// ];
+    ///
+    /// assert_eq!(CompressedGuessResult::from_results(&results)?.to_results(), results);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn to_results(&self) -> Vec<LetterResult> {
+        // Every letter result has a non-zero value, so the results end at the first zero.
+        (0..MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT)
+            .map(|index| (self.data >> (index * NUM_BITS_PER_LETTER_RESULT)) & 0b11)
+            .map_while(|bits| match bits {
+                0b01 => Some(LetterResult::Correct),
+                0b10 => Some(LetterResult::PresentNotHere),
+                0b11 => Some(LetterResult::NotPresent),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the raw bits of this compressed result, with two bits per letter result.
+    pub(crate) fn to_bits(self) -> u128 {
+        self.data
+    }
+
+    /// Recreates a compressed result from the raw bits for `num_letters` letter results.
+    ///
+    /// Returns `None` if the bits don't encode a valid set of letter results.
+    pub(crate) fn from_bits(data: u128, num_letters: usize) -> Option<CompressedGuessResult> {
+        if num_letters > MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT {
+            return None;
+        }
+        let num_bits = num_letters * NUM_BITS_PER_LETTER_RESULT;
+        if num_bits < u128::BITS as usize && data >> num_bits != 0 {
+            return None;
+        }
+        let all_valid = (0..num_letters)
+            .all(|index| (data >> (index * NUM_BITS_PER_LETTER_RESULT)) & 0b11 != 0);
+        if all_valid {
+            Some(Self { data })
+        } else {
+            None
+        }
+    }
+}
+
+/// Counts how many of the possible words would produce each distinct result for the given guess.
+///
+/// Returns a [`WordleError::WordLength`] error if any possible word differs in length from the
+/// guess, or is too long to be compressed.
+pub(crate) fn count_words_by_result<W, I, T>(
+    guess: W,
+    possible_words: I,
+) -> Result<HashMap<CompressedGuessResult, usize>, WordleError>
+where
+    W: AsRef<str>,
+    I: IntoIterator<Item = T>,
+    T: AsRef<str>,
+{
+    let mut matching_results: HashMap<CompressedGuessResult, usize> = HashMap::new();
+    for possible_word in possible_words {
+        let guess_result = CompressedGuessResult::from_results(
+            &get_result_for_guess(possible_word.as_ref(), guess.as_ref())?.results,
+        )?;
+        *matching_results.entry(guess_result).or_insert(0) += 1;
+    }
+    Ok(matching_results)
+}
+
+/// Returns the number of distinct results that the guess would produce across the possible words.
+///
+/// The more results a guess can produce, the better it separates the possible words. This is a
+/// cheaper measure than the expected number of eliminations or the entropy of the results.
+///
+/// Returns a [`WordleError::WordLength`] error if any possible word differs in length from the
+/// guess, or is too long for its results to be compressed.
+///
+/// ```
+/// use rs_wordle_solver::details::count_result_patterns;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let possible_words = ["cod", "wod", "mod", "cat"];
+///
+/// assert_eq!(count_result_patterns("cod", &possible_words)?, 3);
+/// assert_eq!(count_result_patterns("mwt", &possible_words)?, 4);
+/// assert_eq!(count_result_patterns("zzz", &possible_words)?, 1);
+/// assert!(count_result_patterns("code", &possible_words).is_err());
+/// # Ok::<(), WordleError>(())
+/// ```
+pub fn count_result_patterns<S>(guess: &str, possible_words: &[S]) -> Result<usize, WordleError>
+where
+    S: AsRef<str>,
+{
+    Ok(count_words_by_result(guess, possible_words)?.len())
+}
+
+/// Returns the guesses after which fewer than `threshold` of the `possible_words` are guaranteed
+/// to remain, no matter which possible word is the objective.
+///
+/// Guesses are returned in the same order as in `all_guesses`.
+///
+/// Returns a [`WordleError::WordLength`] error if any guess differs in length from the possible
+/// words, or if the words are too long to be compressed.
+///
+/// ```
+/// use std::sync::Arc;
+/// use rs_wordle_solver::details::guesses_reducing_below;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let possible_words = ["cod", "wod", "mod"];
+/// let all_guesses = [Arc::from("cod"), Arc::from("mwc"), Arc::from("zzz")];
+///
+/// assert_eq!(
+///     guesses_reducing_below(&possible_words, &all_guesses, 2)?,
+///     vec![Arc::from("mwc")]
+/// );
+/// # Ok::<(), WordleError>(())
+/// ```
+pub fn guesses_reducing_below<S>(
+    possible_words: &[S],
+    all_guesses: &[Arc<str>],
+    threshold: usize,
+) -> Result<Vec<Arc<str>>, WordleError>
+where
+    S: AsRef<str>,
+{
+    let mut guesses = Vec::new();
+    for guess in all_guesses {
+        let max_bucket_size = count_words_by_result(guess, possible_words)?
+            .into_values()
+            .max()
+            .unwrap_or(0);
+        if max_bucket_size < threshold {
+            guesses.push(Arc::clone(guess));
+        }
+    }
+    Ok(guesses)
+}
+
+/// Returns pairs of close words that can't be told apart by guessing any of the first
+/// `num_probe_guesses` words in the bank, other than guessing one of the pair itself.
+///
+/// Two words are close if they differ in exactly one location. If such a pair are the final two
+/// candidates, the only way to find the answer is to guess one of them and hope. Pairs are
+/// returned in the same order as in the bank.
+///
+/// ```
+/// use std::sync::Arc;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::details::ambiguous_word_pairs;
+///
+/// let bank = WordBank::from_iterator(&["cod", "mod", "xyz"]).unwrap();
+///
+/// assert_eq!(
+///     ambiguous_word_pairs(&bank, 3),
+///     vec![(Arc::from("cod"), Arc::from("mod"))]
+/// );
+/// ```
+pub fn ambiguous_word_pairs(
+    bank: &WordBank,
+    num_probe_guesses: usize,
+) -> Vec<(Arc<str>, Arc<str>)> {
+    let probe_guesses = &bank[..num_probe_guesses.min(bank.len())];
+    let mut pairs = Vec::new();
+    for (index, first) in bank.iter().enumerate() {
+        for second in bank[index + 1..].iter() {
+            let num_differences = first
+                .chars()
+                .zip(second.chars())
+                .filter(|(first_letter, second_letter)| first_letter != second_letter)
+                .count();
+            if num_differences != 1 {
+                continue;
+            }
+            let is_distinguishable = probe_guesses
+                .iter()
+                .filter(|guess| *guess != first && *guess != second)
+                .any(|guess| would_distinguish(guess, first, second));
+            if !is_distinguishable {
+                pairs.push((Arc::clone(first), Arc::clone(second)));
+            }
+        }
+    }
+    pairs
+}
+
+/// Returns true iff the guess would produce different results for the two possible objectives.
+fn would_distinguish(guess: &str, first_objective: &str, second_objective: &str) -> bool {
+    get_result_for_guess(first_objective, guess)
+        .unwrap()
+        .results
+        != get_result_for_guess(second_objective, guess)
+            .unwrap()
+            .results
 }
 
 /// The result of a given letter at a specific location. There is some complexity here when a
 /// letter appears in a word more than once. See [`GuessResult`] for more details.
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LetterResult {
     /// This letter goes exactly here in the objective word.
     Correct = 0b01,
@@ -47,23 +259,198 @@ pub enum LetterResult {
     NotPresent = 0b11,
 }
 
+impl LetterResult {
+    /// Parses a letter result from the character used for it in result strings: '.' for
+    /// [`LetterResult::NotPresent`], 'y' for [`LetterResult::PresentNotHere`], and 'g' for
+    /// [`LetterResult::Correct`]. 'y' and 'g' are case-insensitive.
+    ///
+    /// Returns [`WordleError::InvalidResults`] for any other character.
+    ///
+    /// ```
+    /// use rs_wordle_solver::LetterResult;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// assert_eq!(LetterResult::from_char('G')?, LetterResult::Correct);
+    /// assert!(LetterResult::from_char('x').is_err());
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn from_char(c: char) -> Result<LetterResult, WordleError> {
+        match c {
+            '.' => Ok(LetterResult::NotPresent),
+            'y' | 'Y' => Ok(LetterResult::PresentNotHere),
+            'g' | 'G' => Ok(LetterResult::Correct),
+            _ => Err(WordleError::InvalidResults),
+        }
+    }
+
+    /// Parses a single row of a shared result, such as the output of
+    /// [`GameData::to_share_grid()`].
+    ///
+    /// 🟩 and 🟧 are parsed as [`LetterResult::Correct`], 🟨 and 🟦 as
+    /// [`LetterResult::PresentNotHere`], and ⬛ and ⬜ as [`LetterResult::NotPresent`]. Emoji
+    /// variation selectors and surrounding whitespace are ignored.
+    ///
+    /// Returns [`WordleError::InvalidResults`] if the row contains any other characters.
+    ///
+    /// ```
+    /// use rs_wordle_solver::LetterResult;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// assert_eq!(
+    ///     LetterResult::parse_emoji_row("🟩🟨⬛")?,
+    ///     vec![LetterResult::Correct, LetterResult::PresentNotHere, LetterResult::NotPresent]
+    /// );
+    /// assert!(LetterResult::parse_emoji_row("🟩x⬛").is_err());
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn parse_emoji_row(row: &str) -> Result<Vec<LetterResult>, WordleError> {
+        row.trim()
+            .chars()
+            .filter(|c| *c != '\u{FE0F}')
+            .map(|c| match c {
+                '🟩' | '🟧' => Ok(LetterResult::Correct),
+                '🟨' | '🟦' => Ok(LetterResult::PresentNotHere),
+                '⬛' | '⬜' => Ok(LetterResult::NotPresent),
+                _ => Err(WordleError::InvalidResults),
+            })
+            .collect()
+    }
+
+    /// Parses a shared result grid, with one row per line. Blank lines are skipped.
+    ///
+    /// Returns [`WordleError::InvalidResults`] if any row can't be parsed by
+    /// [`Self::parse_emoji_row()`].
+    ///
+    /// ```
+    /// use rs_wordle_solver::LetterResult;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let grid = LetterResult::parse_emoji_grid("🟨🟨⬛\n🟩🟩🟩\n")?;
+    ///
+    /// assert_eq!(grid.len(), 2);
+    /// assert_eq!(grid[1], vec![LetterResult::Correct; 3]);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn parse_emoji_grid(grid: &str) -> Result<Vec<Vec<LetterResult>>, WordleError> {
+        grid.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(LetterResult::parse_emoji_row)
+            .collect()
+    }
+}
+
 /// Indicates that an error occurred while trying to guess the objective word.
 #[derive(Debug)]
 pub enum WordleError {
     /// Indicates that the word lengths differed, or words were too long for the chosen
     /// implementation. The expected word length or max possible word length is provided.
     WordLength(usize),
+    /// Indicates that the word at the given index in a list of words had a different length to
+    /// the words before it.
+    WordLengthAt {
+        /// The length of the words before this one.
+        expected: usize,
+        /// The length of this word.
+        got: usize,
+        /// The index of this word in the list.
+        index: usize,
+    },
     /// Indicates that the given `GuessResult`s are impossible due to some inconsistency.
     InvalidResults,
+    /// Indicates that the given `GuessResult`s contradict what is already known about a letter.
+    ContradictoryResults {
+        /// The letter whose results are contradictory.
+        letter: char,
+        /// The location of the contradictory result in the word, if the contradiction is tied to
+        /// one location.
+        location: Option<usize>,
+        /// Why the result can't be true.
+        reason: &'static str,
+    },
     /// An IO error occurred.
     IoError(std::io::Error),
 }
 
+/// Compares errors by variant and contents. [`WordleError::IoError`]s are compared by their
+/// [`std::io::ErrorKind`] only, since [`std::io::Error`] can't be compared directly.
+///
+/// ```
+/// use std::io;
+/// use rs_wordle_solver::WordleError;
+///
+/// assert_eq!(WordleError::WordLength(5), WordleError::WordLength(5));
+/// assert_ne!(WordleError::WordLength(5), WordleError::InvalidResults);
+/// assert_eq!(
+///     WordleError::IoError(io::Error::new(io::ErrorKind::InvalidData, "bad word")),
+///     WordleError::IoError(io::Error::from(io::ErrorKind::InvalidData))
+/// );
+/// ```
+impl PartialEq for WordleError {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (WordleError::WordLength(length), WordleError::WordLength(other_length)) => {
+                length == other_length
+            }
+            (
+                WordleError::WordLengthAt {
+                    expected,
+                    got,
+                    index,
+                },
+                WordleError::WordLengthAt {
+                    expected: other_expected,
+                    got: other_got,
+                    index: other_index,
+                },
+            ) => expected == other_expected && got == other_got && index == other_index,
+            (WordleError::InvalidResults, WordleError::InvalidResults) => true,
+            (
+                WordleError::ContradictoryResults {
+                    letter,
+                    location,
+                    reason,
+                },
+                WordleError::ContradictoryResults {
+                    letter: other_letter,
+                    location: other_location,
+                    reason: other_reason,
+                },
+            ) => letter == other_letter && location == other_location && reason == other_reason,
+            (WordleError::IoError(io_err), WordleError::IoError(other_io_err)) => {
+                io_err.kind() == other_io_err.kind()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for WordleError {}
+
 impl fmt::Display for WordleError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             WordleError::WordLength(expected_length) => write!(f, "{:?}: all words and guesses in a Wordle game must have the same length, and must be less than or equal to the max word length: {}", self, expected_length),
+            WordleError::WordLengthAt {
+                expected,
+                got,
+                index,
+            } => write!(
+                f,
+                "WordLengthAt: the word at index {} has length {}, but the previous words have length {}",
+                index, got, expected
+            ),
             WordleError::InvalidResults => write!(f, "{:?}: provided GuessResults led to an impossible set of WordRestrictions", self),
+            WordleError::ContradictoryResults {
+                letter,
+                location,
+                reason,
+            } => {
+                write!(f, "ContradictoryResults: the result for '{}'", letter)?;
+                if let Some(location) = location {
+                    write!(f, " at index {}", location)?;
+                }
+                write!(f, " is impossible, because {}", reason)
+            }
             WordleError::IoError(io_err) => write!(f, "{:?}: {}", self, io_err),
         }
     }
@@ -105,24 +492,324 @@ pub struct GuessResult<'a> {
     pub results: Vec<LetterResult>,
 }
 
+/// An owned version of [`GuessResult`], for storing or serializing results.
+///
+/// ```
+/// use rs_wordle_solver::GuessResultBuf;
+/// use rs_wordle_solver::get_result_for_guess;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let result = get_result_for_guess("mesas", "sassy")?;
+/// let owned = GuessResultBuf::from(result.clone());
+///
+/// assert_eq!(owned.as_guess_result(), result);
+/// # Ok::<(), WordleError>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GuessResultBuf {
+    /// The guess that was made.
+    pub guess: Box<str>,
+    /// The result of each letter, provided in the same leter order as in the guess.
+    pub results: Vec<LetterResult>,
+}
+
+impl GuessResultBuf {
+    /// Borrows this as a [`GuessResult`].
+    pub fn as_guess_result(&self) -> GuessResult<'_> {
+        GuessResult {
+            guess: &self.guess,
+            results: self.results.clone(),
+        }
+    }
+}
+
+impl From<GuessResult<'_>> for GuessResultBuf {
+    fn from(result: GuessResult<'_>) -> Self {
+        GuessResultBuf {
+            guess: Box::from(result.guess),
+            results: result.results,
+        }
+    }
+}
+
+/// The result of a given letter at a specific location, when the result may not be fully known.
+/// See [`PartialGuessResult`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PartialLetterResult {
+    /// This letter goes exactly here in the objective word.
+    Correct,
+    /// This letter is in the objective word, but not here.
+    PresentNotHere,
+    /// This letter is in the objective word, and may or may not be here, e.g. if it's not clear
+    /// whether the result was [`LetterResult::Correct`] or [`LetterResult::PresentNotHere`].
+    PresentMaybeHere,
+    /// This letter is not in the objective word, or is only in the word as many times as it was
+    /// marked present.
+    NotPresent,
+    /// Nothing is known about this letter's result.
+    Unknown,
+}
+
+impl From<LetterResult> for PartialLetterResult {
+    fn from(result: LetterResult) -> Self {
+        match result {
+            LetterResult::Correct => PartialLetterResult::Correct,
+            LetterResult::PresentNotHere => PartialLetterResult::PresentNotHere,
+            LetterResult::NotPresent => PartialLetterResult::NotPresent,
+        }
+    }
+}
+
+/// The result of a single word guess, where some letters' results may not be fully known.
+///
+/// See [`WordRestrictions::update_partial()`](crate::details::WordRestrictions::update_partial).
+#[derive(Clone, Debug, PartialEq, Hash)]
+pub struct PartialGuessResult<'a> {
+    /// The guess that was made.
+    pub guess: &'a str,
+    /// The result of each letter, provided in the same letter order as in the guess.
+    pub results: Vec<PartialLetterResult>,
+}
+
+impl<'a> PartialGuessResult<'a> {
+    /// Returns the equivalent [`GuessResult`] if every letter's result is fully known, else
+    /// `None`.
+    ///
+    /// ```
+    /// use rs_wordle_solver::PartialGuessResult;
+    /// use rs_wordle_solver::PartialLetterResult;
+    ///
+    /// let mut result = PartialGuessResult {
+    ///     guess: "ab",
+    ///     results: vec![PartialLetterResult::Correct, PartialLetterResult::NotPresent],
+    /// };
+    /// assert!(result.to_guess_result().is_some());
+    ///
+    /// result.results[1] = PartialLetterResult::Unknown;
+    /// assert!(result.to_guess_result().is_none());
+    /// ```
+    pub fn to_guess_result(&self) -> Option<GuessResult<'a>> {
+        let results = self
+            .results
+            .iter()
+            .map(|result| match result {
+                PartialLetterResult::Correct => Some(LetterResult::Correct),
+                PartialLetterResult::PresentNotHere => Some(LetterResult::PresentNotHere),
+                PartialLetterResult::NotPresent => Some(LetterResult::NotPresent),
+                PartialLetterResult::PresentMaybeHere | PartialLetterResult::Unknown => None,
+            })
+            .collect::<Option<Vec<LetterResult>>>()?;
+        Some(GuessResult {
+            guess: self.guess,
+            results,
+        })
+    }
+}
+
+impl<'a> From<GuessResult<'a>> for PartialGuessResult<'a> {
+    fn from(result: GuessResult<'a>) -> Self {
+        PartialGuessResult {
+            guess: result.guess,
+            results: result
+                .results
+                .into_iter()
+                .map(PartialLetterResult::from)
+                .collect(),
+        }
+    }
+}
+
 /// Data about a single turn of a Wordle game.
 #[derive(Clone, Debug, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TurnData {
     /// The guess that was made this turn.
     pub guess: Box<str>,
     /// The number of possible words that remained at the start of this turn.
     pub num_possible_words_before_guess: usize,
+    /// The restrictions that were known at the start of this turn, if they were recorded.
+    ///
+    /// These are always recorded by [`play_game_with_guesser()`](crate::play_game_with_guesser).
+    pub restrictions: Option<WordRestrictions>,
 }
 
 /// The data from a game that was played.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameData {
     /// Data for each turn that was played.
     pub turns: Vec<TurnData>,
 }
 
+/// The color scheme to use when sharing a game's results. See [`GameData::to_share_grid()`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ShareTheme {
+    /// Green and yellow squares on a light background.
+    #[default]
+    Light,
+    /// Green and yellow squares on a dark background.
+    Dark,
+    /// Orange and blue squares on a light background, for color-blind players.
+    HighContrastLight,
+    /// Orange and blue squares on a dark background, for color-blind players.
+    HighContrastDark,
+}
+
+impl ShareTheme {
+    /// Returns the square used to represent the given result in this theme.
+    fn square(&self, result: LetterResult) -> char {
+        match (self, result) {
+            (ShareTheme::Light | ShareTheme::Dark, LetterResult::Correct) => '🟩',
+            (ShareTheme::Light | ShareTheme::Dark, LetterResult::PresentNotHere) => '🟨',
+            (_, LetterResult::Correct) => '🟧',
+            (_, LetterResult::PresentNotHere) => '🟦',
+            (ShareTheme::Light | ShareTheme::HighContrastLight, LetterResult::NotPresent) => '⬜',
+            (ShareTheme::Dark | ShareTheme::HighContrastDark, LetterResult::NotPresent) => '⬛',
+        }
+    }
+}
+
+impl GameData {
+    /// Renders the results of each turn as a grid of colored squares, one line per turn, in the
+    /// style of Wordle's share text.
+    ///
+    /// Returns a [`WordleError::WordLength`] error if any guess has a different length to the
+    /// objective.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GameData;
+    /// use rs_wordle_solver::ShareTheme;
+    /// use rs_wordle_solver::TurnData;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let data = GameData {
+    ///     turns: vec![
+    ///         TurnData {
+    ///             guess: Box::from("bad"),
+    ///             num_possible_words_before_guess: 3,
+    ///             restrictions: None,
+    ///         },
+    ///         TurnData {
+    ///             guess: Box::from("abc"),
+    ///             num_possible_words_before_guess: 1,
+    ///             restrictions: None,
+    ///         },
+    ///     ],
+    /// };
+    ///
+    /// assert_eq!(data.to_share_grid("abc", ShareTheme::Dark)?, "🟨🟨⬛\n🟩🟩🟩");
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn to_share_grid(&self, objective: &str, theme: ShareTheme) -> Result<String, WordleError> {
+        let lines = self
+            .turns
+            .iter()
+            .map(|turn| {
+                get_result_for_guess(objective, &turn.guess).map(|result| {
+                    result
+                        .results
+                        .iter()
+                        .map(|letter_result| theme.square(*letter_result))
+                        .collect::<String>()
+                })
+            })
+            .collect::<Result<Vec<String>, WordleError>>()?;
+        Ok(lines.join("\n"))
+    }
+
+    /// Returns the number of possible words before each guess, in the order the guesses were
+    /// made.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GameData;
+    /// use rs_wordle_solver::TurnData;
+    ///
+    /// let data = GameData {
+    ///     turns: vec![
+    ///         TurnData {
+    ///             guess: Box::from("bad"),
+    ///             num_possible_words_before_guess: 3,
+    ///             restrictions: None,
+    ///         },
+    ///         TurnData {
+    ///             guess: Box::from("abc"),
+    ///             num_possible_words_before_guess: 1,
+    ///             restrictions: None,
+    ///         },
+    ///     ],
+    /// };
+    ///
+    /// assert_eq!(data.possible_word_trajectory(), vec![3, 1]);
+    /// assert_eq!(data.total_reduction_ratio(), Some(3.0));
+    /// ```
+    pub fn possible_word_trajectory(&self) -> Vec<usize> {
+        self.turns
+            .iter()
+            .map(|turn| turn.num_possible_words_before_guess)
+            .collect()
+    }
+
+    /// Returns how many times smaller the set of possible words was before the final guess than
+    /// before the first guess, or `None` if no guesses were made or there were no possible words
+    /// before the final guess.
+    pub fn total_reduction_ratio(&self) -> Option<f64> {
+        let first = self.turns.first()?.num_possible_words_before_guess;
+        let last = self.turns.last()?.num_possible_words_before_guess;
+        if last == 0 {
+            return None;
+        }
+        Some(first as f64 / last as f64)
+    }
+
+    /// Returns the number of guesses that were made.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GameData;
+    /// use rs_wordle_solver::TurnData;
+    ///
+    /// let data = GameData {
+    ///     turns: vec![
+    ///         TurnData {
+    ///             guess: Box::from("bad"),
+    ///             num_possible_words_before_guess: 3,
+    ///             restrictions: None,
+    ///         },
+    ///         TurnData {
+    ///             guess: Box::from("abc"),
+    ///             num_possible_words_before_guess: 1,
+    ///             restrictions: None,
+    ///         },
+    ///     ],
+    /// };
+    ///
+    /// assert_eq!(data.num_turns(), 2);
+    /// assert_eq!(data.final_guess(), Some("abc"));
+    /// assert_eq!(data.final_num_possible_words(), Some(1));
+    /// ```
+    #[inline]
+    pub fn num_turns(&self) -> usize {
+        self.turns.len()
+    }
+
+    /// Returns the last guess that was made, or `None` if no guesses were made.
+    pub fn final_guess(&self) -> Option<&str> {
+        self.turns.last().map(|turn| turn.guess.as_ref())
+    }
+
+    /// Returns the number of possible words before the last guess was made, or `None` if no
+    /// guesses were made.
+    pub fn final_num_possible_words(&self) -> Option<usize> {
+        self.turns
+            .last()
+            .map(|turn| turn.num_possible_words_before_guess)
+    }
+}
+
 /// Whether the game was won or lost by the guesser.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameResult {
     /// Indicates that the guesser won the game, and provides the guesses that were given.
     Success(GameData),
@@ -131,9 +818,210 @@ pub enum GameResult {
     Failure(GameData),
     /// Indicates that the given word was not in the guesser's word bank.
     UnknownWord,
+    /// Indicates that the guesser ran out of time, and provides the guesses that were given
+    /// before the time ran out. See
+    /// [`play_game_with_guesser_timed()`](crate::play_game_with_guesser_timed).
+    TimedOut(GameData),
 }
 
-/// Determines the result of the given `guess` when applied to the given `objective`.
+impl GameResult {
+    /// Returns true iff the guesser won the game.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GameData;
+    /// use rs_wordle_solver::GameResult;
+    ///
+    /// let result = GameResult::Success(GameData { turns: Vec::new() });
+    ///
+    /// assert!(result.is_success());
+    /// assert!(!result.is_failure());
+    /// assert!(!GameResult::UnknownWord.is_success());
+    /// ```
+    #[inline]
+    pub fn is_success(&self) -> bool {
+        matches!(self, GameResult::Success(_))
+    }
+
+    /// Returns true iff the guesser used up all its guesses without winning the game.
+    #[inline]
+    pub fn is_failure(&self) -> bool {
+        matches!(self, GameResult::Failure(_))
+    }
+
+    /// Returns the guesses that were made, or `None` if the word was unknown.
+    pub fn game_data(&self) -> Option<&GameData> {
+        match self {
+            GameResult::Success(data) | GameResult::Failure(data) | GameResult::TimedOut(data) => {
+                Some(data)
+            }
+            GameResult::UnknownWord => None,
+        }
+    }
+}
+
+/// Summary statistics for the number of guesses needed across many games.
+///
+/// ```
+/// use rs_wordle_solver::GameStats;
+/// use rs_wordle_solver::RandomGuesser;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::play_game_with_guesser;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let bank = WordBank::from_iterator(&["abc", "def", "ghi"])?;
+/// let guesser = RandomGuesser::new(bank.clone());
+/// let results = ["abc", "def", "ghi", "xyz"]
+///     .iter()
+///     .map(|word| play_game_with_guesser(word, 3, guesser.clone()))
+///     .collect::<Result<Vec<_>, WordleError>>()?;
+///
+/// let stats = GameStats::from_results(&results);
+///
+/// assert_eq!(stats.num_games(), 4);
+/// assert_eq!(stats.num_unsolved, 1);
+/// assert_eq!(stats.win_rate(), 0.75);
+/// assert!(stats.worst_case_guesses().unwrap() <= 3);
+/// # Ok::<(), WordleError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameStats {
+    /// The number of solved games, keyed by the number of guesses needed.
+    pub histogram: BTreeMap<u32, u32>,
+    /// The number of games that were not solved.
+    pub num_unsolved: u32,
+}
+
+impl GameStats {
+    /// Computes the statistics for the given game results. Any result other than
+    /// [`GameResult::Success`] counts as an unsolved game.
+    pub fn from_results<I, R>(results: I) -> GameStats
+    where
+        I: IntoIterator<Item = R>,
+        R: Borrow<GameResult>,
+    {
+        let mut stats = GameStats::default();
+        for result in results {
+            stats.add_result(result.borrow());
+        }
+        stats
+    }
+
+    /// Adds a single game result to these statistics.
+    pub fn add_result(&mut self, result: &GameResult) {
+        match result {
+            GameResult::Success(data) => self.add_solved(data.num_turns() as u32),
+            _ => self.num_unsolved += 1,
+        }
+    }
+
+    /// Adds a game that was solved in the given number of guesses.
+    pub fn add_solved(&mut self, num_guesses: u32) {
+        *self.histogram.entry(num_guesses).or_insert(0) += 1;
+    }
+
+    /// The number of solved games.
+    pub fn num_solved(&self) -> u32 {
+        self.histogram.values().sum()
+    }
+
+    /// The total number of games, including unsolved games.
+    pub fn num_games(&self) -> u32 {
+        self.num_solved() + self.num_unsolved
+    }
+
+    /// The mean number of guesses needed to solve each solved game. This is `NaN` if no games
+    /// were solved.
+    pub fn mean_guesses(&self) -> f64 {
+        self.histogram
+            .iter()
+            .map(|(num_guesses, num_games)| num_guesses * num_games)
+            .sum::<u32>() as f64
+            / self.num_solved() as f64
+    }
+
+    /// The standard deviation of the number of guesses needed to solve each solved game. This is
+    /// `NaN` if no games were solved.
+    pub fn std_dev(&self) -> f64 {
+        let mean = self.mean_guesses();
+        (self
+            .histogram
+            .iter()
+            .map(|(num_guesses, num_games)| {
+                (*num_guesses as f64 - mean).powi(2) * *num_games as f64
+            })
+            .sum::<f64>()
+            / self.num_solved() as f64)
+            .sqrt()
+    }
+
+    /// The fraction of games that were solved. This is `NaN` if there are no games.
+    pub fn win_rate(&self) -> f64 {
+        self.num_solved() as f64 / self.num_games() as f64
+    }
+
+    /// The most guesses that were needed to solve any solved game, or `None` if no games were
+    /// solved.
+    pub fn worst_case_guesses(&self) -> Option<u32> {
+        self.histogram.keys().next_back().copied()
+    }
+}
+
+impl fmt::Display for GameStats {
+    /// Writes the number of games solved with each number of guesses as a markdown table,
+    /// followed by the number of unsolved games, if any.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "|Num guesses|Num games|")?;
+        writeln!(f, "|-----------|---------|")?;
+        for (num_guesses, num_games) in &self.histogram {
+            writeln!(f, "|{}|{}|", num_guesses, num_games)?;
+        }
+        if self.num_unsolved > 0 {
+            writeln!(f, "Unsolved games: {}", self.num_unsolved)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a result string, such as "g.yy.", with one character per letter as accepted by
+/// [`LetterResult::from_char()`].
+///
+/// Returns [`WordleError::InvalidResults`] if the string contains any other characters.
+///
+/// ```
+/// use rs_wordle_solver::LetterResult;
+/// use rs_wordle_solver::parse_result_string;
+/// # use rs_wordle_solver::WordleError;
+///
+/// assert_eq!(
+///     parse_result_string("g.Y")?,
+///     vec![LetterResult::Correct, LetterResult::NotPresent, LetterResult::PresentNotHere]
+/// );
+/// assert!(parse_result_string("g.x").is_err());
+/// # Ok::<(), WordleError>(())
+/// ```
+pub fn parse_result_string(results: &str) -> Result<Vec<LetterResult>, WordleError> {
+    results.chars().map(LetterResult::from_char).collect()
+}
+
+/// How letters that appear more than once are marked by [`get_result_for_guess_with()`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DuplicateMode {
+    /// Each letter in the objective can only mark one letter in the guess, as in the original
+    /// Wordle. Correct letters are marked first, and then present letters from left to right. See
+    /// [`GuessResult`] for more details.
+    #[default]
+    Standard,
+    /// Every letter in the guess that is anywhere in the objective is marked as present, no matter
+    /// how many times it appears.
+    Simple,
+}
+
+/// Determines the result of the given `guess` when applied to the given `objective`, using the
+/// [`DuplicateMode::Standard`] rules.
+///
+/// Letters are compared exactly, so this is case-sensitive: `'a'` and `'A'` are different letters.
 ///
 /// ```
 /// use rs_wordle_solver::get_result_for_guess;
@@ -165,14 +1053,66 @@ pub fn get_result_for_guess<'a>(
     objective: &str,
     guess: &'a str,
 ) -> Result<GuessResult<'a>, WordleError> {
-    if objective.len() != guess.len() {
-        return Err(WordleError::WordLength(objective.len()));
+    get_result_for_guess_with(objective, guess, DuplicateMode::Standard)
+}
+
+/// Determines the result of the given `guess` when applied to the given `objective`, marking
+/// repeated letters according to the given [`DuplicateMode`].
+///
+/// [`WordRestrictions`] and the guessers interpret results using the [`DuplicateMode::Standard`]
+/// rules by default. Use [`WordRestrictions::with_duplicate_mode()`], or the guessers'
+/// `with_duplicate_mode` methods, to solve games that use other rules.
+///
+/// ```
+/// use rs_wordle_solver::DuplicateMode;
+/// use rs_wordle_solver::LetterResult;
+/// use rs_wordle_solver::get_result_for_guess_with;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let result = get_result_for_guess_with("abcd", "aaxa", DuplicateMode::Standard)?;
+/// assert_eq!(
+///     result.results,
+///     vec![
+///         LetterResult::Correct,
+///         LetterResult::NotPresent,
+///         LetterResult::NotPresent,
+///         LetterResult::NotPresent
+///     ]
+/// );
+///
+/// let result = get_result_for_guess_with("abcd", "aaxa", DuplicateMode::Simple)?;
+/// assert_eq!(
+///     result.results,
+///     vec![
+///         LetterResult::Correct,
+///         LetterResult::PresentNotHere,
+///         LetterResult::NotPresent,
+///         LetterResult::PresentNotHere
+///     ]
+/// );
+/// # Ok::<(), WordleError>(())
+/// ```
+pub fn get_result_for_guess_with<'a>(
+    objective: &str,
+    guess: &'a str,
+    mode: DuplicateMode,
+) -> Result<GuessResult<'a>, WordleError> {
+    let num_letters = objective.chars().count();
+    if num_letters != guess.chars().count() {
+        return Err(WordleError::WordLength(num_letters));
     }
-    let mut results = Vec::with_capacity(guess.len());
-    results.resize(guess.len(), LetterResult::NotPresent);
-    for (objective_index, objective_letter) in objective.char_indices() {
+    let results = match mode {
+        DuplicateMode::Standard => standard_results(objective, guess, num_letters),
+        DuplicateMode::Simple => simple_results(objective, guess),
+    };
+    Ok(GuessResult { guess, results })
+}
+
+fn standard_results(objective: &str, guess: &str, num_letters: usize) -> Vec<LetterResult> {
+    let mut results = vec![LetterResult::NotPresent; num_letters];
+    for (objective_index, objective_letter) in objective.chars().enumerate() {
         let mut set_index = None;
-        for (guess_index, guess_letter) in guess.char_indices() {
+        for (guess_index, guess_letter) in guess.chars().enumerate() {
             // Break if we're done and there is no chance of being correct.
             if set_index.is_some() && guess_index > objective_index {
                 break;
@@ -207,5 +1147,21 @@ pub fn get_result_for_guess<'a>(
             set_index = Some(guess_index);
         }
     }
-    Ok(GuessResult { guess, results })
+    results
+}
+
+fn simple_results(objective: &str, guess: &str) -> Vec<LetterResult> {
+    guess
+        .chars()
+        .zip(objective.chars())
+        .map(|(guess_letter, objective_letter)| {
+            if guess_letter == objective_letter {
+                LetterResult::Correct
+            } else if objective.contains(guess_letter) {
+                LetterResult::PresentNotHere
+            } else {
+                LetterResult::NotPresent
+            }
+        })
+        .collect()
 }
@@ -1,15 +1,19 @@
 use crate::data::*;
+use crate::parallel::*;
 use crate::restrictions::LetterRestriction;
 use crate::restrictions::WordRestrictions;
+use crate::results::count_words_by_result;
 use crate::results::get_result_for_guess;
 use crate::results::CompressedGuessResult;
+use crate::results::LetterResult;
 use crate::results::WordleError;
+use crate::results::MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT;
 use crate::GuessFrom;
-use rayon::prelude::*;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::result::Result;
 use std::sync::Arc;
+use std::sync::Mutex;
 
 /// Gives words a score, where the maximum score indicates the best guess.
 ///
@@ -22,13 +26,15 @@ use std::sync::Arc;
 /// file. This table shows the average number of guesses needed to find the objective word. There
 /// are more details in the docs for each scorer.
 ///
-/// | Scorer                             |[`GuessFrom::PossibleWords`]|[`GuessFrom::AllUnguessedWords`]|
-/// |------------------------------------|----------------------------|--------------------------------|
-/// |[`MaxComboEliminationsScorer`]      |               3.91 +/- 1.03|                   3.70 +/- 0.65|
-/// |[`MaxEliminationsScorer`]           |               3.95 +/- 1.10|                   3.72 +/- 0.67|
-/// |[`MaxApproximateEliminationsScorer`]|               4.01 +/- 1.15|                   3.85 +/- 0.72|
-/// |[`LocatedLettersScorer`]            |               4.00 +/- 1.15|                   3.91 +/- 1.04|
-/// |[`MaxUniqueLetterFrequencyScorer`]  |               4.17 +/- 1.24|                   4.08 +/- 0.83|
+/// | Scorer                                |[`GuessFrom::PossibleWords`]|[`GuessFrom::AllUnguessedWords`]|
+/// |---------------------------------------|----------------------------|--------------------------------|
+/// |[`MinExpectedGuessesApproximateScorer`]|               3.92 +/- 1.09|                   3.69 +/- 0.67|
+/// |[`MaxComboEliminationsScorer`]         |               3.91 +/- 1.03|                   3.70 +/- 0.65|
+/// |[`MaxEliminationsScorer`]              |               3.95 +/- 1.10|                   3.72 +/- 0.67|
+/// |[`MaxApproximateEliminationsScorer`]   |               4.01 +/- 1.16|                   3.84 +/- 0.72|
+/// |[`LocatedLettersScorer`]               |               4.00 +/- 1.15|                   3.91 +/- 1.04|
+/// |[`PositionalLetterFrequencyScorer`]    |               4.23 +/- 1.18|                   4.26 +/- 1.17|
+/// |[`MaxUniqueLetterFrequencyScorer`]     |               4.17 +/- 1.24|                   4.08 +/- 0.83|
 pub trait WordScorer {
     /// Updates the scorer with the latest guess, the updated set of restrictions, and the updated
     /// list of possible words.
@@ -40,17 +46,102 @@ pub trait WordScorer {
     ) -> Result<(), WordleError>;
     /// Determines a score for the given word. The higher the score, the better the guess.
     fn score_word(&self, word: &Arc<str>) -> i64;
+
+    /// Determines a score for the given word without truncating it to an integer, in the same
+    /// units as [`Self::score_word()`]. Defaults to the score from [`Self::score_word()`].
+    ///
+    /// This is used by [`MaxScoreGuesser`](crate::MaxScoreGuesser) when precise ranking is
+    /// enabled.
+    fn score_word_precise(&self, word: &Arc<str>) -> f64 {
+        self.score_word(word) as f64
+    }
+
+    /// Returns true iff this scorer's scores depend only on the word being scored and the current
+    /// list of possible words.
+    ///
+    /// If this is true, [`MaxScoreGuesser`](crate::MaxScoreGuesser) may reuse previously computed
+    /// scores when an update doesn't change the list of possible words. Defaults to `false`.
+    fn is_incremental(&self) -> bool {
+        false
+    }
+
+    /// Describes the score for the given word in human-readable terms, such as how many possible
+    /// words it would leave on average, for display alongside a suggested guess. Defaults to
+    /// `None`, for scorers that don't provide a description.
+    fn explain(&self, _word: &Arc<str>) -> Option<String> {
+        None
+    }
+}
+
+/// The default factor by which scorers with fractional scores multiply them before truncating
+/// them to integers.
+const DEFAULT_PRECISION: i64 = 1000;
+
+/// Returns a [`WordleError::WordLength`] error if words of the given length are too long for
+/// their results to be stored in a [`CompressedGuessResult`].
+fn check_compressible_word_length(word_length: usize) -> Result<(), WordleError> {
+    if word_length > MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT {
+        return Err(WordleError::WordLength(
+            MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT,
+        ));
+    }
+    Ok(())
+}
+
+/// Updates the `counter` to count the new `possible_words` instead of the `previous_words`.
+///
+/// If the new possible words are a subset of the previous words, this removes just the words that
+/// are no longer possible, else it rebuilds the counter. The words are only hashed when removing
+/// them would be cheaper than rebuilding.
+fn update_word_counter(
+    counter: &mut WordCounter,
+    previous_words: &mut Vec<Arc<str>>,
+    possible_words: &[Arc<str>],
+) {
+    // Removing most of the words is slower than counting the few that remain.
+    let num_removed = previous_words.len().saturating_sub(possible_words.len());
+    let should_remove =
+        possible_words.len() <= previous_words.len() && num_removed < possible_words.len();
+    let removed_words: Option<Vec<&Arc<str>>> = should_remove
+        .then(|| {
+            let mut not_yet_kept: HashSet<&str> =
+                possible_words.iter().map(|word| word.as_ref()).collect();
+            if not_yet_kept.len() != possible_words.len() {
+                return None;
+            }
+            let removed_words: Vec<&Arc<str>> = previous_words
+                .iter()
+                .filter(|word| !not_yet_kept.remove(word.as_ref()))
+                .collect();
+            // The new words are a subset iff each of them was kept from the previous words.
+            not_yet_kept.is_empty().then_some(removed_words)
+        })
+        .flatten();
+    match removed_words {
+        Some(removed_words) => {
+            for word in removed_words {
+                counter.remove_word(word);
+            }
+        }
+        None => *counter = WordCounter::new(possible_words),
+    }
+    *previous_words = possible_words.to_vec();
 }
 
 /// Scores words by the number of unique words that have the same letter (in any location), summed
 /// across each unique and not-yet guessed letter in the word.
 ///
+/// Optionally, this can also add the number of words with each not-yet guessed letter in the same
+/// location, multiplied by a position weight. See [`Self::with_position_weight()`].
+///
 /// See the [README](https://github.com/MorganR/rs-wordle-solver/blob/main/README.md) for benchmarks.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaxUniqueLetterFrequencyScorer {
     guessed_letters: HashSet<char>,
     word_counter: WordCounter,
+    possible_words: Vec<Arc<str>>,
+    position_weight: f64,
 }
 
 impl MaxUniqueLetterFrequencyScorer {
@@ -75,13 +166,40 @@ impl MaxUniqueLetterFrequencyScorer {
     /// ```
     pub fn new<S>(all_words: &[S]) -> MaxUniqueLetterFrequencyScorer
     where
-        S: AsRef<str>,
+        S: AsRef<str> + Sync,
     {
         MaxUniqueLetterFrequencyScorer {
             guessed_letters: HashSet::new(),
             word_counter: WordCounter::new(all_words),
+            possible_words: all_words
+                .iter()
+                .map(|word| Arc::from(word.as_ref()))
+                .collect(),
+            position_weight: 0.0,
         }
     }
+
+    /// Sets the weight given to letter locations. For each not-yet guessed letter in the word,
+    /// the number of possible words with that letter in the same location is multiplied by this
+    /// weight and added to the score. The default weight is `0.0`, i.e. locations are ignored.
+    ///
+    /// This provides a middle ground between this scorer and the [`LocatedLettersScorer`].
+    ///
+    /// ```
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxUniqueLetterFrequencyScorer;
+    /// use rs_wordle_solver::scorers::WordScorer;
+    /// use std::sync::Arc;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "cba"]).unwrap();
+    /// let scorer = MaxUniqueLetterFrequencyScorer::new(&bank).with_position_weight(1.0);
+    ///
+    /// assert!(scorer.score_word(&Arc::from("abc")) > scorer.score_word(&Arc::from("cba")));
+    /// ```
+    pub fn with_position_weight(mut self, position_weight: f64) -> Self {
+        self.position_weight = position_weight;
+        self
+    }
 }
 
 impl WordScorer for MaxUniqueLetterFrequencyScorer {
@@ -92,25 +210,38 @@ impl WordScorer for MaxUniqueLetterFrequencyScorer {
         possible_words: &[Arc<str>],
     ) -> Result<(), WordleError> {
         self.guessed_letters.extend(latest_guess.chars());
-        self.word_counter = WordCounter::from_iter(possible_words);
+        update_word_counter(
+            &mut self.word_counter,
+            &mut self.possible_words,
+            possible_words,
+        );
         Ok(())
     }
 
     fn score_word(&self, word: &Arc<str>) -> i64 {
         let mut sum = 0;
-        for (index, letter) in word.char_indices() {
-            if (index > 0
+        let mut located_sum = 0;
+        for (index, letter) in word.chars().enumerate() {
+            if self.guessed_letters.contains(&letter) {
+                continue;
+            }
+            located_sum += self
+                .word_counter
+                .num_words_with_located_letter(&LocatedLetter::new(letter, index as u8));
+            if index > 0
                 && word
                     .chars()
                     .take(index)
-                    .any(|other_letter| other_letter == letter))
-                || self.guessed_letters.contains(&letter)
+                    .any(|other_letter| other_letter == letter)
             {
                 continue;
             }
             sum += self.word_counter.num_words_with_letter(letter) as i64;
         }
-        sum
+        if self.position_weight == 0.0 {
+            return sum;
+        }
+        sum + (located_sum as f64 * self.position_weight) as i64
     }
 }
 
@@ -139,6 +270,7 @@ impl WordScorer for MaxUniqueLetterFrequencyScorer {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LocatedLettersScorer {
     counter: WordCounter,
+    possible_words: Vec<Arc<str>>,
     restrictions: WordRestrictions,
 }
 
@@ -163,6 +295,7 @@ impl LocatedLettersScorer {
         LocatedLettersScorer {
             restrictions: WordRestrictions::new(bank.word_length() as u8),
             counter: WordCounter::new(bank),
+            possible_words: bank.to_vec(),
         }
     }
 }
@@ -175,13 +308,13 @@ impl WordScorer for LocatedLettersScorer {
         possible_words: &[Arc<str>],
     ) -> Result<(), WordleError> {
         self.restrictions = restrictions.clone();
-        self.counter = WordCounter::from_iter(possible_words);
+        update_word_counter(&mut self.counter, &mut self.possible_words, possible_words);
         Ok(())
     }
 
     fn score_word(&self, word: &Arc<str>) -> i64 {
         let mut sum = 0;
-        for (index, letter) in word.char_indices() {
+        for (index, letter) in word.chars().enumerate() {
             let located_letter = LocatedLetter::new(letter, index as u8);
             if let Some(known_state) = self.restrictions.state(&located_letter) {
                 match known_state {
@@ -213,6 +346,71 @@ impl WordScorer for LocatedLettersScorer {
     }
 }
 
+/// Scores words by the number of possible words that have the same letter in the same location,
+/// summed across each location in the word. Locations where the letter is already known to be
+/// correct are skipped, since they don't provide any new information.
+///
+/// This is a middle ground between the [`MaxUniqueLetterFrequencyScorer`] and the
+/// [`LocatedLettersScorer`].
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PositionalLetterFrequencyScorer {
+    counter: WordCounter,
+    possible_words: Vec<Arc<str>>,
+    restrictions: WordRestrictions,
+}
+
+impl PositionalLetterFrequencyScorer {
+    /// Constructs a `PositionalLetterFrequencyScorer` based on the given [`WordBank`].
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::PositionalLetterFrequencyScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"]).unwrap();
+    /// let scorer = PositionalLetterFrequencyScorer::new(&bank);
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// assert!(guesser.select_next_guess().is_some());
+    /// ```
+    pub fn new(bank: &WordBank) -> PositionalLetterFrequencyScorer {
+        PositionalLetterFrequencyScorer {
+            counter: WordCounter::new(bank),
+            possible_words: bank.to_vec(),
+            restrictions: WordRestrictions::new(bank.word_length() as u8),
+        }
+    }
+}
+
+impl WordScorer for PositionalLetterFrequencyScorer {
+    fn update(
+        &mut self,
+        _last_guess: &str,
+        restrictions: &WordRestrictions,
+        possible_words: &[Arc<str>],
+    ) -> Result<(), WordleError> {
+        self.restrictions = restrictions.clone();
+        update_word_counter(&mut self.counter, &mut self.possible_words, possible_words);
+        Ok(())
+    }
+
+    fn score_word(&self, word: &Arc<str>) -> i64 {
+        word.chars()
+            .enumerate()
+            .map(|(index, letter)| LocatedLetter::new(letter, index as u8))
+            .filter(|located_letter| {
+                self.restrictions.state(located_letter) != Some(LetterRestriction::Here)
+            })
+            .map(|located_letter| {
+                self.counter.num_words_with_located_letter(&located_letter) as i64
+            })
+            .sum()
+    }
+}
+
 /// This selects the word that is expected to eliminate approximately the most other words.
 /// For each letter, the expected number of eliminations is computed for each possible state:
 ///
@@ -227,6 +425,10 @@ impl WordScorer for LocatedLettersScorer {
 /// * if not present: all are removed, so: 3 * (0/3) *(note: this expectation is skipped if this letter*
 ///   *has already been checked at another location)*.
 ///
+/// If the letter has already been checked at another location, then words without the letter have
+/// already been counted, so only words with the letter are considered eliminated when it's correct
+/// or present not here.
+///
 /// These per-letter expectations are then summed together to get the expectation value for the
 /// word. Approximating the expected eliminations in this way is cheap to compute, but slightly less
 /// accurate, and therefore less effective, than using the precise counts computed by
@@ -238,6 +440,7 @@ impl WordScorer for LocatedLettersScorer {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaxApproximateEliminationsScorer {
     counter: WordCounter,
+    precision: i64,
 }
 
 impl MaxApproximateEliminationsScorer {
@@ -261,12 +464,21 @@ impl MaxApproximateEliminationsScorer {
     pub fn new(all_words: &WordBank) -> MaxApproximateEliminationsScorer {
         MaxApproximateEliminationsScorer {
             counter: WordCounter::new(all_words),
+            precision: DEFAULT_PRECISION,
         }
     }
 
+    /// Sets the factor by which the expected number of eliminations is multiplied to produce each
+    /// score, before truncating it to an integer. The default is `1000`. Higher values break more
+    /// ties between guesses with similar expected eliminations.
+    pub fn with_precision(mut self, precision: i64) -> Self {
+        self.precision = precision;
+        self
+    }
+
     fn compute_expected_eliminations(&self, word: &str) -> f64 {
         let mut sum = 0.0;
-        for (index, letter) in word.char_indices() {
+        for (index, letter) in word.chars().enumerate() {
             sum += self.compute_expected_eliminations_for_letter(
                 LocatedLetter::new(letter, index as u8),
                 index == 0
@@ -288,16 +500,22 @@ impl MaxApproximateEliminationsScorer {
         let num_if_present = self.counter.num_words_with_letter(located_letter.letter) as f64;
         let num_if_present_not_here = num_if_present - num_if_correct;
         let total = self.counter.num_words() as f64;
+        if !is_new_letter {
+            // Only expect the eliminations tied to location, since we've already included the
+            // expected eliminations for if the letter is not present at all. Words without this
+            // letter were already eliminated by those expectations, so only words with this
+            // letter are eliminated here.
+            let eliminations_if_correct = num_if_present_not_here;
+            let eliminations_if_present_not_here = num_if_correct;
+            return (eliminations_if_correct * num_if_correct
+                + eliminations_if_present_not_here * num_if_present_not_here)
+                / total;
+        }
         let eliminations_if_correct = total - num_if_correct;
         let eliminations_if_present_not_here = total - num_if_present_not_here;
         let expected_eliminations_for_present_somewhere = eliminations_if_correct * num_if_correct
             / total
             + eliminations_if_present_not_here * num_if_present_not_here / total;
-        if !is_new_letter {
-            // Only expect the eliminations tied to location, since we've already included the
-            // expected eliminations for if the letter is not present at all.
-            return expected_eliminations_for_present_somewhere;
-        }
         let num_if_not_present = total - num_if_present;
         let eliminations_if_not_present = num_if_present;
         expected_eliminations_for_present_somewhere
@@ -312,12 +530,20 @@ impl WordScorer for MaxApproximateEliminationsScorer {
         _restrictions: &WordRestrictions,
         possible_words: &[Arc<str>],
     ) -> Result<(), WordleError> {
-        self.counter = WordCounter::from_iter(possible_words);
+        self.counter = WordCounter::new(possible_words);
         Ok(())
     }
 
     fn score_word(&self, word: &Arc<str>) -> i64 {
-        (self.compute_expected_eliminations(word.as_ref()) * 1000.0) as i64
+        self.score_word_precise(word) as i64
+    }
+
+    fn score_word_precise(&self, word: &Arc<str>) -> f64 {
+        self.compute_expected_eliminations(word.as_ref()) * self.precision as f64
+    }
+
+    fn is_incremental(&self) -> bool {
+        true
     }
 }
 
@@ -330,61 +556,389 @@ impl WordScorer for MaxApproximateEliminationsScorer {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaxEliminationsScorer {
     possible_words: Vec<Arc<str>>,
+    weights: Option<HashMap<Arc<str>, f64>>,
+    first_guess_eliminations: Option<HashMap<Arc<str>, f64>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cache: Option<Arc<GuessResultCache>>,
+    /// The index of each possible word in the cache, if all of them are in the cache.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    possible_word_indices: Option<Vec<usize>>,
+    precision: i64,
+}
+
+/// A precomputed matrix of the result of guessing each word in a bank against every other word.
+///
+/// Results are interned per guess into a single byte, so that the matrix takes `n²` bytes for a
+/// bank of `n` words.
+struct GuessResultCache {
+    words: Vec<Arc<str>>,
+    index_by_word: HashMap<Arc<str>, usize>,
+    /// The result ID for guessing word `g` when the objective is word `o` is at `g * n + o`. IDs
+    /// are only comparable within the same guess.
+    result_ids: Vec<u8>,
+}
+
+impl GuessResultCache {
+    /// Builds the cache, or returns `None` if any guess has more than 256 distinct results.
+    fn new(words: &[Arc<str>]) -> Option<GuessResultCache> {
+        let rows: Option<Vec<Vec<u8>>> = words
+            .par_iter()
+            .map(|guess| {
+                let mut id_by_result: HashMap<CompressedGuessResult, u8> = HashMap::new();
+                words
+                    .iter()
+                    .map(|objective| {
+                        let result = CompressedGuessResult::from_results(
+                            &get_result_for_guess(objective, guess).ok()?.results,
+                        )
+                        .ok()?;
+                        let next_id = id_by_result.len();
+                        if let Some(id) = id_by_result.get(&result) {
+                            return Some(*id);
+                        }
+                        let id = u8::try_from(next_id).ok()?;
+                        id_by_result.insert(result, id);
+                        Some(id)
+                    })
+                    .collect()
+            })
+            .collect();
+        Some(GuessResultCache {
+            words: words.to_vec(),
+            index_by_word: words
+                .iter()
+                .enumerate()
+                .map(|(index, word)| (Arc::clone(word), index))
+                .collect(),
+            result_ids: rows?.concat(),
+        })
+    }
+
+    /// Returns the index of each word in the cache, or `None` if any word is not in the cache.
+    fn indices_of(&self, words: &[Arc<str>]) -> Option<Vec<usize>> {
+        words
+            .iter()
+            .map(|word| self.index_by_word.get(word).copied())
+            .collect()
+    }
 }
 
 impl MaxEliminationsScorer {
     /// Constructs a `MaxEliminationsScorer`.
-
+    ///
+    /// Returns a [`WordleError::WordLength`] error if the words are longer than
+    /// [`MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT`], since their results can't be compared.
+    ///
     /// ```
     /// use rs_wordle_solver::GuessFrom;
     /// use rs_wordle_solver::Guesser;
     /// use rs_wordle_solver::MaxScoreGuesser;
     /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::WordleError;
+    /// use rs_wordle_solver::details::MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT;
     /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
     ///
-    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"]).unwrap();
-    /// let scorer = MaxEliminationsScorer::new(bank.clone());
+    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"])?;
+    /// let scorer = MaxEliminationsScorer::new(bank.clone())?;
     /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
     ///
     /// assert!(guesser.select_next_guess().is_some());
+    ///
+    /// let too_long = "a".repeat(MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT + 1);
+    /// assert_eq!(
+    ///     MaxEliminationsScorer::new(WordBank::from_iterator(&[too_long])?).err(),
+    ///     Some(WordleError::WordLength(MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT))
+    /// );
+    /// # Ok::<(), WordleError>(())
     /// ```
-    pub fn new(all_words: WordBank) -> MaxEliminationsScorer {
-        MaxEliminationsScorer {
+    pub fn new(all_words: WordBank) -> Result<MaxEliminationsScorer, WordleError> {
+        check_compressible_word_length(all_words.word_length())?;
+        Ok(MaxEliminationsScorer {
             possible_words: all_words.all_words,
-        }
+            weights: None,
+            first_guess_eliminations: None,
+            cache: None,
+            possible_word_indices: None,
+            precision: DEFAULT_PRECISION,
+        })
+    }
+
+    /// Constructs a `MaxEliminationsScorer` that weights each possible objective by its weight in
+    /// the given bank, instead of treating all possible objectives as equally likely.
+    ///
+    /// Returns a [`WordleError::WordLength`] error as for [`Self::new()`].
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WeightedWordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WeightedWordBank::from_reader(Cursor::new("abc,10\ndef,1\nghi,1\n"))?;
+    /// let scorer = MaxEliminationsScorer::from_weighted_bank(bank.clone())?;
+    /// let mut guesser = MaxScoreGuesser::new(
+    ///     GuessFrom::AllUnguessedWords, bank.word_bank().clone(), scorer);
+    ///
+    /// assert!(guesser.select_next_guess().is_some());
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn from_weighted_bank(
+        all_words: WeightedWordBank,
+    ) -> Result<MaxEliminationsScorer, WordleError> {
+        check_compressible_word_length(all_words.word_length())?;
+        Ok(MaxEliminationsScorer {
+            possible_words: all_words.all_words.clone(),
+            weights: Some(all_words.weights().clone()),
+            first_guess_eliminations: None,
+            cache: None,
+            possible_word_indices: None,
+            precision: DEFAULT_PRECISION,
+        })
+    }
+
+    /// Constructs a `MaxEliminationsScorer` from a table previously returned by
+    /// [`Self::first_guess_eliminations()`].
+    ///
+    /// The words in the table are used as the possible words, and the expected eliminations of
+    /// each word are read from the table until the first call to [`WordScorer::update()`].
+    ///
+    /// Returns a [`WordleError::WordLength`] error if the words have different lengths, or as for
+    /// [`Self::new()`].
+    ///
+    /// ```
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// use rs_wordle_solver::scorers::WordScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"])?;
+    /// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    /// let table = scorer.first_guess_eliminations();
+    ///
+    /// let restored = MaxEliminationsScorer::from_first_guess_eliminations(table)?;
+    ///
+    /// assert_eq!(restored.score_word(&bank[0]), scorer.score_word(&bank[0]));
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn from_first_guess_eliminations(
+        first_guess_eliminations: HashMap<Arc<str>, f64>,
+    ) -> Result<MaxEliminationsScorer, WordleError> {
+        let mut words: Vec<&Arc<str>> = first_guess_eliminations.keys().collect();
+        words.sort_unstable();
+        let bank = WordBank::from_iterator(words)?;
+        check_compressible_word_length(bank.word_length())?;
+        Ok(MaxEliminationsScorer {
+            possible_words: bank.all_words,
+            weights: None,
+            first_guess_eliminations: Some(first_guess_eliminations),
+            cache: None,
+            possible_word_indices: None,
+            precision: DEFAULT_PRECISION,
+        })
+    }
+
+    /// Constructs a `MaxEliminationsScorer` that precomputes the result of guessing every word in
+    /// the bank against every other word, instead of recomputing these results each time a word
+    /// is scored.
+    ///
+    /// This makes scoring much faster, at the cost of roughly `n²` bytes of memory for a bank of
+    /// `n` words (about 144MB for 12,000 words), and an upfront computation of the same cost as
+    /// scoring every word once. Words that aren't in the bank are still scored on the fly. If the
+    /// words are too long for their results to be cached, (i.e. any guess has more than 256
+    /// possible results), no cache is built and all words are scored on the fly.
+    ///
+    /// Returns a [`WordleError::WordLength`] error as for [`Self::new()`].
+    ///
+    /// ```
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// use rs_wordle_solver::scorers::WordScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"])?;
+    /// let cached_scorer = MaxEliminationsScorer::new_with_cache(bank.clone())?;
+    /// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    ///
+    /// assert_eq!(cached_scorer.score_word(&bank[0]), scorer.score_word(&bank[0]));
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn new_with_cache(all_words: WordBank) -> Result<MaxEliminationsScorer, WordleError> {
+        check_compressible_word_length(all_words.word_length())?;
+        let cache = GuessResultCache::new(&all_words.all_words).map(Arc::new);
+        let possible_word_indices = cache
+            .as_ref()
+            .map(|_| (0..all_words.all_words.len()).collect());
+        Ok(MaxEliminationsScorer {
+            possible_words: all_words.all_words,
+            weights: None,
+            first_guess_eliminations: None,
+            cache,
+            possible_word_indices,
+            precision: DEFAULT_PRECISION,
+        })
+    }
+
+    /// Sets the factor by which the expected number of eliminations is multiplied to produce each
+    /// score, before truncating it to an integer. The default is `1000`. Higher values break more
+    /// ties between guesses with similar expected eliminations.
+    ///
+    /// ```
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// use rs_wordle_solver::scorers::WordScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["cod", "wod", "mod"]).unwrap();
+    /// let scorer = MaxEliminationsScorer::new(bank.clone()).unwrap();
+    /// let precise_scorer = MaxEliminationsScorer::new(bank.clone()).unwrap().with_precision(1_000_000);
+    ///
+    /// assert_eq!(scorer.score_word(&bank[0]), 1333);
+    /// assert_eq!(precise_scorer.score_word(&bank[0]), 1333333);
+    /// ```
+    pub fn with_precision(mut self, precision: i64) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Returns the expected number of eliminations for guessing each of the current possible
+    /// words.
+    ///
+    /// This is expensive to compute for a full word bank, so it can be saved and later restored
+    /// with [`Self::from_first_guess_eliminations()`].
+    pub fn first_guess_eliminations(&self) -> HashMap<Arc<str>, f64> {
+        self.possible_words
+            .par_iter()
+            .map(|word| (Arc::clone(word), self.compute_expected_eliminations(word)))
+            .collect()
     }
 
     fn compute_expected_eliminations(&self, word: &Arc<str>) -> f64 {
-        compute_expected_eliminations(word, self.possible_words.iter(), self.possible_words.len())
+        if let Some(eliminations) = self
+            .first_guess_eliminations
+            .as_ref()
+            .and_then(|eliminations| eliminations.get(word))
+        {
+            return *eliminations;
+        }
+        if let Some(eliminations) = self.compute_cached_expected_eliminations(word) {
+            return eliminations;
+        }
+        compute_expected_eliminations(
+            word,
+            self.possible_words.iter(),
+            self.possible_words.len(),
+            self.weights.as_ref(),
+        )
+    }
+
+    /// Computes the expected eliminations using the cached results, or returns `None` if the
+    /// results for this word aren't cached.
+    fn compute_cached_expected_eliminations(&self, word: &Arc<str>) -> Option<f64> {
+        let cache = self.cache.as_ref()?;
+        let possible_word_indices = self.possible_word_indices.as_ref()?;
+        let guess_index = *cache.index_by_word.get(word)?;
+        let num_words = cache.words.len();
+        let row = &cache.result_ids[guess_index * num_words..(guess_index + 1) * num_words];
+        let mut count_by_id = [0usize; 256];
+        let mut weight_by_id = [0.0f64; 256];
+        let mut total_weight = 0.0;
+        for &index in possible_word_indices {
+            let id = row[index] as usize;
+            count_by_id[id] += 1;
+            if let Some(weights) = &self.weights {
+                let weight = weights.get(&cache.words[index]).copied().unwrap_or(0.0);
+                weight_by_id[id] += weight;
+                total_weight += weight;
+            }
+        }
+        Some(expected_eliminations_for_groups(
+            possible_word_indices.len(),
+            count_by_id
+                .into_iter()
+                .zip(weight_by_id)
+                .filter(|(num_matched, _)| *num_matched > 0),
+            self.weights.as_ref().map(|_| total_weight),
+        ))
+    }
+}
+
+/// Computes the expected number of words eliminated, given the number of possible words matching
+/// each distinct result, and the total weight of those words.
+///
+/// If `total_weight` is given and positive, each group is weighted by its share of the total
+/// weight. Otherwise, all possible objectives are treated as equally likely.
+fn expected_eliminations_for_groups<I>(
+    num_possible_words: usize,
+    count_and_weight_by_result: I,
+    total_weight: Option<f64>,
+) -> f64
+where
+    I: Iterator<Item = (usize, f64)>,
+{
+    match total_weight {
+        Some(total_weight) if total_weight > 0.0 => count_and_weight_by_result
+            .map(|(num_matched, weight)| {
+                (num_possible_words - num_matched) as f64 * weight / total_weight
+            })
+            .sum(),
+        _ => {
+            count_and_weight_by_result.fold(0, |acc, (num_matched, _)| {
+                acc + (num_possible_words - num_matched) * num_matched
+            }) as f64
+                / num_possible_words as f64
+        }
     }
 }
 
+/// Computes the expected number of words eliminated by guessing `word`.
+///
+/// If `weights` are given, each possible objective is assumed to be the answer with probability
+/// proportional to its weight. Otherwise, or if the total weight is not positive, all possible
+/// objectives are treated as equally likely.
 fn compute_expected_eliminations<W, I, T>(
     word: W,
     possible_words: I,
     num_possible_words: usize,
+    weights: Option<&HashMap<Arc<str>, f64>>,
 ) -> f64
 where
     W: AsRef<str>,
     I: Iterator<Item = T>,
     T: AsRef<str>,
 {
-    let mut matching_results: HashMap<CompressedGuessResult, usize> = HashMap::new();
-
-    for possible_word in possible_words {
-        let guess_result = CompressedGuessResult::from_results(
-            &get_result_for_guess(possible_word.as_ref(), word.as_ref())
-                .unwrap()
-                .results,
-        )
-        .unwrap();
-        *matching_results.entry(guess_result).or_insert(0) += 1;
+    if let Some(weights) = weights {
+        let mut count_and_weight_by_result: HashMap<CompressedGuessResult, (usize, f64)> =
+            HashMap::new();
+        let mut total_weight = 0.0;
+        for possible_word in possible_words {
+            let weight = weights.get(possible_word.as_ref()).copied().unwrap_or(0.0);
+            let result = CompressedGuessResult::from_results(
+                &get_result_for_guess(possible_word.as_ref(), word.as_ref())
+                    .unwrap()
+                    .results,
+            )
+            .unwrap();
+            let entry = count_and_weight_by_result.entry(result).or_insert((0, 0.0));
+            entry.0 += 1;
+            entry.1 += weight;
+            total_weight += weight;
+        }
+        return expected_eliminations_for_groups(
+            num_possible_words,
+            count_and_weight_by_result.into_values(),
+            Some(total_weight),
+        );
     }
-    matching_results.into_values().fold(0, |acc, num_matched| {
-        let num_eliminated = num_possible_words - num_matched;
-        acc + num_eliminated * num_matched
-    }) as f64
-        / num_possible_words as f64
+    expected_eliminations_for_groups(
+        num_possible_words,
+        count_words_by_result(word, possible_words)
+            .unwrap()
+            .into_values()
+            .map(|num_matched| (num_matched, 0.0)),
+        None,
+    )
 }
 
 impl WordScorer for MaxEliminationsScorer {
@@ -395,98 +949,347 @@ impl WordScorer for MaxEliminationsScorer {
         possible_words: &[Arc<str>],
     ) -> Result<(), WordleError> {
         self.possible_words = possible_words.to_vec();
+        self.first_guess_eliminations = None;
+        self.possible_word_indices = self
+            .cache
+            .as_ref()
+            .and_then(|cache| cache.indices_of(possible_words));
         Ok(())
     }
 
     fn score_word(&self, word: &Arc<str>) -> i64 {
-        let expected_elimations = self.compute_expected_eliminations(word);
-        (expected_elimations * 1000.0) as i64
+        self.score_word_precise(word) as i64
+    }
+
+    fn score_word_precise(&self, word: &Arc<str>) -> f64 {
+        self.compute_expected_eliminations(word) * self.precision as f64
+    }
+
+    fn is_incremental(&self) -> bool {
+        true
+    }
+
+    fn explain(&self, word: &Arc<str>) -> Option<String> {
+        if self.possible_words.is_empty() {
+            return None;
+        }
+        let num_possible_words = self.possible_words.len();
+        let expected_remaining =
+            num_possible_words as f64 - self.compute_expected_eliminations(word);
+        Some(format!(
+            "leaves {expected_remaining:.2} of {num_possible_words} possible words on average"
+        ))
     }
 }
 
-/// This probabilistically calculates the expectation value for how many words will be eliminated by
-/// the next two guesses, and chooses the word that maximizes that.
-///
-/// This is very expensive to run, and does not perform significantly better than
-/// [`MaxEliminationsScorer`], so you should probably use that instead. Constructing this solver
-/// with 4602 words takes a few minutes.
+/// Scores words by the Shannon entropy of the results they would produce across the possible
+/// words, i.e. the expected information gained from the guess, in bits.
 ///
-/// See the [README](https://github.com/MorganR/rs-wordle-solver/blob/main/README.md) for
-/// benchmarks.
+/// This assumes that each possible word is equally likely to be the objective. Compared to the
+/// [`MaxEliminationsScorer`], this gives more weight to guesses that split the possible words into
+/// many small groups. It has a similar computational cost.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct MaxComboEliminationsScorer {
-    words_to_guess: Vec<Arc<str>>,
+pub struct MaxEntropyScorer {
     possible_words: Vec<Arc<str>>,
-    guess_from: GuessFrom,
-    min_possible_words_for_combo: usize,
 }
 
-impl MaxComboEliminationsScorer {
-    /// Constructs a `MaxComboEliminationsScorer`. **Be careful, using this scorer is**
-    /// **computationally expensive!**
+impl MaxEntropyScorer {
+    /// Constructs a `MaxEntropyScorer`.
     ///
-    /// The cost of this scoring function scales in approximately *O*(*n*<sup>3</sup>), where *n*
-    /// is the number of words in `all_words`. `min_possible_words_for_combo` indicates the
-    /// threshold at which this scorer will only score words for the max eliminations on a single guess
-    /// (i.e. [`MaxEliminationsScorer`] behavior) instead of calculating the expected eliminations in
-    /// combination with a subsequent guess.
+    /// Returns a [`WordleError::WordLength`] error if the words are longer than
+    /// [`MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT`], since their results can't be compared.
     ///
     /// ```
     /// use rs_wordle_solver::GuessFrom;
     /// use rs_wordle_solver::Guesser;
     /// use rs_wordle_solver::MaxScoreGuesser;
     /// use rs_wordle_solver::WordBank;
-    /// use rs_wordle_solver::scorers::MaxComboEliminationsScorer;
+    /// # use rs_wordle_solver::WordleError;
+    /// use rs_wordle_solver::scorers::MaxEntropyScorer;
     ///
-    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"]).unwrap();
-    /// let guess_from = GuessFrom::AllUnguessedWords;
-    /// let scorer = MaxComboEliminationsScorer::new(bank.clone(), guess_from, 1000).unwrap();
-    /// let mut guesser = MaxScoreGuesser::new(guess_from, bank, scorer);
+    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"])?;
+    /// let scorer = MaxEntropyScorer::new(bank.clone())?;
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
     ///
     /// assert!(guesser.select_next_guess().is_some());
+    /// # Ok::<(), WordleError>(())
     /// ```
-    pub fn new(
-        all_words: WordBank,
-        guess_from: GuessFrom,
-        min_possible_words_for_combo: usize,
-    ) -> Result<MaxComboEliminationsScorer, WordleError> {
-        let scorer = MaxComboEliminationsScorer {
-            words_to_guess: all_words.iter().map(Arc::clone).collect(),
+    pub fn new(all_words: WordBank) -> Result<MaxEntropyScorer, WordleError> {
+        check_compressible_word_length(all_words.word_length())?;
+        Ok(MaxEntropyScorer {
             possible_words: all_words.all_words,
-            guess_from,
-            min_possible_words_for_combo,
-        };
-        Ok(scorer)
-    }
-
-    fn compute_expected_eliminations(&self, word: &Arc<str>) -> f64 {
-        if self.possible_words.len() > self.min_possible_words_for_combo {
-            self.compute_expected_combo_eliminations(word)
-        } else {
-            compute_expected_eliminations(
-                word,
-                self.possible_words.iter(),
-                self.possible_words.len(),
-            )
-        }
+        })
     }
 
-    fn compute_expected_combo_eliminations(&self, word: &Arc<str>) -> f64 {
-        let mut total_expected_eliminations = 0.0;
-        let num_possible_words = self.possible_words.len();
-        // Get an `&str` version of all remaining words to guess, excluding this word, to simplify
-        // choosing a second guess in the inner loop.
-        let words_to_guess: Vec<&str> = self
-            .words_to_guess
-            .iter()
-            .filter_map(|w| match w {
-                _ if w == word => None,
-                _ => Some(w.as_ref()),
+    fn compute_entropy(&self, word: &Arc<str>) -> f64 {
+        let num_possible_words = self.possible_words.len() as f64;
+        count_words_by_result(word, self.possible_words.iter())
+            .unwrap()
+            .into_values()
+            .map(|num_matched| {
+                let probability = num_matched as f64 / num_possible_words;
+                -probability * probability.log2()
             })
-            .collect();
+            .sum()
+    }
+}
 
-        let mut total_eliminations_for_first_result =
+impl WordScorer for MaxEntropyScorer {
+    fn update(
+        &mut self,
+        _latest_guess: &str,
+        _restrictions: &WordRestrictions,
+        possible_words: &[Arc<str>],
+    ) -> Result<(), WordleError> {
+        self.possible_words = possible_words.to_vec();
+        Ok(())
+    }
+
+    fn score_word(&self, word: &Arc<str>) -> i64 {
+        self.score_word_precise(word) as i64
+    }
+
+    fn score_word_precise(&self, word: &Arc<str>) -> f64 {
+        self.compute_entropy(word) * 1000.0
+    }
+
+    fn is_incremental(&self) -> bool {
+        true
+    }
+
+    fn explain(&self, word: &Arc<str>) -> Option<String> {
+        if self.possible_words.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "gains {:.2} bits of information",
+            self.compute_entropy(word)
+        ))
+    }
+}
+
+/// Scores words by the size of the largest group of possible words that could remain after the
+/// guess, i.e. the worst-case result. Guesses with smaller worst cases score higher, and ties are
+/// broken in favour of guesses that could be the objective.
+///
+/// This bounds the number of possible words after each guess, rather than optimizing the average
+/// case like the [`MaxEliminationsScorer`]. It has a similar computational cost.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinimaxScorer {
+    possible_words: Vec<Arc<str>>,
+}
+
+impl MinimaxScorer {
+    /// Constructs a `MinimaxScorer`.
+    ///
+    /// Returns a [`WordleError::WordLength`] error if the words are longer than
+    /// [`MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT`], since their results can't be compared.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// # use rs_wordle_solver::WordleError;
+    /// use rs_wordle_solver::scorers::MinimaxScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"])?;
+    /// let scorer = MinimaxScorer::new(bank.clone())?;
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// assert!(guesser.select_next_guess().is_some());
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn new(all_words: WordBank) -> Result<MinimaxScorer, WordleError> {
+        check_compressible_word_length(all_words.word_length())?;
+        Ok(MinimaxScorer {
+            possible_words: all_words.all_words,
+        })
+    }
+}
+
+impl WordScorer for MinimaxScorer {
+    fn update(
+        &mut self,
+        _latest_guess: &str,
+        _restrictions: &WordRestrictions,
+        possible_words: &[Arc<str>],
+    ) -> Result<(), WordleError> {
+        self.possible_words = possible_words.to_vec();
+        Ok(())
+    }
+
+    fn score_word(&self, word: &Arc<str>) -> i64 {
+        let num_words_by_result = count_words_by_result(word, self.possible_words.iter()).unwrap();
+        let largest_group = num_words_by_result.values().copied().max().unwrap_or(0) as i64;
+        // The word is possible iff some possible word would give an all-correct result.
+        let is_possible =
+            CompressedGuessResult::from_results(&vec![LetterResult::Correct; word.chars().count()])
+                .is_ok_and(|all_correct| num_words_by_result.contains_key(&all_correct));
+        -2 * largest_group + i64::from(is_possible)
+    }
+
+    fn is_incremental(&self) -> bool {
+        true
+    }
+}
+
+/// This probabilistically calculates the expectation value for how many words will be eliminated by
+/// the next two guesses, and chooses the word that maximizes that.
+///
+/// This is very expensive to run, and does not perform significantly better than
+/// [`MaxEliminationsScorer`], so you should probably use that instead. Constructing this solver
+/// with 4602 words takes a few minutes.
+///
+/// See the [README](https://github.com/MorganR/rs-wordle-solver/blob/main/README.md) for
+/// benchmarks.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MaxComboEliminationsScorer {
+    words_to_guess: Vec<Arc<str>>,
+    possible_words: Vec<Arc<str>>,
+    guess_from: GuessFrom,
+    min_possible_words_for_combo: usize,
+    /// The most work, as the number of possible words multiplied by the number of words to guess,
+    /// for which a combo search is done.
+    #[cfg_attr(feature = "serde", serde(default))]
+    work_budget: Option<u64>,
+    precision: i64,
+}
+
+impl MaxComboEliminationsScorer {
+    /// Constructs a `MaxComboEliminationsScorer`. **Be careful, using this scorer is**
+    /// **computationally expensive!**
+    ///
+    /// The cost of this scoring function scales in approximately *O*(*n*<sup>3</sup>), where *n*
+    /// is the number of words in `all_words`. `min_possible_words_for_combo` indicates the
+    /// threshold at which this scorer will only score words for the max eliminations on a single guess
+    /// (i.e. [`MaxEliminationsScorer`] behavior) instead of calculating the expected eliminations in
+    /// combination with a subsequent guess.
+    /// To bound the cost of each guess instead, see [`Self::with_work_budget()`].
+    ///
+    /// Returns a [`WordleError::WordLength`] error as for [`MaxEliminationsScorer::new()`].
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxComboEliminationsScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"]).unwrap();
+    /// let guess_from = GuessFrom::AllUnguessedWords;
+    /// let scorer = MaxComboEliminationsScorer::new(bank.clone(), guess_from, 1000).unwrap();
+    /// let mut guesser = MaxScoreGuesser::new(guess_from, bank, scorer);
+    ///
+    /// assert!(guesser.select_next_guess().is_some());
+    /// ```
+    pub fn new(
+        all_words: WordBank,
+        guess_from: GuessFrom,
+        min_possible_words_for_combo: usize,
+    ) -> Result<MaxComboEliminationsScorer, WordleError> {
+        check_compressible_word_length(all_words.word_length())?;
+        let scorer = MaxComboEliminationsScorer {
+            words_to_guess: all_words.iter().map(Arc::clone).collect(),
+            possible_words: all_words.all_words,
+            guess_from,
+            min_possible_words_for_combo,
+            work_budget: None,
+            precision: DEFAULT_PRECISION,
+        };
+        Ok(scorer)
+    }
+
+    /// Sets a budget for the combo search. While there are more than
+    /// `min_possible_words_for_combo` possible words, the combo search is only done if the number
+    /// of possible words multiplied by the number of words to guess is below `budget`. Otherwise,
+    /// words are scored by their eliminations on a single guess.
+    ///
+    /// This is checked each time a word is scored, so it bounds the time taken for each guess no
+    /// matter how the possible words shrink. By default, there is no budget.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxComboEliminationsScorer;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// use rs_wordle_solver::scorers::WordScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["cod", "wod", "mod"])?;
+    /// let combo_scorer = MaxComboEliminationsScorer::new(bank.clone(), GuessFrom::PossibleWords, 2)?;
+    /// // There are 3 possible words and 3 words to guess, which is over this budget.
+    /// let budget_scorer = combo_scorer.clone().with_work_budget(9);
+    /// let single_scorer = MaxEliminationsScorer::new(bank.clone())?;
+    ///
+    /// let word = Arc::from("zzz");
+    /// assert!(combo_scorer.score_word(&word) > 0);
+    /// assert_eq!(budget_scorer.score_word(&word), single_scorer.score_word(&word));
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn with_work_budget(mut self, budget: u64) -> Self {
+        self.work_budget = Some(budget);
+        self
+    }
+
+    /// Sets the factor by which the expected number of eliminations is multiplied to produce each
+    /// score, before truncating it to an integer. The default is `1000`. Higher values break more
+    /// ties between guesses with similar expected eliminations.
+    ///
+    /// The small bonus for guesses that would find the objective is added to the expected
+    /// eliminations before they are multiplied, so it scales with the precision.
+    pub fn with_precision(mut self, precision: i64) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Whether to score words by the combo search, rather than just their eliminations on a single
+    /// guess.
+    fn should_compute_combo(&self) -> bool {
+        if self.possible_words.len() <= self.min_possible_words_for_combo {
+            return false;
+        }
+        match self.work_budget {
+            Some(budget) => {
+                (self.possible_words.len() as u64).saturating_mul(self.words_to_guess.len() as u64)
+                    < budget
+            }
+            None => true,
+        }
+    }
+
+    fn compute_expected_eliminations(&self, word: &Arc<str>) -> f64 {
+        if self.should_compute_combo() {
+            self.compute_expected_combo_eliminations(word)
+        } else {
+            compute_expected_eliminations(
+                word,
+                self.possible_words.iter(),
+                self.possible_words.len(),
+                None,
+            )
+        }
+    }
+
+    fn compute_expected_combo_eliminations(&self, word: &Arc<str>) -> f64 {
+        let mut total_expected_eliminations = 0.0;
+        let num_possible_words = self.possible_words.len();
+        // Get an `&str` version of all remaining words to guess, excluding this word, to simplify
+        // choosing a second guess in the inner loop.
+        let words_to_guess: Vec<&str> = self
+            .words_to_guess
+            .iter()
+            .filter_map(|w| match w {
+                _ if w == word => None,
+                _ => Some(w.as_ref()),
+            })
+            .collect();
+
+        let mut total_eliminations_for_first_result =
             HashMap::with_capacity(self.possible_words.len());
 
         // For each possible objective word, which have equal probability, compute how many words we
@@ -542,6 +1345,7 @@ impl MaxComboEliminationsScorer {
                     second_guess,
                     still_possible_words.iter(),
                     still_possible_words.len(),
+                    None,
                 );
                 if expected_second_eliminations > best_second_eliminations {
                     best_second_eliminations = expected_second_eliminations;
@@ -584,7 +1388,633 @@ impl WordScorer for MaxComboEliminationsScorer {
     }
 
     fn score_word(&self, word: &Arc<str>) -> i64 {
-        let expected_eliminations = self.compute_expected_eliminations(word);
-        (expected_eliminations * 1000.0) as i64
+        self.score_word_precise(word) as i64
+    }
+
+    fn score_word_precise(&self, word: &Arc<str>) -> f64 {
+        self.compute_expected_eliminations(word) * self.precision as f64
+    }
+}
+
+/// Wraps another scorer, and adds a fixed bonus to the score of any word that could still be
+/// the answer according to the latest restrictions.
+///
+/// When guessing from all unguessed words, this makes ties and near-ties favour a guess that could
+/// win the game outright. The bonus is in the same units as the inner scorer's scores, so it should
+/// be small relative to the typical difference between the inner scorer's scores.
+///
+/// ```
+/// use std::sync::Arc;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::get_result_for_guess;
+/// use rs_wordle_solver::details::WordRestrictions;
+/// use rs_wordle_solver::scorers::BiasedEliminationsScorer;
+/// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+/// use rs_wordle_solver::scorers::WordScorer;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let bank = WordBank::from_iterator(&["abc", "abd", "xyz"])?;
+/// let mut scorer = BiasedEliminationsScorer::new(MaxEliminationsScorer::new(bank.clone())?, 100);
+///
+/// let restrictions = WordRestrictions::from_result(&get_result_for_guess("abc", "xyz")?);
+/// scorer.update("xyz", &restrictions, &bank[0..2])?;
+///
+/// // "abc" and "bcz" both split the possible words, but only "abc" could be the answer.
+/// assert_eq!(scorer.score_word(&bank[0]), 1100);
+/// assert_eq!(scorer.score_word(&Arc::from("bcz")), 1000);
+/// # Ok::<(), WordleError>(())
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BiasedEliminationsScorer<S: WordScorer> {
+    inner: S,
+    bonus: i64,
+    restrictions: Option<WordRestrictions>,
+}
+
+impl<S: WordScorer> BiasedEliminationsScorer<S> {
+    /// Constructs a `BiasedEliminationsScorer` that adds `bonus` to the inner scorer's score for
+    /// each word that is still possible. Until the first call to [`WordScorer::update()`], all words
+    /// are considered possible.
+    pub fn new(inner: S, bonus: i64) -> BiasedEliminationsScorer<S> {
+        BiasedEliminationsScorer {
+            inner,
+            bonus,
+            restrictions: None,
+        }
+    }
+
+    fn bonus_for(&self, word: &str) -> i64 {
+        match &self.restrictions {
+            Some(restrictions) if !restrictions.is_satisfied_by(word) => 0,
+            _ => self.bonus,
+        }
+    }
+}
+
+impl<S: WordScorer> WordScorer for BiasedEliminationsScorer<S> {
+    fn update(
+        &mut self,
+        latest_guess: &str,
+        restrictions: &WordRestrictions,
+        possible_words: &[Arc<str>],
+    ) -> Result<(), WordleError> {
+        self.restrictions = Some(restrictions.clone());
+        self.inner
+            .update(latest_guess, restrictions, possible_words)
+    }
+
+    fn score_word(&self, word: &Arc<str>) -> i64 {
+        self.inner.score_word(word) + self.bonus_for(word)
+    }
+
+    fn score_word_precise(&self, word: &Arc<str>) -> f64 {
+        self.inner.score_word_precise(word) + self.bonus_for(word) as f64
+    }
+
+    fn is_incremental(&self) -> bool {
+        // A word in the bank satisfies the restrictions iff it is still possible, so the bonus
+        // only changes for words that are eliminated.
+        self.inner.is_incremental()
+    }
+
+    fn explain(&self, word: &Arc<str>) -> Option<String> {
+        self.inner.explain(word)
+    }
+}
+
+/// Combines two scorers by scoring each word with the weighted sum of their scores, which is
+/// useful for experimenting with hybrid strategies.
+///
+/// The weighted sum is computed from each scorer's [`WordScorer::score_word_precise()`], and
+/// [`WordScorer::score_word()`] rounds it to the nearest integer. To combine more than two
+/// scorers, nest `WeightedScorer`s.
+///
+/// ```
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::scorers::LocatedLettersScorer;
+/// use rs_wordle_solver::scorers::MaxApproximateEliminationsScorer;
+/// use rs_wordle_solver::scorers::WeightedScorer;
+/// use rs_wordle_solver::scorers::WordScorer;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let bank = WordBank::from_iterator(&["abc", "abd", "xyz"])?;
+/// let located_letters = LocatedLettersScorer::new(&bank);
+/// let eliminations = MaxApproximateEliminationsScorer::new(&bank);
+/// let expected_score = 0.5 * located_letters.score_word_precise(&bank[0])
+///     + 0.5 * eliminations.score_word_precise(&bank[0]);
+///
+/// let scorer = WeightedScorer::new(located_letters, 0.5, eliminations, 0.5);
+///
+/// assert_eq!(scorer.score_word_precise(&bank[0]), expected_score);
+/// assert_eq!(scorer.score_word(&bank[0]), expected_score.round() as i64);
+/// # Ok::<(), WordleError>(())
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightedScorer<A: WordScorer, B: WordScorer> {
+    first: A,
+    first_weight: f64,
+    second: B,
+    second_weight: f64,
+}
+
+impl<A: WordScorer, B: WordScorer> WeightedScorer<A, B> {
+    /// Constructs a `WeightedScorer` that scores words with
+    /// `first_weight * first + second_weight * second`.
+    pub fn new(first: A, first_weight: f64, second: B, second_weight: f64) -> WeightedScorer<A, B> {
+        WeightedScorer {
+            first,
+            first_weight,
+            second,
+            second_weight,
+        }
+    }
+}
+
+impl<A: WordScorer, B: WordScorer> WordScorer for WeightedScorer<A, B> {
+    fn update(
+        &mut self,
+        latest_guess: &str,
+        restrictions: &WordRestrictions,
+        possible_words: &[Arc<str>],
+    ) -> Result<(), WordleError> {
+        self.first
+            .update(latest_guess, restrictions, possible_words)?;
+        self.second
+            .update(latest_guess, restrictions, possible_words)
+    }
+
+    fn score_word(&self, word: &Arc<str>) -> i64 {
+        self.score_word_precise(word).round() as i64
+    }
+
+    fn score_word_precise(&self, word: &Arc<str>) -> f64 {
+        self.first_weight * self.first.score_word_precise(word)
+            + self.second_weight * self.second.score_word_precise(word)
+    }
+
+    fn is_incremental(&self) -> bool {
+        self.first.is_incremental() && self.second.is_incremental()
+    }
+}
+
+/// Scores words by the number of distinct letters they contain that are not yet known to be in, or
+/// not in, the objective word. This ignores the possible words entirely.
+///
+/// This favours guesses that probe new letters, which can be useful early in the game. It is best
+/// combined with another scorer, for example by switching to a [`MaxEliminationsScorer`] after the
+/// first couple of guesses.
+///
+/// ```
+/// use std::sync::Arc;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::get_result_for_guess;
+/// use rs_wordle_solver::details::WordRestrictions;
+/// use rs_wordle_solver::scorers::ExplorationScorer;
+/// use rs_wordle_solver::scorers::WordScorer;
+/// # use rs_wordle_solver::WordleError;
+///
+/// let bank = WordBank::from_iterator(&["abc", "cde", "fgh"])?;
+/// let mut scorer = ExplorationScorer::new(&bank);
+///
+/// assert_eq!(scorer.score_word(&Arc::from("aab")), 2);
+///
+/// let restrictions = WordRestrictions::from_result(&get_result_for_guess("fgh", "abc")?);
+/// scorer.update("abc", &restrictions, &bank[2..])?;
+///
+/// assert_eq!(scorer.score_word(&bank[1]), 2);
+/// assert_eq!(scorer.score_word(&bank[2]), 3);
+/// # Ok::<(), WordleError>(())
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExplorationScorer {
+    restrictions: WordRestrictions,
+}
+
+impl ExplorationScorer {
+    /// Constructs an `ExplorationScorer` for words in the given bank, with all letters unknown.
+    pub fn new(bank: &WordBank) -> ExplorationScorer {
+        ExplorationScorer {
+            restrictions: WordRestrictions::new(bank.word_length() as u8),
+        }
+    }
+}
+
+impl WordScorer for ExplorationScorer {
+    fn update(
+        &mut self,
+        _latest_guess: &str,
+        restrictions: &WordRestrictions,
+        _possible_words: &[Arc<str>],
+    ) -> Result<(), WordleError> {
+        self.restrictions = restrictions.clone();
+        Ok(())
+    }
+
+    fn score_word(&self, word: &Arc<str>) -> i64 {
+        let unknown_letters: HashSet<char> = word
+            .chars()
+            .filter(|letter| self.restrictions.state_at(*letter, None).is_none())
+            .collect();
+        unknown_letters.len() as i64
+    }
+}
+
+/// Gives every word the same score, which is handy for testing the guesser plumbing independently
+/// of any scoring algorithm. To control the score of each word, see [`FnScorer`].
+///
+/// ```
+/// use std::sync::Arc;
+/// use rs_wordle_solver::GuessFrom;
+/// use rs_wordle_solver::MaxScoreGuesser;
+/// use rs_wordle_solver::ScoredGuess;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::scorers::ConstantScorer;
+///
+/// let bank = WordBank::from_iterator(&["abc", "def"]).unwrap();
+/// let mut guesser =
+///     MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, ConstantScorer::new(7));
+///
+/// // Ties are ordered by the word bank.
+/// assert_eq!(
+///     guesser.select_top_n_guesses(1),
+///     vec![ScoredGuess { score: 7, guess: Arc::from("abc") }]
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConstantScorer {
+    score: i64,
+}
+
+impl ConstantScorer {
+    /// Constructs a `ConstantScorer` that gives every word the given score.
+    pub fn new(score: i64) -> ConstantScorer {
+        ConstantScorer { score }
+    }
+}
+
+impl WordScorer for ConstantScorer {
+    fn update(
+        &mut self,
+        _latest_guess: &str,
+        _restrictions: &WordRestrictions,
+        _possible_words: &[Arc<str>],
+    ) -> Result<(), WordleError> {
+        Ok(())
+    }
+
+    fn score_word(&self, _word: &Arc<str>) -> i64 {
+        self.score
+    }
+
+    fn is_incremental(&self) -> bool {
+        true
+    }
+}
+
+/// Scores words by calling a user-supplied closure, which is handy for prototyping new scoring
+/// heuristics without defining a new type.
+///
+/// The closure is given the word to score, the latest restrictions, and the latest list of
+/// possible words, as provided by the most recent call to [`WordScorer::update()`]. Closures that
+/// only depend on the word can ignore the other arguments, e.g. `|word, _, _| word.len() as i64`.
+///
+/// ```
+/// use std::sync::Arc;
+/// use rs_wordle_solver::GuessFrom;
+/// use rs_wordle_solver::Guesser;
+/// use rs_wordle_solver::MaxScoreGuesser;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::details::WordRestrictions;
+/// use rs_wordle_solver::scorers::FnScorer;
+///
+/// let bank = WordBank::from_iterator(&["abc", "abd", "xyz"]).unwrap();
+/// // Prefer words that share a letter with more possible words.
+/// let scorer = FnScorer::new(
+///     &bank,
+///     |word: &Arc<str>, _restrictions: &WordRestrictions, possible_words: &[Arc<str>]| {
+///         possible_words
+///             .iter()
+///             .filter(|possible_word| word.chars().any(|letter| possible_word.contains(letter)))
+///             .count() as i64
+///     },
+/// );
+/// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+///
+/// assert_eq!(guesser.select_next_guess(), Some(Arc::from("abc")));
+/// ```
+#[derive(Clone)]
+pub struct FnScorer<F>
+where
+    F: Fn(&Arc<str>, &WordRestrictions, &[Arc<str>]) -> i64,
+{
+    score_fn: F,
+    restrictions: WordRestrictions,
+    possible_words: Vec<Arc<str>>,
+}
+
+impl<F> FnScorer<F>
+where
+    F: Fn(&Arc<str>, &WordRestrictions, &[Arc<str>]) -> i64,
+{
+    /// Constructs an `FnScorer` that scores words with the given closure. Initially, all words in
+    /// the bank are considered possible.
+    pub fn new(bank: &WordBank, score_fn: F) -> FnScorer<F> {
+        FnScorer {
+            score_fn,
+            restrictions: WordRestrictions::new(bank.word_length() as u8),
+            possible_words: bank.to_vec(),
+        }
+    }
+}
+
+impl<F> WordScorer for FnScorer<F>
+where
+    F: Fn(&Arc<str>, &WordRestrictions, &[Arc<str>]) -> i64,
+{
+    fn update(
+        &mut self,
+        _latest_guess: &str,
+        restrictions: &WordRestrictions,
+        possible_words: &[Arc<str>],
+    ) -> Result<(), WordleError> {
+        self.restrictions = restrictions.clone();
+        self.possible_words = possible_words.to_vec();
+        Ok(())
+    }
+
+    fn score_word(&self, word: &Arc<str>) -> i64 {
+        (self.score_fn)(word, &self.restrictions, &self.possible_words)
+    }
+}
+
+/// Scores words by the expected number of guesses needed to find the objective, computed by
+/// looking ahead a limited number of guesses. Lower expected guesses give higher scores.
+///
+/// For each result that a guess could produce, this recursively finds the remaining possible word
+/// that would best solve the resulting set of words, up to the configured depth. Past that depth,
+/// each set of words is assumed to be split perfectly by the next guess. The expected cost of each
+/// set of words is memoized, and shared between clones of this scorer. The memo holds the costs
+/// of up to 65536 sets of words, and is cleared when it fills up.
+///
+/// This directly optimizes the number of guesses, so it can make better guesses than
+/// [`MaxEliminationsScorer`], at a higher computational cost that grows quickly with the depth.
+///
+/// With the default depth of 1, and guessing from all unguessed words, this needed
+/// 3.19 +/- 0.65 guesses on average to solve each word in the
+/// `data/1000-improved-words-shuffled.txt` file, compared to 3.21 +/- 0.58 for the
+/// [`MaxEliminationsScorer`]. It has not been benchmarked on the full `data/improved-words.txt`
+/// file, because computing the first guess for that many words is very slow.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinExpectedGuessesExactScorer {
+    possible_words: Vec<Arc<str>>,
+    max_depth: u8,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    subtree_costs: Arc<Mutex<HashMap<SubtreeKey, f64>>>,
+}
+
+/// Identifies a memoized subtree by its sorted possible words and remaining lookahead depth.
+type SubtreeKey = (Vec<Arc<str>>, u8);
+
+/// The most sets of words whose expected costs are memoized by a [`MinExpectedGuessesExactScorer`]
+/// at once. The memo is cleared when it is full.
+const MAX_MEMOIZED_SUBTREE_COSTS: usize = 1 << 16;
+
+impl MinExpectedGuessesExactScorer {
+    /// Constructs a `MinExpectedGuessesExactScorer` for the given word bank, with a lookahead
+    /// depth of 1.
+    ///
+    /// Returns a [`WordleError::WordLength`] error if the words are longer than
+    /// [`MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT`], since their results can't be compared.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// # use rs_wordle_solver::WordleError;
+    /// use rs_wordle_solver::scorers::MinExpectedGuessesExactScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"])?;
+    /// let scorer = MinExpectedGuessesExactScorer::new(bank.clone())?;
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// assert!(guesser.select_next_guess().is_some());
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn new(bank: WordBank) -> Result<MinExpectedGuessesExactScorer, WordleError> {
+        check_compressible_word_length(bank.word_length())?;
+        Ok(MinExpectedGuessesExactScorer {
+            possible_words: bank.all_words,
+            max_depth: 1,
+            subtree_costs: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    /// Sets how many guesses to look ahead when computing the expected number of guesses after
+    /// each result. A depth of 0 only considers the immediate results of each guess.
+    pub fn with_max_depth(mut self, max_depth: u8) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Computes the expected number of guesses needed to find the objective, including this guess.
+    fn expected_guesses(&self, guess: &str, possible_words: &[Arc<str>], depth: u8) -> f64 {
+        let mut buckets: HashMap<CompressedGuessResult, Vec<Arc<str>>> = HashMap::new();
+        for word in possible_words {
+            let result = get_result_for_guess(word, guess).unwrap();
+            buckets
+                .entry(CompressedGuessResult::from_results(&result.results).unwrap())
+                .or_default()
+                .push(Arc::clone(word));
+        }
+        let mut total_cost = 0.0;
+        for bucket in buckets.values() {
+            if bucket.len() == 1 && bucket[0].as_ref() == guess {
+                continue;
+            }
+            if bucket.len() == possible_words.len() {
+                // This guess provides no information.
+                return f64::INFINITY;
+            }
+            total_cost += bucket.len() as f64 * self.subtree_cost(bucket, depth);
+        }
+        1.0 + total_cost / possible_words.len() as f64
+    }
+
+    /// Computes the expected number of guesses needed to find the objective from the given set of
+    /// possible words.
+    fn subtree_cost(&self, possible_words: &[Arc<str>], depth: u8) -> f64 {
+        let num_words = possible_words.len() as f64;
+        match possible_words.len() {
+            1 => return 1.0,
+            2 => return 1.5,
+            _ if depth == 0 => return (2.0 * num_words - 1.0) / num_words,
+            _ => {}
+        }
+        // Sort the words, since the same set of words may appear in different orders.
+        let mut sorted_words = possible_words.to_vec();
+        sorted_words.sort_unstable();
+        let key = (sorted_words, depth);
+        if let Some(cost) = self.subtree_costs.lock().unwrap().get(&key) {
+            return *cost;
+        }
+        let cost = possible_words
+            .iter()
+            .map(|guess| self.expected_guesses(guess, possible_words, depth - 1))
+            .fold(f64::INFINITY, f64::min);
+        let mut subtree_costs = self.subtree_costs.lock().unwrap();
+        if subtree_costs.len() >= MAX_MEMOIZED_SUBTREE_COSTS {
+            subtree_costs.clear();
+        }
+        subtree_costs.insert(key, cost);
+        cost
+    }
+}
+
+impl WordScorer for MinExpectedGuessesExactScorer {
+    fn update(
+        &mut self,
+        _latest_guess: &str,
+        _restrictions: &WordRestrictions,
+        possible_words: &[Arc<str>],
+    ) -> Result<(), WordleError> {
+        self.possible_words = possible_words.to_vec();
+        Ok(())
+    }
+
+    fn score_word(&self, word: &Arc<str>) -> i64 {
+        self.score_word_precise(word) as i64
+    }
+
+    fn score_word_precise(&self, word: &Arc<str>) -> f64 {
+        if self.possible_words.is_empty() {
+            return 0.0;
+        }
+        -self.expected_guesses(word, &self.possible_words, self.max_depth) * 1000.0
+    }
+
+    fn is_incremental(&self) -> bool {
+        true
+    }
+}
+
+/// Scores words by an approximation of the expected number of guesses needed to find the
+/// objective. Lower expected guesses give higher scores.
+///
+/// The possible words are grouped by the result that each would produce if this word were
+/// guessed, exactly as in the [`MaxEliminationsScorer`]. Instead of searching for the best second
+/// guess for each group like the [`MaxComboEliminationsScorer`] or the
+/// [`MinExpectedGuessesExactScorer`], this approximates the number of guesses still needed to
+/// find the objective in a group of `n` words as `1 + log2(n)`. Guessing the objective itself
+/// needs no further guesses. The extra `1` counts the guess that finds the objective once the
+/// group is narrowed down, since `log2(n)` alone would say a group of one word needs no more
+/// guesses.
+///
+/// This costs about the same to compute as the [`MaxEliminationsScorer`], and needs no
+/// precomputation on construction.
+///
+/// See the [README](https://github.com/MorganR/rs-wordle-solver/blob/main/README.md) for benchmarks.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinExpectedGuessesApproximateScorer {
+    possible_words: Vec<Arc<str>>,
+    precision: i64,
+}
+
+impl MinExpectedGuessesApproximateScorer {
+    /// Constructs a `MinExpectedGuessesApproximateScorer` for the given word bank.
+    ///
+    /// Returns a [`WordleError::WordLength`] error if the words are longer than
+    /// [`MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT`], since their results can't be compared.
+    ///
+    /// ```
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::Guesser;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// # use rs_wordle_solver::WordleError;
+    /// use rs_wordle_solver::scorers::MinExpectedGuessesApproximateScorer;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "def", "ghi"])?;
+    /// let scorer = MinExpectedGuessesApproximateScorer::new(bank.clone())?;
+    /// let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    ///
+    /// assert!(guesser.select_next_guess().is_some());
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn new(bank: WordBank) -> Result<MinExpectedGuessesApproximateScorer, WordleError> {
+        check_compressible_word_length(bank.word_length())?;
+        Ok(MinExpectedGuessesApproximateScorer {
+            possible_words: bank.all_words,
+            precision: DEFAULT_PRECISION,
+        })
+    }
+
+    /// Sets the factor by which the expected number of guesses is multiplied to produce each
+    /// score, before truncating it to an integer. The default is `1000`.
+    pub fn with_precision(mut self, precision: i64) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Approximates the expected number of guesses needed to find the objective, including this
+    /// guess.
+    fn expected_guesses(&self, word: &Arc<str>) -> f64 {
+        let num_possible_words = self.possible_words.len() as f64;
+        let all_correct =
+            CompressedGuessResult::from_results(&vec![LetterResult::Correct; word.chars().count()])
+                .ok();
+        let remaining_cost: f64 = count_words_by_result(word, self.possible_words.iter())
+            .unwrap()
+            .into_iter()
+            .filter(|(result, _)| Some(*result) != all_correct)
+            .map(|(_, num_matched)| {
+                let num_matched = num_matched as f64;
+                num_matched * (1.0 + num_matched.log2())
+            })
+            .sum();
+        1.0 + remaining_cost / num_possible_words
+    }
+}
+
+impl WordScorer for MinExpectedGuessesApproximateScorer {
+    fn update(
+        &mut self,
+        _latest_guess: &str,
+        _restrictions: &WordRestrictions,
+        possible_words: &[Arc<str>],
+    ) -> Result<(), WordleError> {
+        self.possible_words = possible_words.to_vec();
+        Ok(())
+    }
+
+    fn score_word(&self, word: &Arc<str>) -> i64 {
+        self.score_word_precise(word) as i64
+    }
+
+    fn score_word_precise(&self, word: &Arc<str>) -> f64 {
+        if self.possible_words.is_empty() {
+            return 0.0;
+        }
+        -self.expected_guesses(word) * self.precision as f64
+    }
+
+    fn is_incremental(&self) -> bool {
+        true
+    }
+
+    fn explain(&self, word: &Arc<str>) -> Option<String> {
+        if self.possible_words.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "needs about {:.2} guesses on average",
+            self.expected_guesses(word)
+        ))
     }
 }
@@ -0,0 +1,233 @@
+use crate::data::WordBank;
+use crate::engine::Guesser;
+use crate::results::*;
+use std::collections::HashMap;
+use std::io;
+use std::result::Result;
+use std::sync::Arc;
+
+/// A precomputed strategy for solving every possible word, built by following a [`Guesser`].
+///
+/// Each node holds the guess to make, and a child node for each result that guess could produce
+/// (other than the word being solved).
+///
+/// ```
+/// use rs_wordle_solver::DecisionTree;
+/// use rs_wordle_solver::GuessFrom;
+/// use rs_wordle_solver::MaxScoreGuesser;
+/// use rs_wordle_solver::WordBank;
+/// use rs_wordle_solver::get_result_for_guess;
+/// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+///
+/// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"]).unwrap();
+/// let scorer = MaxEliminationsScorer::new(bank.clone()).unwrap();
+/// let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+///
+/// let tree = DecisionTree::from_guesser(&guesser).unwrap();
+/// let result = get_result_for_guess("bcd", tree.guess()).unwrap();
+///
+/// assert_eq!(tree.next(&result.results).unwrap().guess().as_ref(), "bcd");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecisionTree {
+    guess: Arc<str>,
+    children: HashMap<CompressedGuessResult, DecisionTree>,
+}
+
+impl DecisionTree {
+    /// Builds a decision tree by asking clones of the guesser for a guess after each possible
+    /// result.
+    ///
+    /// Each branch is allowed up to as many guesses as there are possible words for the given
+    /// guesser. Words that the guesser fails to solve within that limit are not included in the
+    /// tree. Returns `None` if the guesser has no possible words.
+    pub fn from_guesser<G: Guesser + Clone>(guesser: &G) -> Option<DecisionTree> {
+        let max_depth = guesser.possible_words().len();
+        Self::build(guesser.clone(), max_depth)
+    }
+
+    fn build<G: Guesser + Clone>(mut guesser: G, remaining_depth: usize) -> Option<DecisionTree> {
+        if remaining_depth == 0 {
+            return None;
+        }
+        let guess = guesser.select_next_guess()?;
+        let mut results_by_compressed = HashMap::new();
+        for word in guesser.possible_words() {
+            if *word == guess {
+                continue;
+            }
+            let result = get_result_for_guess(word, &guess).ok()?;
+            let compressed = CompressedGuessResult::from_results(&result.results).ok()?;
+            results_by_compressed
+                .entry(compressed)
+                .or_insert(result.results);
+        }
+        let children = results_by_compressed
+            .into_iter()
+            .filter_map(|(compressed, results)| {
+                let mut child_guesser = guesser.clone();
+                child_guesser
+                    .update(&GuessResult {
+                        guess: &guess,
+                        results,
+                    })
+                    .ok()?;
+                Self::build(child_guesser, remaining_depth - 1).map(|child| (compressed, child))
+            })
+            .collect();
+        Some(DecisionTree { guess, children })
+    }
+
+    /// The guess to make at this point in the game.
+    pub fn guess(&self) -> &Arc<str> {
+        &self.guess
+    }
+
+    /// Returns the subtree to follow after [`Self::guess()`] produced the given results, or `None`
+    /// if this result isn't covered by the tree.
+    pub fn next(&self, results: &[LetterResult]) -> Option<&DecisionTree> {
+        let compressed = CompressedGuessResult::from_results(results).ok()?;
+        self.children.get(&compressed)
+    }
+
+    /// Encodes this tree in a compact binary form.
+    ///
+    /// Guesses are stored as their index in the given bank, and each result is stored as two bits
+    /// per letter. Decode the tree with [`Self::from_bytes()`] using the same bank.
+    ///
+    /// Returns a [`WordleError::IoError`] if a guess is not in the bank or a node has more than
+    /// [`u16::MAX`] children, or a [`WordleError::WordLength`] error if a guess is a different
+    /// length to the words in the bank.
+    ///
+    /// ```
+    /// use rs_wordle_solver::DecisionTree;
+    /// use rs_wordle_solver::GuessFrom;
+    /// use rs_wordle_solver::MaxScoreGuesser;
+    /// use rs_wordle_solver::WordBank;
+    /// use rs_wordle_solver::scorers::MaxEliminationsScorer;
+    /// # use rs_wordle_solver::WordleError;
+    ///
+    /// let bank = WordBank::from_iterator(&["abc", "abd", "bcd"])?;
+    /// let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    /// let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+    /// let tree = DecisionTree::from_guesser(&guesser).unwrap();
+    ///
+    /// let bytes = tree.to_bytes(&bank)?;
+    ///
+    /// assert_eq!(DecisionTree::from_bytes(&bytes, &bank)?, tree);
+    /// # Ok::<(), WordleError>(())
+    /// ```
+    pub fn to_bytes(&self, bank: &WordBank) -> Result<Vec<u8>, WordleError> {
+        let index_by_word: HashMap<&str, u32> = bank
+            .iter()
+            .enumerate()
+            .map(|(index, word)| (word.as_ref(), index as u32))
+            .collect();
+        let mut bytes = Vec::new();
+        self.write_bytes(&index_by_word, bank.word_length(), &mut bytes)?;
+        Ok(bytes)
+    }
+
+    fn write_bytes(
+        &self,
+        index_by_word: &HashMap<&str, u32>,
+        word_length: usize,
+        bytes: &mut Vec<u8>,
+    ) -> Result<(), WordleError> {
+        if self.guess.chars().count() != word_length {
+            return Err(WordleError::WordLength(word_length));
+        }
+        let index = index_by_word.get(self.guess.as_ref()).ok_or_else(|| {
+            WordleError::IoError(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("guess {} is not in the word bank", self.guess),
+            ))
+        })?;
+        bytes.extend_from_slice(&index.to_le_bytes());
+        let num_children = u16::try_from(self.children.len()).map_err(|_| {
+            WordleError::IoError(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "guess {} has {} children, but at most {} can be encoded",
+                    self.guess,
+                    self.children.len(),
+                    u16::MAX
+                ),
+            ))
+        })?;
+        bytes.extend_from_slice(&num_children.to_le_bytes());
+        let mut children: Vec<(&CompressedGuessResult, &DecisionTree)> =
+            self.children.iter().collect();
+        children.sort_unstable_by_key(|(result, _)| result.to_bits());
+        let num_result_bytes = num_result_bytes(word_length);
+        for (result, child) in children {
+            bytes.extend_from_slice(&result.to_bits().to_le_bytes()[..num_result_bytes]);
+            child.write_bytes(index_by_word, word_length, bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Decodes a tree that was encoded by [`Self::to_bytes()`] using the same bank.
+    ///
+    /// Returns a [`WordleError::IoError`] if the bytes are not a valid encoding for this bank.
+    pub fn from_bytes(bytes: &[u8], bank: &WordBank) -> Result<DecisionTree, WordleError> {
+        let mut remaining = bytes;
+        let tree = Self::read_bytes(&mut remaining, bank)?;
+        if !remaining.is_empty() {
+            return Err(invalid_data_error(
+                "unexpected bytes after the end of the tree",
+            ));
+        }
+        Ok(tree)
+    }
+
+    fn read_bytes(remaining: &mut &[u8], bank: &WordBank) -> Result<DecisionTree, WordleError> {
+        let index = u32::from_le_bytes(take_bytes(remaining)?) as usize;
+        let guess = bank
+            .get(index)
+            .ok_or_else(|| invalid_data_error("guess index is out of bounds for the word bank"))?
+            .clone();
+        let num_children = u16::from_le_bytes(take_bytes(remaining)?);
+        let num_result_bytes = num_result_bytes(bank.word_length());
+        let mut children = HashMap::with_capacity(num_children as usize);
+        for _ in 0..num_children {
+            if remaining.len() < num_result_bytes {
+                return Err(invalid_data_error("unexpected end of bytes"));
+            }
+            let mut result_bytes = [0u8; 16];
+            if num_result_bytes > result_bytes.len() {
+                return Err(invalid_data_error(
+                    "words are too long to have guess results",
+                ));
+            }
+            result_bytes[..num_result_bytes].copy_from_slice(&remaining[..num_result_bytes]);
+            *remaining = &remaining[num_result_bytes..];
+            let result = CompressedGuessResult::from_bits(
+                u128::from_le_bytes(result_bytes),
+                bank.word_length(),
+            )
+            .ok_or_else(|| invalid_data_error("invalid guess result"))?;
+            children.insert(result, Self::read_bytes(remaining, bank)?);
+        }
+        Ok(DecisionTree { guess, children })
+    }
+}
+
+/// The number of bytes needed to store a compressed result for words of the given length.
+fn num_result_bytes(word_length: usize) -> usize {
+    (word_length * 2).div_ceil(8)
+}
+
+fn take_bytes<const N: usize>(remaining: &mut &[u8]) -> Result<[u8; N], WordleError> {
+    if remaining.len() < N {
+        return Err(invalid_data_error("unexpected end of bytes"));
+    }
+    let mut bytes = [0u8; N];
+    bytes.copy_from_slice(&remaining[..N]);
+    *remaining = &remaining[N..];
+    Ok(bytes)
+}
+
+fn invalid_data_error(message: &str) -> WordleError {
+    WordleError::IoError(io::Error::new(io::ErrorKind::InvalidData, message))
+}
@@ -4,6 +4,7 @@ extern crate assert_matches;
 use rs_wordle_solver::details::*;
 use rs_wordle_solver::*;
 
+use std::io;
 use std::io::Cursor;
 use std::result::Result;
 use std::sync::Arc;
@@ -32,6 +33,33 @@ fn word_bank_from_reader_succeeds() -> Result<(), WordleError> {
     Ok(())
 }
 
+#[test]
+fn word_bank_from_reader_strips_byte_order_mark() -> Result<(), WordleError> {
+    let mut bytes: Vec<u8> = vec![0xEF, 0xBB, 0xBF];
+    bytes.extend_from_slice(b"worda\r\nwordb\r\n");
+    let mut cursor = Cursor::new(bytes);
+
+    let word_bank = WordBank::from_reader(&mut cursor)?;
+
+    assert_arc_eq!(&word_bank, &["worda", "wordb"]);
+    assert_eq!(word_bank.word_length(), 5);
+    Ok(())
+}
+
+#[test]
+fn word_bank_from_reader_handles_crlf_without_trim() -> Result<(), WordleError> {
+    let mut cursor = Cursor::new(b"\xEF\xBB\xBFworda\r\nwordb\r\n".to_vec());
+    let options = LoadOptions {
+        trim: false,
+        ..LoadOptions::default()
+    };
+
+    let word_bank = WordBank::from_reader_with_options(&mut cursor, &options)?;
+
+    assert_arc_eq!(&word_bank, &["worda", "wordb"]);
+    Ok(())
+}
+
 #[test]
 fn word_bank_from_iterator_succeeds() -> Result<(), WordleError> {
     let word_bank = WordBank::from_iterator(vec!["", "worda", "Wordb "])?;
@@ -56,6 +84,80 @@ fn word_bank_from_string_iterator_succeeds() -> Result<(), WordleError> {
     Ok(())
 }
 
+#[test]
+fn word_bank_from_iterator_without_lowercase_preserves_case() -> Result<(), WordleError> {
+    let options = LoadOptions {
+        lowercase: false,
+        ..LoadOptions::default()
+    };
+    let word_bank = WordBank::from_iterator_with_options(vec!["Worda", "worda "], &options)?;
+
+    assert_arc_eq!(&word_bank, &["Worda", "worda"]);
+    assert!(word_bank[0] != word_bank[1]);
+    Ok(())
+}
+
+#[test]
+fn word_bank_from_reader_without_lowercase_preserves_case() -> Result<(), WordleError> {
+    let mut cursor = Cursor::new(String::from("Worda\nworda\n"));
+    let options = LoadOptions {
+        lowercase: false,
+        ..LoadOptions::default()
+    };
+
+    let word_bank = WordBank::from_reader_with_options(&mut cursor, &options)?;
+
+    assert_arc_eq!(&word_bank, &["Worda", "worda"]);
+    Ok(())
+}
+
+#[test]
+fn word_bank_from_iterator_raw_trims_and_preserves_case() -> Result<(), WordleError> {
+    let word_bank = WordBank::from_iterator_raw(vec![" Worda", "worda ", "", "WORDA"])?;
+
+    assert_arc_eq!(&word_bank, &["Worda", "worda", "WORDA"]);
+    assert_eq!(word_bank.word_length(), 5);
+    assert_eq!(
+        WordBank::from_iterator_raw(vec!["Worda", "Word "]),
+        Err(WordleError::WordLength(5))
+    );
+    Ok(())
+}
+
+#[test]
+fn word_bank_from_iterator_raw_solves_case_sensitive_games() -> Result<(), WordleError> {
+    let word_bank = WordBank::from_iterator_raw(vec!["abC", "aBc", "Abc", "abc"])?;
+
+    assert_eq!(
+        get_result_for_guess("aBc", "abc")?.results,
+        vec![
+            LetterResult::Correct,
+            LetterResult::NotPresent,
+            LetterResult::Correct
+        ]
+    );
+    for objective in word_bank.iter() {
+        assert_matches!(
+            play_game_with_guesser(objective, 4, RandomGuesser::new(word_bank.clone()))?,
+            GameResult::Success(_)
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn word_bank_from_iterator_without_trim() {
+    let options = LoadOptions {
+        trim: false,
+        ..LoadOptions::default()
+    };
+
+    assert_matches!(
+        WordBank::from_iterator_with_options(vec!["worda", "wordb "], &options),
+        Err(WordleError::WordLength(5))
+    );
+}
+
 #[test]
 fn word_bank_from_reader_mismatched_word_length_fails() {
     let mut cursor = Cursor::new(String::from("\nlongword\n   short\n"));
@@ -66,6 +168,254 @@ fn word_bank_from_reader_mismatched_word_length_fails() {
     );
 }
 
+#[test]
+fn word_bank_counts_multi_byte_letters() -> Result<(), WordleError> {
+    let word_bank = WordBank::from_iterator(vec!["año", "PIÑA", "niño"]);
+
+    assert_matches!(word_bank, Err(WordleError::WordLength(3)));
+
+    let word_bank = WordBank::from_iterator(vec!["piña", "NIÑO", "sana"])?;
+
+    assert_arc_eq!(&word_bank, &["piña", "niño", "sana"]);
+    assert_eq!(word_bank.word_length(), 4);
+    Ok(())
+}
+
+#[test]
+fn word_bank_from_readers_merges_and_dedups() -> Result<(), WordleError> {
+    let allowed = Cursor::new(String::from("worda\nwordb\nworda\n"));
+    let empty = Cursor::new(String::new());
+    let answers = Cursor::new(String::from(" WORDB\nwordc\n"));
+
+    let word_bank = WordBank::from_readers([allowed, empty, answers])?;
+
+    assert_arc_eq!(&word_bank, &["worda", "wordb", "wordc"]);
+    assert_eq!(word_bank.word_length(), 5);
+    Ok(())
+}
+
+#[test]
+fn word_bank_from_readers_mismatched_word_length_fails() {
+    let allowed = Cursor::new(String::from("worda\n"));
+    let answers = Cursor::new(String::from("word\n"));
+
+    assert_matches!(
+        WordBank::from_readers([allowed, answers]),
+        Err(WordleError::WordLength(5))
+    );
+}
+
+#[test]
+fn word_bank_without_words() -> Result<(), WordleError> {
+    let word_bank = WordBank::from_iterator(vec!["worda", "wordb", "wordc", "wordd"])?;
+
+    let filtered = word_bank.without_words(&["wordb", "wordd", "WORDA", "other"]);
+
+    assert_arc_eq!(&filtered, &["worda", "wordc"]);
+    assert_eq!(filtered.word_length(), 5);
+    assert_eq!(word_bank.len(), 4);
+    Ok(())
+}
+
+#[test]
+fn word_bank_matching() -> Result<(), WordleError> {
+    let word_bank = WordBank::from_iterator(vec!["worda", "wordb", "wordc", "other"])?;
+    let restrictions = WordRestrictions::from_result(&get_result_for_guess("wordc", "wordb")?);
+
+    let matches: Vec<Arc<str>> = word_bank.matching(&restrictions).cloned().collect();
+    assert_arc_eq!(&matches, &["worda", "wordc"]);
+
+    // The bank can be searched again with other restrictions.
+    let restrictions = WordRestrictions::new(5);
+    assert_eq!(word_bank.matching(&restrictions).count(), 4);
+    Ok(())
+}
+
+#[test]
+fn word_bank_from_iterator_preserving_case() -> Result<(), WordleError> {
+    let word_bank =
+        WordBank::from_iterator_preserving_case(vec!["Paris", " lille", "", "PARIS", "Nancy"])?;
+
+    assert_arc_eq!(&word_bank, &["paris", "lille", "paris", "nancy"]);
+    assert_eq!(word_bank.original("paris"), Some("Paris"));
+    assert_eq!(word_bank.original("lille"), Some("lille"));
+    assert_eq!(word_bank.original("nancy"), Some("Nancy"));
+    assert_eq!(word_bank.original("Nancy"), None);
+    assert_eq!(word_bank.original("other"), None);
+    Ok(())
+}
+
+#[test]
+fn word_bank_from_iterator_preserving_case_mismatched_word_length_fails() {
+    let result = WordBank::from_iterator_preserving_case(vec!["Paris", "Rome"]);
+
+    assert_matches!(result, Err(WordleError::WordLength(5)));
+}
+
+#[test]
+fn word_bank_try_from_iter_succeeds() -> Result<(), WordleError> {
+    let word_bank = WordBank::try_from_iter(vec!["worda", "", " WORDB ", "wordc"])?;
+
+    assert_arc_eq!(&word_bank, &["worda", "wordb", "wordc"]);
+    assert_eq!(word_bank.word_length(), 5);
+    Ok(())
+}
+
+#[test]
+fn word_bank_try_from_iter_reports_index_of_mismatched_word() {
+    let result = WordBank::try_from_iter(vec!["", "worda", "wordb", "word", "wordc"]);
+
+    assert_eq!(
+        result.unwrap_err(),
+        WordleError::WordLengthAt {
+            expected: 5,
+            got: 4,
+            index: 3
+        }
+    );
+}
+
+#[test]
+fn word_bank_try_from_iter_stops_at_first_error() {
+    // This iterator never ends, so this only returns if it stops at the mismatched word.
+    let words = std::iter::repeat_n("worda", 100_000)
+        .chain(std::iter::once("toolong"))
+        .chain(std::iter::repeat("wordb"));
+
+    assert_eq!(
+        WordBank::try_from_iter(words).unwrap_err(),
+        WordleError::WordLengthAt {
+            expected: 5,
+            got: 7,
+            index: 100_000
+        }
+    );
+}
+
+#[test]
+fn word_bank_original_without_preserving_case() -> Result<(), WordleError> {
+    let word_bank = WordBank::from_iterator(vec!["Paris"])?;
+
+    assert_eq!(word_bank.original("paris"), None);
+    Ok(())
+}
+
+#[test]
+fn word_bank_retain_removes_original_words() -> Result<(), WordleError> {
+    let mut word_bank = WordBank::from_iterator_preserving_case(vec!["Paris", "Nancy"])?;
+
+    word_bank.retain(|word| word != "paris");
+
+    assert_eq!(word_bank.original("paris"), None);
+    assert_eq!(word_bank.original("nancy"), Some("Nancy"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn word_bank_normalizes_unicode() -> Result<(), WordleError> {
+    // "Café" with a precomposed 'é', and with an 'e' followed by a combining accent.
+    let word_bank = WordBank::from_iterator(vec!["Caf\u{e9}", "cafe\u{301}", "cafe"])?;
+
+    assert_arc_eq!(&word_bank, &["caf\u{e9}", "caf\u{e9}", "cafe"]);
+    assert_eq!(word_bank.word_length(), 4);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn word_bank_rejects_combining_characters() {
+    // There is no precomposed 'q' with an acute accent.
+    let result = WordBank::from_iterator(vec!["cafe", "q\u{301}ed"]);
+
+    assert_matches!(result, Err(WordleError::IoError(_)));
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn weighted_word_bank_normalizes_unicode() -> Result<(), WordleError> {
+    let bank = WeightedWordBank::from_frequency_and_used(
+        Cursor::new("cafe\u{301},4\nabcd,2\n"),
+        &["caf\u{e9}"],
+        0.5,
+    )?;
+
+    assert_eq!(bank.weight("caf\u{e9}"), 2.0);
+    assert_eq!(bank.word_bank().word_length(), 4);
+    Ok(())
+}
+
+#[test]
+fn word_bank_retain_keeps_word_length_when_empty() -> Result<(), WordleError> {
+    let mut word_bank = WordBank::from_iterator(vec!["worda", "wordb"])?;
+
+    word_bank.retain(|_| false);
+
+    assert!(word_bank.is_empty());
+    assert_eq!(word_bank.word_length(), 5);
+    Ok(())
+}
+
+#[test]
+fn weighted_word_bank_from_frequency_and_used() -> Result<(), WordleError> {
+    let mut cursor = Cursor::new(String::from("worda,10\nWordb, 10\n\nwordc,10\nwordd,2.5\n"));
+
+    let bank = WeightedWordBank::from_frequency_and_used(&mut cursor, &["wordb", "worda"], 0.5)?;
+
+    assert_arc_eq!(bank.word_bank(), &["worda", "wordb", "wordc", "wordd"]);
+    // The most recently used word is the least likely.
+    assert!(bank.weight("wordb") < bank.weight("worda"));
+    assert!(bank.weight("worda") < bank.weight("wordc"));
+    assert_eq!(bank.weight("wordb"), 5.0);
+    assert_eq!(bank.weight("worda"), 7.5);
+    assert_eq!(bank.weight("wordc"), 10.0);
+    assert_eq!(bank.weight("wordd"), 2.5);
+    assert_eq!(bank.weight("other"), 0.0);
+    Ok(())
+}
+
+#[test]
+fn weighted_word_bank_from_frequency_and_used_no_decay() -> Result<(), WordleError> {
+    let mut cursor = Cursor::new(String::from("worda,10\nwordb,10\n"));
+
+    let bank = WeightedWordBank::from_frequency_and_used(&mut cursor, &["wordb"], 0.0)?;
+
+    assert_eq!(bank.weight("worda"), bank.weight("wordb"));
+    Ok(())
+}
+
+#[test]
+fn weighted_word_bank_from_frequency_and_used_invalid_weight() {
+    let mut cursor = Cursor::new(String::from("worda,10\nwordb\n"));
+
+    assert_matches!(
+        WeightedWordBank::from_frequency_and_used(&mut cursor, &[] as &[&str], 0.5),
+        Err(WordleError::IoError(_))
+    );
+}
+
+#[test]
+fn weighted_word_bank_from_reader_invalid_weights() {
+    for contents in ["abc,NaN\n", "abc,-1\n", "abc,2\nbcd,inf\n"] {
+        assert_matches!(
+            WeightedWordBank::from_reader(Cursor::new(contents)),
+            Err(WordleError::IoError(err)) if err.kind() == io::ErrorKind::InvalidData
+        );
+    }
+}
+
+#[test]
+fn weighted_word_bank_from_reader_wrong_length() {
+    assert_eq!(
+        WeightedWordBank::from_reader(Cursor::new("abc,1\n\nabcd,2\n")).err(),
+        Some(WordleError::WordLengthAt {
+            expected: 3,
+            got: 4,
+            index: 2
+        })
+    );
+}
+
 #[test]
 fn compressed_guess_result_equality() -> Result<(), WordleError> {
     let result_correct = CompressedGuessResult::from_results(&[LetterResult::Correct; 4])?;
@@ -81,6 +431,25 @@ fn compressed_guess_result_equality() -> Result<(), WordleError> {
     Ok(())
 }
 
+#[test]
+fn compressed_guess_result_long_words() {
+    assert_eq!(MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT, 64);
+
+    let mut long_results = vec![LetterResult::NotPresent; 21];
+    long_results[20] = LetterResult::Correct;
+    let mut other_long_results = vec![LetterResult::NotPresent; 21];
+    other_long_results[20] = LetterResult::PresentNotHere;
+
+    assert_ne!(
+        CompressedGuessResult::from_results(&long_results).unwrap(),
+        CompressedGuessResult::from_results(&other_long_results).unwrap()
+    );
+    assert_eq!(
+        CompressedGuessResult::from_results(&long_results).unwrap(),
+        CompressedGuessResult::from_results(&long_results.clone()).unwrap()
+    );
+}
+
 #[test]
 fn compressed_guess_result_too_long() {
     assert_matches!(
@@ -98,3 +467,78 @@ fn compressed_guess_result_too_long() {
         ))
     );
 }
+
+#[test]
+fn word_tracker_words_with_located_letter_absent_matches_scan() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abba", "baba", "cabs", "dddd", "ñaña"])?;
+    let tracker = WordTracker::from_slice(&bank);
+
+    for letter in ['a', 'b', 'ñ', 'z'] {
+        for location in 0..4u8 {
+            let ll = LocatedLetter::new(letter, location);
+            let mut words: Vec<&Arc<str>> = tracker.words_with_located_letter_absent(ll).collect();
+            words.sort();
+            let expected: Vec<&Arc<str>> = bank
+                .iter()
+                .filter(|word| word.chars().nth(location as usize) != Some(letter))
+                .collect();
+            assert_eq!(words, expected);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn word_masks_letters() {
+    assert_eq!(WordMasks::new(Arc::from("abba")).letters(), Some(0b11));
+    assert_eq!(
+        WordMasks::new(Arc::from("zap")).letters(),
+        Some(1 << 25 | 1 << 15 | 1)
+    );
+    assert_eq!(WordMasks::new(Arc::from("ñaña")).letters(), None);
+}
+
+#[test]
+fn word_masks_matches_is_satisfied_by() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec![
+        "abba", "baba", "abab", "aabb", "cabs", "scab", "dddd", "ñaña", "aaña", "abbb",
+    ])?;
+    let word_masks = bank.word_masks();
+
+    for (guess, objective) in [
+        ("abba", "baba"),
+        ("abbb", "abab"),
+        ("aabb", "cabs"),
+        ("dddd", "scab"),
+        ("ñaña", "aaña"),
+        ("cabs", "ñaña"),
+        ("baba", "baba"),
+    ] {
+        let restrictions = WordRestrictions::from_result(&get_result_for_guess(objective, guess)?);
+        for masks in &word_masks {
+            assert_eq!(
+                masks.matches(&restrictions),
+                restrictions.is_satisfied_by(masks.word()),
+                "guess: {guess}, objective: {objective}, word: {}",
+                masks.word()
+            );
+        }
+        let matching: Vec<&Arc<str>> = WordMasks::filter(&word_masks, &restrictions).collect();
+        let expected: Vec<&Arc<str>> = bank
+            .iter()
+            .filter(|word| restrictions.is_satisfied_by(word))
+            .collect();
+        assert_eq!(matching, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn word_masks_checks_word_length() -> Result<(), WordleError> {
+    let restrictions = WordRestrictions::from_result(&get_result_for_guess("abc", "abd")?);
+
+    assert!(!WordMasks::new(Arc::from("ab")).matches(&restrictions));
+    assert!(!WordMasks::new(Arc::from("abcd")).matches(&restrictions));
+    assert!(WordMasks::new(Arc::from("abc")).matches(&restrictions));
+    Ok(())
+}
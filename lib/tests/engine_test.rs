@@ -1,15 +1,23 @@
 #[macro_use]
 extern crate assert_matches;
 
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rs_wordle_solver::details::WordRestrictions;
 use rs_wordle_solver::scorers::*;
 use rs_wordle_solver::*;
 
+use std::collections::HashSet;
 use std::result::Result;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::mpsc;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[test]
 fn random_guesser_select_next_guess_no_words() -> Result<(), WordleError> {
-    let bank = WordBank::from_iterator(&Vec::<Arc<str>>::new())?;
+    let bank = WordBank::from_iterator(Vec::<Arc<str>>::new())?;
     let mut guesser = RandomGuesser::new(bank);
 
     assert_eq!(guesser.select_next_guess(), None);
@@ -45,6 +53,52 @@ fn random_guesser_update_guess_result_modifies_next_guess() -> Result<(), Wordle
     Ok(())
 }
 
+#[test]
+fn random_guesser_shuffled_follows_permutation() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "def", "ghi", "jkl", "mno"])?;
+    let mut expected_order = bank.to_vec();
+    expected_order.shuffle(&mut StdRng::seed_from_u64(3));
+    let mut guesser = RandomGuesser::shuffled(bank, &mut StdRng::seed_from_u64(3));
+
+    let mut guesses = Vec::new();
+    while let Some(guess) = guesser.select_next_guess_from(GuessFrom::AllUnguessedWords) {
+        // Selecting again without an update returns the same guess.
+        assert_eq!(
+            guesser.select_next_guess_from(GuessFrom::AllUnguessedWords),
+            Some(Arc::clone(&guess))
+        );
+        guesser.update(&get_result_for_guess("xyz", &guess)?)?;
+        guesses.push(guess);
+    }
+
+    assert_eq!(guesses, expected_order);
+    Ok(())
+}
+
+#[test]
+fn random_guesser_shuffled_skips_eliminated_words() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "abe", "xyz", "xyw"])?;
+    let mut guesser = RandomGuesser::shuffled(bank.clone(), &mut StdRng::seed_from_u64(5));
+
+    guesser.guess_word("xyz")?;
+    guesser.update(&get_result_for_guess("abe", "xyz")?)?;
+    let mut guesses = Vec::new();
+    while let Some(guess) = guesser.select_next_guess() {
+        if guess.as_ref() == "abe" {
+            break;
+        }
+        guesser.update(&get_result_for_guess("abe", &guess)?)?;
+        guesses.push(guess);
+    }
+
+    assert!(guesses.len() <= 2);
+    for guess in &guesses {
+        assert!(["abc", "abd"].contains(&guess.as_ref()));
+    }
+    assert_eq!(guesser.select_next_guess(), Some(Arc::from("abe")));
+    Ok(())
+}
+
 #[test]
 fn random_guesser_invalid_update_fails() -> Result<(), WordleError> {
     let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
@@ -77,14 +131,72 @@ fn random_guesser_invalid_update_fails() -> Result<(), WordleError> {
                 LetterResult::NotPresent,
             ],
         }),
-        Err(WordleError::InvalidResults)
+        Err(WordleError::ContradictoryResults {
+            letter: 'c',
+            location: Some(0),
+            ..
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn random_guesser_unseeded_guessers_can_differ() -> Result<(), WordleError> {
+    let words: Vec<String> = (0..100).map(|index| format!("{index:03}")).collect();
+    let bank = WordBank::from_iterator(&words)?;
+
+    let first_guesses: HashSet<Arc<str>> = (0..10)
+        .filter_map(|_| RandomGuesser::new(bank.clone()).select_next_guess())
+        .collect();
+
+    assert!(first_guesses.len() > 1);
+    Ok(())
+}
+
+#[test]
+fn random_guesser_with_seed_is_deterministic() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec![
+        "abcde", "bcdea", "cdeab", "deabc", "eabcd", "fghij", "fghik", "zzzzz",
+    ])?;
+    let guesser = RandomGuesser::new(bank.clone()).with_seed(42);
+
+    for word in bank.iter() {
+        let result = play_game_with_guesser(word, 10, guesser.clone())?;
+        let other_result = play_game_with_guesser(word, 10, guesser.clone())?;
+
+        assert_matches!(result, GameResult::Success(_));
+        assert_eq!(result, other_result);
+    }
+    Ok(())
+}
+
+#[test]
+fn random_guesser_possible_words_iter_and_remaining_count() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+    let mut guesser = RandomGuesser::new(bank);
+
+    assert_eq!(guesser.remaining_count(), 3);
+
+    guesser.update(&GuessResult {
+        guess: "cde",
+        results: vec![
+            LetterResult::PresentNotHere,
+            LetterResult::NotPresent,
+            LetterResult::NotPresent,
+        ],
+    })?;
+
+    assert_eq!(guesser.remaining_count(), 1);
+    assert_eq!(
+        guesser.possible_words_iter().collect::<Vec<_>>(),
+        guesser.possible_words().iter().collect::<Vec<_>>()
     );
     Ok(())
 }
 
 #[test]
 fn max_score_guesser_select_next_guess_no_words() -> Result<(), WordleError> {
-    let bank = WordBank::from_iterator(&Vec::<Arc<str>>::new())?;
+    let bank = WordBank::from_iterator(Vec::<Arc<str>>::new())?;
     let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
     let mut guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
 
@@ -122,9 +234,32 @@ fn max_score_guesser_update_guess_result_modifies_next_guess() -> Result<(), Wor
     Ok(())
 }
 
+#[test]
+fn max_score_guesser_possible_words_iter_and_remaining_count() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+
+    guesser.update(&GuessResult {
+        guess: "cde",
+        results: vec![
+            LetterResult::PresentNotHere,
+            LetterResult::NotPresent,
+            LetterResult::NotPresent,
+        ],
+    })?;
+
+    assert_eq!(guesser.remaining_count(), 1);
+    assert_eq!(
+        guesser.possible_words_iter().cloned().collect::<Vec<_>>(),
+        guesser.possible_words().to_vec()
+    );
+    Ok(())
+}
+
 #[test]
 fn max_score_guesser_select_top_n_guesses_no_words() -> Result<(), WordleError> {
-    let bank = WordBank::from_iterator(&Vec::<Arc<str>>::new())?;
+    let bank = WordBank::from_iterator(Vec::<Arc<str>>::new())?;
     let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
     let mut guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
 
@@ -159,112 +294,1702 @@ fn max_score_guesser_select_top_n_guesses() -> Result<(), WordleError> {
 }
 
 #[test]
-fn max_score_guesser_invalid_update_fails() -> Result<(), WordleError> {
-    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+fn max_score_guesser_select_top_n_guesses_among() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["xxxx", "aaaa", "baac", "xabc"])?;
     let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
     let mut guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+    let scores_before = guesser.select_top_n_guesses(4);
 
-    guesser.update(&GuessResult {
-        guess: "abc",
-        results: vec![
-            LetterResult::NotPresent,
-            LetterResult::NotPresent,
-            LetterResult::NotPresent,
-        ],
-    })?;
-    assert_matches!(
-        guesser.update(&GuessResult {
-            guess: "bcd",
-            results: vec![
-                LetterResult::NotPresent,
-                // "c" can't be present, because we just said it's not present.
-                LetterResult::PresentNotHere,
-                LetterResult::PresentNotHere,
-            ],
-        }),
-        Err(WordleError::InvalidResults)
+    let candidates: Vec<Arc<str>> = vec![Arc::from("aaaa"), Arc::from("cbax"), Arc::from("xxxx")];
+
+    assert_eq!(
+        guesser.select_top_n_guesses_among(2, &candidates),
+        vec![
+            ScoredGuess {
+                score: 9,
+                guess: Arc::from("cbax")
+            },
+            ScoredGuess {
+                score: 3,
+                guess: Arc::from("aaaa")
+            }
+        ]
     );
+    assert_eq!(guesser.select_top_n_guesses(4), scores_before);
+    assert_eq!(guesser.select_top_n_guesses_among(2, &[]), vec![]);
     Ok(())
 }
 
 #[test]
-fn play_game_with_unknown_word_random() -> Result<(), WordleError> {
-    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
-    let guesser = RandomGuesser::new(bank);
+fn max_score_guesser_select_top_n_guesses_with_tiebreak() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcd", "bcda", "cdab", "dabc", "aaaa"])?;
+    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    let mut guesser =
+        MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer).with_tiebreak(|word| {
+            if word.starts_with('c') {
+                2
+            } else if word.starts_with('d') {
+                1
+            } else {
+                0
+            }
+        });
+
+    let top_guesses: Vec<Arc<str>> = guesser
+        .select_top_n_guesses(5)
+        .into_iter()
+        .map(|scored_guess| scored_guess.guess)
+        .collect();
 
+    // Ties are ordered by the tiebreak, then by word order, but the score still comes first.
     assert_eq!(
-        play_game_with_guesser("nope", 10, guesser),
-        GameResult::UnknownWord
+        top_guesses,
+        vec![
+            Arc::from("cdab"),
+            Arc::from("dabc"),
+            Arc::from("abcd"),
+            Arc::from("bcda"),
+            Arc::from("aaaa")
+        ]
     );
     Ok(())
 }
 
 #[test]
-fn play_game_with_known_word_random() -> Result<(), WordleError> {
-    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
-    let guesser = RandomGuesser::new(bank);
+fn max_score_guesser_possible_words_scored() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcd", "abce", "abff", "wxyz", "wxyy"])?;
+    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    guesser.update(&get_result_for_guess("abcd", "wxyz")?)?;
 
-    let result = play_game_with_guesser("abcz", 10, guesser);
-    if let GameResult::Success(data) = result {
-        assert!(data.turns.len() < 10);
-        assert_eq!(
-            data.turns.iter().map(|turn| &turn.guess).last(),
-            Some(&Box::from("abcz"))
-        );
-    } else {
-        panic!("Game failed: {:?}", result);
+    let possible_words_scored = guesser.possible_words_scored();
+
+    assert_eq!(possible_words_scored.len(), 3);
+    for scored_guess in &possible_words_scored {
+        assert!(guesser.possible_words().contains(&scored_guess.guess));
     }
+    assert!(possible_words_scored
+        .windows(2)
+        .all(|pair| pair[0].score >= pair[1].score));
+    // The top guess may not be possible when guessing from all unguessed words.
+    assert_eq!(guesser.select_top_n_guesses(10).len(), 4);
     Ok(())
 }
 
 #[test]
-fn play_game_with_unknown_word_max_eliminations() -> Result<(), WordleError> {
+fn max_score_guesser_possible_words_scored_no_words() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd"])?;
+    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    guesser.update(&get_result_for_guess("xyz", "xyz")?)?;
+
+    assert_eq!(guesser.possible_words_scored(), vec![]);
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_pattern_count() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "abe", "xyz"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+
+    assert_eq!(guesser.pattern_count("abc"), 3);
+    assert_eq!(guesser.pattern_count("cde"), 4);
+
+    guesser.update(&get_result_for_guess("abd", "xyz")?)?;
+
+    // Only the possible words are considered.
+    assert_eq!(guesser.pattern_count("abc"), 2);
+    assert_eq!(guesser.pattern_count("xyz"), 1);
+    assert_eq!(guesser.pattern_count("ab"), 0);
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_determined_answer() -> Result<(), WordleError> {
     let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
-    let scorer = MaxEliminationsScorer::new(bank.clone());
-    let guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+
+    assert!(!guesser.is_determined());
+    assert_eq!(guesser.determined_answer(), None);
+
+    guesser.update(&get_result_for_guess("ghix", "abcz")?)?;
+    assert_eq!(guesser.possible_words().len(), 2);
+    assert!(!guesser.is_determined());
+
+    guesser.update(&get_result_for_guess("ghix", "defy")?)?;
+    assert!(guesser.is_determined());
+    assert_eq!(guesser.determined_answer(), Some(&Arc::from("ghix")));
+    assert_eq!(guesser.select_next_guess(), Some(Arc::from("ghix")));
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_result_distribution() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "abe", "xyz"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+
+    guesser.update(&get_result_for_guess("abd", "xyz")?)?;
+    let distribution = guesser.result_distribution("abc");
 
+    // Only the possible words are considered.
+    assert_eq!(distribution.values().sum::<usize>(), 3);
+    let mut results: Vec<(Vec<LetterResult>, usize)> = distribution
+        .into_iter()
+        .map(|(result, count)| (result.to_results(), count))
+        .collect();
+    results.sort_by_key(|(_, count)| *count);
     assert_eq!(
-        play_game_with_guesser("nope", 10, guesser),
+        results,
+        vec![
+            (vec![LetterResult::Correct; 3], 1),
+            (
+                vec![
+                    LetterResult::Correct,
+                    LetterResult::Correct,
+                    LetterResult::NotPresent
+                ],
+                2
+            ),
+        ]
+    );
+    assert!(guesser.result_distribution("ab").is_empty());
+    Ok(())
+}
+
+#[test]
+fn play_adversarial_game_avoids_guess_until_one_word_left() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "abe", "xyz"])?;
+    let guesser = MaxScoreGuesser::new(
+        GuessFrom::PossibleWords,
+        bank.clone(),
+        MaxEliminationsScorer::new(bank.clone())?,
+    );
+
+    let result = play_adversarial_game(&bank, 6, guesser)?;
+
+    let data = assert_matches!(result, GameResult::Success(data) => data);
+    let num_possible_words: Vec<usize> = data
+        .turns
+        .iter()
+        .map(|turn| turn.num_possible_words_before_guess)
+        .collect();
+    assert_eq!(num_possible_words, vec![4, 2, 1]);
+    Ok(())
+}
+
+#[test]
+fn play_adversarial_game_runs_out_of_guesses() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "abe"])?;
+    let guesser = MaxScoreGuesser::new(
+        GuessFrom::PossibleWords,
+        bank.clone(),
+        MaxEliminationsScorer::new(bank.clone())?,
+    );
+
+    let result = play_adversarial_game(&bank, 2, guesser)?;
+
+    assert_matches!(result, GameResult::Failure(data) if data.turns.len() == 2);
+    Ok(())
+}
+
+#[test]
+fn play_adversarial_game_with_wrong_length_guesser() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "abe"])?;
+    let guesser = RandomGuesser::new(WordBank::from_iterator(vec!["abcd"])?);
+
+    assert_matches!(
+        play_adversarial_game(&bank, 6, guesser)?,
         GameResult::UnknownWord
     );
     Ok(())
 }
 
 #[test]
-fn play_game_with_known_word_max_eliminations() -> Result<(), WordleError> {
-    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
-    let scorer = MaxEliminationsScorer::new(bank.clone());
-    let guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+fn max_score_guesser_new_with_restrictions_matches_update() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defz", "ghiz", "wxyz"])?;
+    let result = get_result_for_guess("weyz", "abcz")?;
+    let mut updated_guesser = MaxScoreGuesser::new(
+        GuessFrom::AllUnguessedWords,
+        bank.clone(),
+        MaxEliminationsScorer::new(bank.clone())?,
+    );
+    updated_guesser.update(&result)?;
 
-    let result = play_game_with_guesser("abcz", 10, guesser);
-    if let GameResult::Success(data) = result {
-        assert!(data.turns.len() < 10);
-        assert_eq!(
-            data.turns.iter().map(|turn| &turn.guess).last(),
-            Some(&Box::from("abcz"))
+    let mut seeded_guesser = MaxScoreGuesser::new_with_restrictions(
+        GuessFrom::AllUnguessedWords,
+        bank.clone(),
+        MaxEliminationsScorer::new(bank.clone())?,
+        WordRestrictions::from_result(&result),
+    )?;
+
+    assert_eq!(
+        seeded_guesser.possible_words(),
+        updated_guesser.possible_words()
+    );
+    // The seeded guesser hasn't guessed "abcz", so it can still guess it.
+    let mut seeded_scores = seeded_guesser.get_or_compute_scores();
+    assert!(seeded_scores.remove("abcz").is_some());
+    assert_eq!(seeded_scores, updated_guesser.get_or_compute_scores());
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_new_with_restrictions_wrong_length() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+
+    let result = MaxScoreGuesser::new_with_restrictions(
+        GuessFrom::PossibleWords,
+        bank.clone(),
+        MaxEliminationsScorer::new(bank)?,
+        WordRestrictions::new(4),
+    );
+
+    assert!(matches!(result, Err(WordleError::InvalidResults)));
+    Ok(())
+}
+
+/// Plays a game where results are marked with the given duplicate mode, and returns the number
+/// of guesses needed to find the objective.
+fn play_game_with_duplicate_mode<G: Guesser>(
+    objective: &str,
+    mode: DuplicateMode,
+    mut guesser: G,
+) -> Result<Option<usize>, WordleError> {
+    for num_guesses in 1..=10 {
+        let guess = match guesser.select_next_guess() {
+            Some(guess) => guess,
+            None => return Ok(None),
+        };
+        if guess.as_ref() == objective {
+            return Ok(Some(num_guesses));
+        }
+        guesser.update(&get_result_for_guess_with(objective, &guess, mode)?)?;
+    }
+    Ok(None)
+}
+
+#[test]
+fn guessers_solve_simple_duplicate_mode_games() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec![
+        "aaxa", "abcd", "abca", "aabb", "bbaa", "baba", "cccc", "dcba", "abba", "xaxa",
+    ])?;
+
+    for objective in bank.iter() {
+        let max_score_guesser = MaxScoreGuesser::new(
+            GuessFrom::AllUnguessedWords,
+            bank.clone(),
+            MaxEliminationsScorer::new(bank.clone())?,
+        )
+        .with_duplicate_mode(DuplicateMode::Simple);
+        assert!(
+            play_game_with_duplicate_mode(objective, DuplicateMode::Simple, max_score_guesser)?
+                .is_some(),
+            "failed to solve {objective}"
+        );
+
+        let random_guesser = RandomGuesser::new(bank.clone())
+            .with_seed(1)
+            .with_duplicate_mode(DuplicateMode::Simple);
+        assert!(
+            play_game_with_duplicate_mode(objective, DuplicateMode::Simple, random_guesser)?
+                .is_some(),
+            "failed to solve {objective}"
         );
-    } else {
-        panic!("Game failed: {:?}", result);
     }
     Ok(())
 }
 
 #[test]
-fn play_game_takes_too_many_guesses() -> Result<(), WordleError> {
-    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
-    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
-    let guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+fn max_score_guesser_standard_mode_misreads_simple_results() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["aaxa", "abcd", "aazz"])?;
+    let result = get_result_for_guess_with("abcd", "aaxa", DuplicateMode::Simple)?;
 
-    let result = play_game_with_guesser("abcz", 1, guesser);
-    if let GameResult::Failure(data) = result {
-        assert_eq!(data.turns.len(), 1);
-        assert!(!data
-            .turns
-            .iter()
-            .map(|turn| &turn.guess)
-            .any(|guess| guess == &Box::from("abcz")));
-    } else {
-        panic!("Game succeeded unexpectedly: {:?}", result);
+    let mut simple_guesser = MaxScoreGuesser::new(
+        GuessFrom::PossibleWords,
+        bank.clone(),
+        MaxEliminationsScorer::new(bank.clone())?,
+    )
+    .with_duplicate_mode(DuplicateMode::Simple);
+    simple_guesser.update(&result)?;
+    assert_eq!(simple_guesser.possible_words(), &[Arc::from("abcd")]);
+
+    let mut standard_guesser = MaxScoreGuesser::new(
+        GuessFrom::PossibleWords,
+        bank.clone(),
+        MaxEliminationsScorer::new(bank)?,
+    );
+    assert!(standard_guesser.update(&result).is_err());
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_merge_restrictions_matches_update() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defz", "ghiz", "wxyz"])?;
+    let first_result = get_result_for_guess("wxyz", "abcz")?;
+    let second_result = get_result_for_guess("wxyz", "weyz")?;
+    let mut updated_guesser = MaxScoreGuesser::new(
+        GuessFrom::PossibleWords,
+        bank.clone(),
+        MaxEliminationsScorer::new(bank.clone())?,
+    );
+    updated_guesser.update(&first_result)?;
+    updated_guesser.update(&second_result)?;
+
+    let mut merged_guesser = MaxScoreGuesser::new(
+        GuessFrom::PossibleWords,
+        bank.clone(),
+        MaxEliminationsScorer::new(bank.clone())?,
+    );
+    merged_guesser.update(&first_result)?;
+    // Compute the scores first, so the merge has to invalidate them.
+    merged_guesser.get_or_compute_scores();
+    merged_guesser.merge_restrictions(&WordRestrictions::from_result(&second_result))?;
+
+    assert_eq!(
+        merged_guesser.possible_words(),
+        updated_guesser.possible_words()
+    );
+    assert_eq!(merged_guesser.possible_words(), &[Arc::from("wxyz")]);
+    assert_eq!(
+        merged_guesser.get_or_compute_scores(),
+        updated_guesser.get_or_compute_scores()
+    );
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_merge_restrictions_conflict_leaves_guesser_unchanged(
+) -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd", "bce"])?;
+    let mut guesser = MaxScoreGuesser::new(
+        GuessFrom::AllUnguessedWords,
+        bank.clone(),
+        MaxEliminationsScorer::new(bank)?,
+    );
+    guesser.update(&get_result_for_guess("bce", "abc")?)?;
+    let possible_words = guesser.possible_words().to_vec();
+    let scores = guesser.get_or_compute_scores();
+
+    // This says 'a' is in the word, but the first result says it isn't.
+    let conflicting = WordRestrictions::from_result(&get_result_for_guess("abd", "abd")?);
+
+    assert_matches!(
+        guesser.merge_restrictions(&conflicting),
+        Err(WordleError::ContradictoryResults { .. })
+    );
+    assert_eq!(
+        guesser.merge_restrictions(&WordRestrictions::new(4)),
+        Err(WordleError::InvalidResults)
+    );
+    assert_eq!(guesser.possible_words(), &possible_words[..]);
+    assert_eq!(guesser.get_or_compute_scores(), scores);
+    Ok(())
+}
+
+/// Fails to update without a guess.
+#[derive(Clone)]
+struct RequiresGuessScorer;
+
+impl WordScorer for RequiresGuessScorer {
+    fn update(
+        &mut self,
+        latest_guess: &str,
+        _restrictions: &details::WordRestrictions,
+        _possible_words: &[Arc<str>],
+    ) -> Result<(), WordleError> {
+        if latest_guess.is_empty() {
+            return Err(WordleError::InvalidResults);
+        }
+        Ok(())
+    }
+
+    fn score_word(&self, _word: &Arc<str>) -> i64 {
+        0
     }
+}
+
+#[test]
+fn max_score_guesser_merge_restrictions_scorer_error_leaves_guesser_unchanged(
+) -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd", "bce"])?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, RequiresGuessScorer);
+    guesser.update(&get_result_for_guess("bce", "abc")?)?;
+    let possible_words = guesser.possible_words().to_vec();
+
+    assert_eq!(
+        guesser.merge_restrictions(&WordRestrictions::from_result(&get_result_for_guess(
+            "bce", "bcd"
+        )?)),
+        Err(WordleError::InvalidResults)
+    );
+    assert_eq!(guesser.possible_words(), &possible_words[..]);
     Ok(())
 }
+
+#[test]
+fn max_score_guesser_scores_sorted() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["zzz", "ccc", "abc", "aaa", "bbb"])?;
+    let mut guesser = MaxScoreGuesser::new(
+        GuessFrom::AllUnguessedWords,
+        bank.clone(),
+        MaxUniqueLetterFrequencyScorer::new(&bank),
+    );
+
+    let scores = guesser.get_or_compute_scores();
+    let sorted = guesser.scores_sorted();
+
+    let ordered_guesses: Vec<&str> = sorted
+        .iter()
+        .map(|scored_guess| scored_guess.guess.as_ref())
+        .collect();
+    assert_eq!(ordered_guesses, vec!["abc", "aaa", "bbb", "ccc", "zzz"]);
+    assert_eq!(sorted.len(), scores.len());
+    for scored_guess in &sorted {
+        assert_eq!(scores.get(&scored_guess.guess), Some(&scored_guess.score));
+    }
+    Ok(())
+}
+
+#[test]
+fn cloneable_guesser_clones_are_independent() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd", "xyz"])?;
+    let guessers: Vec<Box<dyn CloneableGuesser>> = vec![
+        Box::new(RandomGuesser::new(bank.clone()).with_seed(1)),
+        Box::new(MaxScoreGuesser::new(
+            GuessFrom::AllUnguessedWords,
+            bank.clone(),
+            MaxEliminationsScorer::new(bank.clone())?,
+        )),
+    ];
+
+    for base_guesser in &guessers {
+        let mut guesser = base_guesser.clone();
+        guesser.update(&get_result_for_guess("xyz", "abc")?)?;
+
+        assert_eq!(guesser.possible_words(), &[Arc::from("xyz")]);
+        assert_eq!(base_guesser.possible_words().len(), 4);
+        let result = play_game_with_guesser("bcd", 4, base_guesser.clone())?;
+        assert!(result.is_success());
+        assert_eq!(
+            play_game_with_guesser("bcd", 4, base_guesser.box_clone())?,
+            result
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_select_top_n_guesses_detailed() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd", "xyz"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer.clone());
+
+    let detailed = guesser.select_top_n_guesses_detailed(3);
+    let scored = guesser.select_top_n_guesses(3);
+
+    assert_eq!(detailed.len(), scored.len());
+    for (detailed_guess, scored_guess) in detailed.iter().zip(scored.iter()) {
+        assert_eq!(detailed_guess.guess, scored_guess.guess);
+        assert_eq!(detailed_guess.score, scored_guess.score);
+        assert_eq!(
+            detailed_guess.explanation,
+            scorer.explain(&scored_guess.guess)
+        );
+        assert!(detailed_guess.explanation.is_some());
+    }
+    Ok(())
+}
+
+#[test]
+fn random_guesser_guess_history() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+    let mut guesser = RandomGuesser::new(bank);
+
+    assert!(guesser.guess_history().is_empty());
+
+    guesser.update(&get_result_for_guess("ghix", "weyz")?)?;
+    guesser.update(&get_result_for_guess("ghix", "qrst")?)?;
+
+    assert_eq!(
+        guesser.guess_history(),
+        &[Arc::from("weyz"), Arc::from("qrst")]
+    );
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_guess_history() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+
+    let guess = guesser.select_next_guess().unwrap();
+    guesser.update(&get_result_for_guess("ghix", &guess)?)?;
+    // Forced guesses are only recorded once their result is known.
+    guesser.guess_word("defy")?;
+    assert_eq!(guesser.guess_history(), &[Arc::clone(&guess)]);
+
+    guesser.update(&get_result_for_guess("ghix", "defy")?)?;
+    // Failed updates aren't recorded.
+    assert!(guesser
+        .update(&GuessResult {
+            guess: "abc",
+            results: vec![LetterResult::NotPresent; 3],
+        })
+        .is_err());
+
+    assert_eq!(guesser.guess_history(), &[guess, Arc::from("defy")]);
+    let boxed_guesser: Box<dyn Guesser> = Box::new(guesser.clone());
+    assert_eq!(boxed_guesser.guess_history(), guesser.guess_history());
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_update_returning_eliminated() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+
+    let mut eliminated =
+        guesser.update_returning_eliminated(&get_result_for_guess("defy", "weyz")?)?;
+    eliminated.sort();
+
+    assert_eq!(
+        eliminated,
+        vec![Arc::from("abcz"), Arc::from("ghix"), Arc::from("weyz")]
+    );
+    assert_eq!(guesser.possible_words(), &[Arc::from("defy")]);
+    // Nothing else can be eliminated.
+    assert_eq!(
+        guesser.update_returning_eliminated(&get_result_for_guess("defy", "abcz")?)?,
+        Vec::<Arc<str>>::new()
+    );
+    Ok(())
+}
+
+#[test]
+fn default_update_returning_eliminated() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+    let mut guesser = SlowGuesser {
+        guesser: RandomGuesser::new(bank),
+        delay: Duration::ZERO,
+    };
+
+    assert_eq!(
+        guesser.update_returning_eliminated(&get_result_for_guess("cde", "abc")?)?,
+        vec![Arc::from("abc"), Arc::from("bcd")]
+    );
+    assert_eq!(guesser.possible_words(), &[Arc::from("cde")]);
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_update_partial() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "bacz", "cbay", "defy"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+
+    // Unsure whether the "a" was green or yellow, and whether the "z" was grey or yellow.
+    guesser.update_partial(&PartialGuessResult {
+        guess: "axxz",
+        results: vec![
+            PartialLetterResult::PresentMaybeHere,
+            PartialLetterResult::NotPresent,
+            PartialLetterResult::NotPresent,
+            PartialLetterResult::Unknown,
+        ],
+    })?;
+
+    assert_eq!(
+        guesser.possible_words(),
+        &[Arc::from("abcz"), Arc::from("bacz"), Arc::from("cbay")]
+    );
+    assert_eq!(guesser.guess_history(), &[Arc::from("axxz")]);
+    assert_ne!(guesser.select_next_guess().unwrap().as_ref(), "defy");
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_last_update_summary() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "bacz", "cbay", "defy"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser: Box<dyn Guesser> = Box::new(MaxScoreGuesser::new(
+        GuessFrom::AllUnguessedWords,
+        bank,
+        scorer,
+    ));
+    assert!(guesser.last_update_summary().is_empty());
+
+    guesser.update(&get_result_for_guess("bacz", "abcy")?)?;
+    assert_eq!(
+        guesser.last_update_summary(),
+        UpdateSummary {
+            newly_present: vec!['a', 'b', 'c'],
+            newly_placed: vec![details::LocatedLetter::new('c', 2)],
+            newly_absent: vec!['y'],
+        }
+    );
+
+    // Repeating the same result teaches nothing new.
+    guesser.update(&get_result_for_guess("bacz", "abcy")?)?;
+    assert!(guesser.last_update_summary().is_empty());
+
+    guesser.update_partial(&PartialGuessResult {
+        guess: "bacy",
+        results: vec![
+            PartialLetterResult::Correct,
+            PartialLetterResult::Correct,
+            PartialLetterResult::Correct,
+            PartialLetterResult::Unknown,
+        ],
+    })?;
+    assert_eq!(
+        guesser.last_update_summary(),
+        UpdateSummary {
+            newly_present: vec![],
+            newly_placed: vec![
+                details::LocatedLetter::new('b', 0),
+                details::LocatedLetter::new('a', 1)
+            ],
+            newly_absent: vec![],
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn default_last_update_summary_is_empty() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+    let mut guesser = SlowGuesser {
+        guesser: RandomGuesser::new(bank),
+        delay: Duration::ZERO,
+    };
+
+    guesser.update(&get_result_for_guess("cde", "abc")?)?;
+
+    assert_eq!(guesser.last_update_summary(), UpdateSummary::default());
+    Ok(())
+}
+
+/// Scores words as if their expected eliminations only differ in the fourth decimal place.
+#[derive(Clone)]
+struct FractionalScorer;
+
+impl WordScorer for FractionalScorer {
+    fn update(
+        &mut self,
+        _latest_guess: &str,
+        _restrictions: &details::WordRestrictions,
+        _possible_words: &[Arc<str>],
+    ) -> Result<(), WordleError> {
+        Ok(())
+    }
+
+    fn score_word(&self, word: &Arc<str>) -> i64 {
+        self.score_word_precise(word) as i64
+    }
+
+    fn score_word_precise(&self, word: &Arc<str>) -> f64 {
+        let expected_eliminations = match word.as_ref() {
+            "efgh" => 2.0004,
+            _ => 2.0001,
+        };
+        expected_eliminations * 1000.0
+    }
+}
+
+#[test]
+fn max_score_guesser_precise_ranking() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcd", "efgh", "ijkl"])?;
+    let mut guesser =
+        MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), FractionalScorer);
+    let mut precise_guesser =
+        MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, FractionalScorer)
+            .with_precise_ranking(true);
+
+    // The integer scores tie, so the first word is chosen.
+    assert_eq!(guesser.select_next_guess(), Some(Arc::from("abcd")));
+    assert_eq!(precise_guesser.select_next_guess(), Some(Arc::from("efgh")));
+    assert_eq!(
+        precise_guesser.select_top_n_guesses(2),
+        vec![
+            ScoredGuess {
+                score: 2000,
+                guess: Arc::from("efgh"),
+            },
+            ScoredGuess {
+                score: 2000,
+                guess: Arc::from("abcd"),
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_repeated_letter_penalty() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["aabc", "dabc", "aacd"])?;
+    let mut guesser =
+        MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, ConstantScorer::new(10))
+            .with_repeated_letter_penalty(3);
+
+    assert_eq!(
+        guesser.select_top_n_guesses(3),
+        vec![
+            ScoredGuess {
+                score: 10,
+                guess: Arc::from("dabc"),
+            },
+            ScoredGuess {
+                score: 7,
+                guess: Arc::from("aabc"),
+            },
+            ScoredGuess {
+                score: 7,
+                guess: Arc::from("aacd"),
+            },
+        ]
+    );
+    assert_eq!(guesser.select_next_guess(), Some(Arc::from("dabc")));
+
+    // Once 'a' is known to be present, repeating it is no longer penalised.
+    guesser.update(&GuessResult {
+        guess: "axyz",
+        results: vec![
+            LetterResult::Correct,
+            LetterResult::NotPresent,
+            LetterResult::NotPresent,
+            LetterResult::NotPresent,
+        ],
+    })?;
+    assert_eq!(
+        guesser.select_top_n_guesses(2),
+        vec![
+            ScoredGuess {
+                score: 10,
+                guess: Arc::from("aabc"),
+            },
+            ScoredGuess {
+                score: 10,
+                guess: Arc::from("aacd"),
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_hard_mode_candidates() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "wxyz"])?;
+    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+
+    assert_eq!(guesser.hard_mode_candidates().len(), 5);
+
+    guesser.update(&GuessResult {
+        guess: "weyz",
+        results: vec![
+            LetterResult::Correct,
+            LetterResult::NotPresent,
+            LetterResult::Correct,
+            LetterResult::Correct,
+        ],
+    })?;
+
+    assert_eq!(guesser.hard_mode_candidates(), vec![&Arc::from("wxyz")]);
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_reguess_would_win() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer)
+        .with_reguesses_allowed(true);
+    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    let mut default_guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    let result = GuessResult {
+        guess: "weyz",
+        results: vec![LetterResult::Correct; 4],
+    };
+
+    assert_eq!(guesser.reguess_would_win(), None);
+
+    guesser.update(&result)?;
+    default_guesser.update(&result)?;
+
+    assert_eq!(guesser.reguess_would_win(), Some(Arc::from("weyz")));
+    assert_eq!(guesser.select_next_guess(), Some(Arc::from("weyz")));
+    assert_eq!(default_guesser.reguess_would_win(), None);
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_reguesses_allowed_scores_guessed_words() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "wxyz"])?;
+    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer)
+        .with_reguesses_allowed(true);
+
+    guesser.update(&GuessResult {
+        guess: "ghix",
+        results: vec![LetterResult::NotPresent; 4],
+    })?;
+
+    let guesses: Vec<Arc<str>> = guesser
+        .select_top_n_guesses(5)
+        .into_iter()
+        .map(|scored_guess| scored_guess.guess)
+        .collect();
+    assert!(guesses.contains(&Arc::from("ghix")));
+    assert_eq!(guesser.reguess_would_win(), None);
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_precompute_second_guess_table() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "wxyz", "zxyc"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+    guesser.compute_scores_if_unknown();
+    let first_guess = guesser.select_next_guess().unwrap();
+
+    let table = guesser.precompute_second_guess_table();
+
+    assert!(!table.is_empty());
+    for objective in bank.iter().filter(|word| **word != first_guess) {
+        let result = get_result_for_guess(objective, &first_guess)?;
+        let mut updated_guesser = guesser.clone();
+        updated_guesser.update(&result)?;
+
+        assert_eq!(
+            table.get(&details::CompressedGuessResult::from_results(
+                &result.results
+            )?),
+            updated_guesser.select_next_guess().as_ref()
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_hard_mode() -> Result<(), WordleError> {
+    let bank =
+        WordBank::from_iterator(vec!["abcd", "abce", "abcf", "abcg", "defg", "aefg", "zzzz"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut hard_mode_guesser =
+        MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer).with_hard_mode(true);
+    let result = GuessResult {
+        guess: "abcd",
+        results: vec![
+            LetterResult::Correct,
+            LetterResult::Correct,
+            LetterResult::Correct,
+            LetterResult::NotPresent,
+        ],
+    };
+
+    guesser.update(&result)?;
+    hard_mode_guesser.update(&result)?;
+
+    assert_eq!(guesser.select_next_guess(), Some(Arc::from("defg")));
+    let hard_mode_guess = hard_mode_guesser.select_next_guess().unwrap();
+    assert!(hard_mode_guess.starts_with("abc"));
+    let top_guesses = hard_mode_guesser.select_top_n_guesses(10);
+    assert_eq!(top_guesses.len(), 3);
+    for scored_guess in top_guesses {
+        assert!(scored_guess.guess.starts_with("abc"));
+        assert_eq!(
+            Some(&scored_guess.score),
+            guesser.get_or_compute_scores().get(&scored_guess.guess)
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_sample_possible_answer() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    guesser.update(&GuessResult {
+        guess: "ghix",
+        results: vec![
+            LetterResult::NotPresent,
+            LetterResult::NotPresent,
+            LetterResult::NotPresent,
+            LetterResult::NotPresent,
+        ],
+    })?;
+
+    for _ in 0..20 {
+        let answer = guesser.sample_possible_answer(&mut rng);
+        assert_matches!(answer.as_deref(), Some("abcz" | "weyz" | "defy"));
+    }
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_sample_possible_answer_no_words() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(Vec::<Arc<str>>::new())?;
+    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    let guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+    let mut rng = StdRng::seed_from_u64(42);
+
+    assert_eq!(guesser.sample_possible_answer(&mut rng), None);
+    Ok(())
+}
+
+/// Wraps a scorer to force full recomputation of scores after every update.
+#[derive(Clone)]
+struct NonIncrementalScorer<T: WordScorer>(T);
+
+impl<T: WordScorer> WordScorer for NonIncrementalScorer<T> {
+    fn update(
+        &mut self,
+        latest_guess: &str,
+        restrictions: &details::WordRestrictions,
+        possible_words: &[Arc<str>],
+    ) -> Result<(), WordleError> {
+        self.0.update(latest_guess, restrictions, possible_words)
+    }
+
+    fn score_word(&self, word: &Arc<str>) -> i64 {
+        self.0.score_word(word)
+    }
+}
+
+#[test]
+fn max_score_guesser_incremental_scores_match_full_recomputation() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "jklm"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    assert!(scorer.is_incremental());
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+    let mut full_guesser = MaxScoreGuesser::new(
+        GuessFrom::AllUnguessedWords,
+        bank.clone(),
+        NonIncrementalScorer(MaxEliminationsScorer::new(bank)?),
+    );
+    guesser.compute_scores_if_unknown();
+    guesser.compute_scores_if_needed_from(GuessFrom::PossibleWords);
+
+    // This doesn't eliminate any words, so the scores can be reused.
+    let no_eliminations = GuessResult {
+        guess: "nopq",
+        results: vec![LetterResult::NotPresent; 4],
+    };
+    guesser.update(&no_eliminations)?;
+    full_guesser.update(&no_eliminations)?;
+
+    assert_eq!(
+        guesser.get_or_compute_scores(),
+        full_guesser.get_or_compute_scores()
+    );
+    assert_eq!(
+        guesser.select_top_n_guesses_from(5, GuessFrom::PossibleWords),
+        full_guesser.select_top_n_guesses_from(5, GuessFrom::PossibleWords)
+    );
+
+    // This eliminates words, so the scores must be recomputed.
+    let some_eliminations = GuessResult {
+        guess: "ghix",
+        results: vec![LetterResult::NotPresent; 4],
+    };
+    guesser.update(&some_eliminations)?;
+    full_guesser.update(&some_eliminations)?;
+
+    assert_eq!(
+        guesser.get_or_compute_scores(),
+        full_guesser.get_or_compute_scores()
+    );
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_invalid_update_fails() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+
+    guesser.update(&GuessResult {
+        guess: "abc",
+        results: vec![
+            LetterResult::NotPresent,
+            LetterResult::NotPresent,
+            LetterResult::NotPresent,
+        ],
+    })?;
+    assert_matches!(
+        guesser.update(&GuessResult {
+            guess: "bcd",
+            results: vec![
+                LetterResult::NotPresent,
+                // "c" can't be present, because we just said it's not present.
+                LetterResult::PresentNotHere,
+                LetterResult::PresentNotHere,
+            ],
+        }),
+        Err(WordleError::ContradictoryResults {
+            letter: 'c',
+            location: Some(1),
+            ..
+        })
+    );
+    Ok(())
+}
+
+#[test]
+fn random_guesser_update_wrong_length_fails() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcde", "bcdef", "cdefg"])?;
+    let mut guesser = RandomGuesser::new(bank);
+
+    assert_matches!(
+        guesser.update(&GuessResult {
+            guess: "abcd",
+            results: vec![LetterResult::NotPresent; 4],
+        }),
+        Err(WordleError::WordLength(5))
+    );
+    assert_eq!(guesser.possible_words().len(), 3);
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_update_wrong_length_fails() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcde", "bcdef", "cdefg"])?;
+    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+
+    assert_matches!(
+        guesser.update(&GuessResult {
+            guess: "abcde",
+            results: vec![LetterResult::NotPresent; 4],
+        }),
+        Err(WordleError::WordLength(5))
+    );
+    assert_eq!(guesser.possible_words().len(), 3);
+    Ok(())
+}
+
+#[test]
+fn play_game_with_unknown_word_random() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+    let guesser = RandomGuesser::new(bank);
+
+    assert_eq!(
+        play_game_with_guesser("nope", 10, guesser)?,
+        GameResult::UnknownWord
+    );
+    Ok(())
+}
+
+#[test]
+fn play_game_with_known_word_random() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+    let guesser = RandomGuesser::new(bank);
+
+    let result = play_game_with_guesser("abcz", 10, guesser)?;
+    if let GameResult::Success(data) = result {
+        assert!(data.turns.len() < 10);
+        assert_eq!(
+            data.turns.iter().map(|turn| &turn.guess).next_back(),
+            Some(&Box::from("abcz"))
+        );
+    } else {
+        panic!("Game failed: {:?}", result);
+    }
+    Ok(())
+}
+
+#[test]
+fn play_game_with_unknown_word_max_eliminations() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+
+    assert_eq!(
+        play_game_with_guesser("nope", 10, guesser)?,
+        GameResult::UnknownWord
+    );
+    Ok(())
+}
+
+#[test]
+fn play_game_with_known_word_max_eliminations() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+
+    let result = play_game_with_guesser("abcz", 10, guesser)?;
+    if let GameResult::Success(data) = result {
+        assert!(data.turns.len() < 10);
+        assert_eq!(
+            data.turns.iter().map(|turn| &turn.guess).next_back(),
+            Some(&Box::from("abcz"))
+        );
+    } else {
+        panic!("Game failed: {:?}", result);
+    }
+    Ok(())
+}
+
+#[test]
+fn play_game_takes_too_many_guesses() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+    let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+    let guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+
+    let result = play_game_with_guesser("abcz", 1, guesser)?;
+    if let GameResult::Failure(data) = result {
+        assert_eq!(data.turns.len(), 1);
+        assert!(!data
+            .turns
+            .iter()
+            .map(|turn| &turn.guess)
+            .any(|guess| guess == &Box::from("abcz")));
+    } else {
+        panic!("Game succeeded unexpectedly: {:?}", result);
+    }
+    Ok(())
+}
+
+#[test]
+fn verify_path_valid() -> Result<(), WordleError> {
+    assert!(verify_path("abcz", &["abcz"])?);
+    assert!(verify_path("abcz", &["weyz", "defy", "abcz"])?);
+    Ok(())
+}
+
+#[test]
+fn verify_path_invalid() -> Result<(), WordleError> {
+    // Doesn't end on the objective.
+    assert!(!verify_path("abcz", &["weyz", "defy"])?);
+    // Keeps guessing after finding the objective.
+    assert!(!verify_path("abcz", &["abcz", "defy"])?);
+    // No guesses.
+    assert!(!verify_path("abcz", &[])?);
+    // Mismatched guess length.
+    assert_matches!(
+        verify_path("abcz", &["weyz", "abc"]),
+        Err(WordleError::WordLength(4))
+    );
+    Ok(())
+}
+
+#[test]
+fn suggest_with_reason_no_words() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(Vec::<Arc<str>>::new())?;
+    let mut guesser = RandomGuesser::new(bank);
+
+    assert_eq!(suggest_with_reason(&mut guesser), None);
+    Ok(())
+}
+
+#[test]
+fn suggest_with_reason_one_possibility() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["cod", "wod", "mod", "cat"])?;
+    let mut guesser = RandomGuesser::new(bank);
+    guesser.update(&get_result_for_guess("cat", "cod")?)?;
+
+    assert_eq!(
+        suggest_with_reason(&mut guesser),
+        Some((
+            Arc::from("cat"),
+            "the only remaining possibility".to_string()
+        ))
+    );
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_words_too_long_to_compress() -> Result<(), WordleError> {
+    let too_long =
+        |letter: &str| letter.repeat(details::MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT + 1);
+    let bank = WordBank::from_iterator(vec![too_long("a"), too_long("b")])?;
+    let mut guesser = MaxScoreGuesser::new(
+        GuessFrom::AllUnguessedWords,
+        bank.clone(),
+        LocatedLettersScorer::new(&bank),
+    );
+
+    assert_eq!(guesser.pattern_count(&too_long("a")), 0);
+    assert!(guesser.result_distribution(&too_long("a")).is_empty());
+    assert!(guesser.precompute_second_guess_table().is_empty());
+    let (_, reason) = suggest_with_reason(&mut guesser).unwrap();
+    assert!(reason.starts_with("narrows 2 possibilities; "));
+    Ok(())
+}
+
+#[test]
+fn suggest_with_reason_skips_known_letters() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["cod", "wod", "mod", "cat"])?;
+    let mut guesser = RandomGuesser::new(bank).with_seed(1);
+    guesser.update(&get_result_for_guess("wod", "cat")?)?;
+
+    let (guess, reason) = suggest_with_reason(&mut guesser).unwrap();
+
+    // "o" and "d" are in every possible word, so only the first letter is tested.
+    let first_letter = guess.chars().next().unwrap();
+    assert_eq!(
+        reason,
+        format!(
+            "narrows 2 possibilities to ~1.0 on average; could be the answer; tests letters \
+             {first_letter}"
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn strategy_depth_distribution_sums_to_bank_size() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "jklm"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+
+    let distribution = strategy_depth_distribution(&bank, &guesser);
+
+    assert_eq!(distribution.values().sum::<usize>(), bank.len());
+    assert_eq!(distribution.get(&1), Some(&1));
+    Ok(())
+}
+
+#[test]
+fn strategy_depth_distribution_random() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+    let guesser = RandomGuesser::new(bank.clone());
+
+    let distribution = strategy_depth_distribution(&bank, &guesser);
+
+    assert_eq!(distribution.values().sum::<usize>(), bank.len());
+    assert!(distribution.keys().all(|num_guesses| *num_guesses <= 4));
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_solves_multi_byte_words() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec![
+        "añejo", "araña", "cañón", "niños", "peñas", "piñas", "sueño", "uñero",
+    ])?;
+    let scorer = LocatedLettersScorer::new(&bank);
+    let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+
+    for word in bank.iter() {
+        assert_matches!(
+            play_game_with_guesser(word, bank.len() as u32, guesser.clone())?,
+            GameResult::Success(_)
+        );
+    }
+    Ok(())
+}
+
+/// Wraps a guesser to make each guess slow.
+struct SlowGuesser<G: Guesser> {
+    guesser: G,
+    delay: Duration,
+}
+
+impl<G: Guesser> Guesser for SlowGuesser<G> {
+    fn update(&mut self, result: &GuessResult) -> Result<(), WordleError> {
+        self.guesser.update(result)
+    }
+
+    fn select_next_guess(&mut self) -> Option<Arc<str>> {
+        std::thread::sleep(self.delay);
+        self.guesser.select_next_guess()
+    }
+
+    fn select_next_guess_from(&mut self, from: GuessFrom) -> Option<Arc<str>> {
+        std::thread::sleep(self.delay);
+        self.guesser.select_next_guess_from(from)
+    }
+
+    fn possible_words(&self) -> &[Arc<str>] {
+        self.guesser.possible_words()
+    }
+}
+
+#[test]
+fn play_game_with_guesser_timed_succeeds_within_budget() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+    let guesser = RandomGuesser::new(bank);
+
+    assert_matches!(
+        play_game_with_guesser_timed("bcd", 3, Duration::from_secs(60), guesser)?,
+        GameResult::Success(_)
+    );
+    Ok(())
+}
+
+#[test]
+fn play_game_with_guesser_timed_times_out() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+    let guesser = SlowGuesser {
+        guesser: RandomGuesser::new(bank),
+        delay: Duration::from_millis(10),
+    };
+
+    let result = play_game_with_guesser_timed("bcd", 3, Duration::from_millis(1), guesser)?;
+
+    assert_eq!(result, GameResult::TimedOut(GameData { turns: Vec::new() }));
+    Ok(())
+}
+
+#[test]
+fn default_update_partial_requires_full_results() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+    let mut guesser = SlowGuesser {
+        guesser: RandomGuesser::new(bank),
+        delay: Duration::ZERO,
+    };
+
+    assert_eq!(
+        guesser.update_partial(&PartialGuessResult {
+            guess: "abc",
+            results: vec![
+                PartialLetterResult::PresentMaybeHere,
+                PartialLetterResult::NotPresent,
+                PartialLetterResult::NotPresent,
+            ],
+        }),
+        Err(WordleError::InvalidResults)
+    );
+    guesser.update_partial(&PartialGuessResult::from(get_result_for_guess(
+        "cde", "abc",
+    )?))?;
+    assert_eq!(guesser.possible_words(), &[Arc::from("cde")]);
+    Ok(())
+}
+
+struct FailingGuesser<G: Guesser> {
+    guesser: G,
+}
+
+impl<G: Guesser> Guesser for FailingGuesser<G> {
+    fn update(&mut self, _result: &GuessResult) -> Result<(), WordleError> {
+        Err(WordleError::InvalidResults)
+    }
+
+    fn select_next_guess(&mut self) -> Option<Arc<str>> {
+        self.guesser.select_next_guess()
+    }
+
+    fn select_next_guess_from(&mut self, from: GuessFrom) -> Option<Arc<str>> {
+        self.guesser.select_next_guess_from(from)
+    }
+
+    fn possible_words(&self) -> &[Arc<str>] {
+        self.guesser.possible_words()
+    }
+}
+
+#[test]
+fn play_game_with_guesser_returns_update_error() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+    let guesser = FailingGuesser {
+        guesser: RandomGuesser::new(bank).with_seed(1),
+    };
+    let first_guess = guesser.guesser.clone().select_next_guess().unwrap();
+    let other_word = if first_guess.as_ref() == "abc" {
+        "bcd"
+    } else {
+        "abc"
+    };
+
+    assert_matches!(
+        play_game_with_guesser(other_word, 3, guesser),
+        Err(WordleError::InvalidResults)
+    );
+    Ok(())
+}
+
+#[test]
+fn play_game_with_guesser_unknown_word_is_not_an_error() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+    let guesser = FailingGuesser {
+        guesser: RandomGuesser::new(bank),
+    };
+
+    assert_matches!(
+        play_game_with_guesser("other", 3, guesser),
+        Ok(GameResult::UnknownWord)
+    );
+    Ok(())
+}
+
+#[test]
+fn solve_all_matches_individual_games() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "jklm"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+    let objectives = ["jklm", "abcz", "zzzz", "ghix"];
+
+    let results = solve_all(&objectives, 4, &guesser)?;
+
+    assert_eq!(results.len(), objectives.len());
+    for (objective, result) in objectives.iter().zip(results) {
+        assert_eq!(
+            result,
+            play_game_with_guesser(objective, 4, guesser.clone())?
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn solve_all_reuses_precomputed_scores() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "jklm"])?;
+    let num_scored = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&num_scored);
+    let scorer = FnScorer::new(
+        &bank,
+        move |word: &Arc<str>, _: &WordRestrictions, _: &[Arc<str>]| {
+            counter.fetch_add(1, AtomicOrdering::Relaxed);
+            word.chars().filter(|letter| *letter == 'z').count() as i64
+        },
+    );
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+    guesser.compute_scores_if_unknown();
+    let num_scored_for_first_guess = num_scored.load(AtomicOrdering::Relaxed);
+    assert_eq!(num_scored_for_first_guess, bank.len());
+
+    // Every objective is the first guess, so no more scores are needed.
+    let results = solve_all(&["abcz", "abcz", "abcz"], 4, &guesser)?;
+
+    assert!(results.iter().all(GameResult::is_success));
+    assert_eq!(
+        num_scored.load(AtomicOrdering::Relaxed),
+        num_scored_for_first_guess
+    );
+    Ok(())
+}
+
+#[test]
+fn evaluate_strategy_matches_individual_games() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "jklm"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+    let answers = ["jklm", "abcz", "zzzz", "ghix", "weyz", "defy"];
+
+    let stats = evaluate_strategy(&answers, &guesser, 4);
+
+    let mut expected = GameStats::default();
+    for answer in answers {
+        expected.add_result(&play_game_with_guesser(answer, 4, guesser.clone())?);
+    }
+    assert_eq!(stats, expected);
+    assert_eq!(stats.num_games(), 6);
+    assert_eq!(stats.num_unsolved, 1);
+    Ok(())
+}
+
+#[test]
+fn evaluate_strategy_counts_games_over_max_guesses_as_unsolved() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "jklm"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+
+    // Every game makes the same first guess, so only one game is solved.
+    let stats = evaluate_strategy(&bank, &guesser, 1);
+
+    assert_eq!(stats.num_games(), 5);
+    assert_eq!(stats.worst_case_guesses(), Some(1));
+    assert_eq!(stats.num_solved(), 1);
+    Ok(())
+}
+
+#[test]
+fn evaluate_strategy_no_answers() {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd"]).unwrap();
+    let guesser = RandomGuesser::new(bank);
+
+    let stats = evaluate_strategy::<_, &str>(&[], &guesser, 6);
+
+    assert_eq!(stats, GameStats::default());
+    assert_eq!(stats.worst_case_guesses(), None);
+}
+
+#[test]
+fn run_benchmark_streaming_sends_each_result() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+    let guesser = MaxScoreGuesser::new(
+        GuessFrom::AllUnguessedWords,
+        bank.clone(),
+        MaxEliminationsScorer::new(bank.clone())?,
+    );
+    let (tx, rx) = mpsc::channel();
+
+    run_benchmark_streaming(&bank, || guesser.clone(), &["cde", "abc", "zzz"], tx)?;
+
+    let mut results: Vec<(String, GameResult)> = rx.iter().collect();
+    results.sort_by(|(a, _), (b, _)| a.cmp(b));
+    assert_eq!(results.len(), 3);
+    for (word, result) in results {
+        assert_eq!(result, play_game_with_guesser(&word, 3, guesser.clone())?);
+    }
+    Ok(())
+}
+
+#[test]
+fn run_benchmark_streaming_returns_update_error() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "bcd", "cde"])?;
+    let (tx, rx) = mpsc::channel();
+
+    let result = run_benchmark_streaming(
+        &bank,
+        || FailingGuesser {
+            guesser: RandomGuesser::new(bank.clone()).with_seed(1),
+        },
+        &bank,
+        tx,
+    );
+
+    // Every game makes the same first guess, so only one word can be solved without an update.
+    assert_matches!(result, Err(WordleError::InvalidResults));
+    assert!(rx.iter().count() <= 1);
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_guess_word_forces_opening() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "wxyz", "zxyc"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+
+    for objective in bank.iter().filter(|word| word.as_ref() != "ghix") {
+        let mut forced_guesser = guesser.clone();
+        // Compute the scores first, to check that they're kept consistent.
+        forced_guesser.select_next_guess();
+        forced_guesser.guess_word("ghix")?;
+        let result = get_result_for_guess(objective, "ghix")?;
+        forced_guesser.update(&result)?;
+
+        let mut expected_guesser = guesser.clone();
+        expected_guesser.update(&result)?;
+
+        assert_eq!(
+            forced_guesser.select_next_guess(),
+            expected_guesser.select_next_guess()
+        );
+        assert_eq!(
+            forced_guesser.possible_words(),
+            expected_guesser.possible_words()
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_guess_word_is_not_selected_again() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    let first_guess = guesser.select_next_guess().unwrap();
+
+    guesser.guess_word(&first_guess)?;
+
+    assert_ne!(guesser.select_next_guess(), Some(first_guess));
+    Ok(())
+}
+
+#[test]
+fn random_guesser_guess_word_removes_unguessed_word() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "def"])?;
+    let mut guesser = RandomGuesser::new(bank);
+
+    guesser.guess_word("abc")?;
+
+    for _ in 0..10 {
+        assert_eq!(
+            guesser
+                .select_next_guess_from(GuessFrom::AllUnguessedWords)
+                .as_deref(),
+            Some("def")
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn guess_word_wrong_length() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "def"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+    let mut random_guesser = RandomGuesser::new(bank);
+
+    assert_matches!(guesser.guess_word("abcd"), Err(WordleError::WordLength(3)));
+    assert_matches!(
+        random_guesser.guess_word("ab"),
+        Err(WordleError::WordLength(3))
+    );
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_export_scores_round_trip() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "wxyz", "zxyc"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+    let expected_guess = guesser.select_next_guess();
+    let scores = guesser.export_scores().unwrap();
+
+    let mut buffer = Vec::new();
+    write_scores(&scores, &mut buffer)?;
+    let read = read_scores(buffer.as_slice())?;
+
+    assert_eq!(read, scores);
+    assert_eq!(read, guesser.get_or_compute_scores());
+
+    // A scorer that can't score anything shows that the stored scores are used.
+    let zero_scorer = FnScorer::new(
+        &bank,
+        |_: &Arc<str>, _: &details::WordRestrictions, _: &[Arc<str>]| 0,
+    );
+    let mut restored_guesser =
+        MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), zero_scorer)
+            .with_scores(&read);
+
+    assert_eq!(restored_guesser.select_next_guess(), expected_guess);
+    assert_ne!(expected_guess.as_ref(), Some(&bank[0]));
+    Ok(())
+}
+
+#[test]
+fn max_score_guesser_export_scores_uses_guess_mode() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "wxyz", "zxyc"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MaxScoreGuesser::new(GuessFrom::PossibleWords, bank, scorer);
+    guesser.update(&get_result_for_guess("wxyz", "abcz")?)?;
+    guesser.select_next_guess();
+
+    let scores = guesser.export_scores().unwrap();
+
+    let mut scored_words: Vec<&str> = scores.keys().map(|word| word.as_ref()).collect();
+    scored_words.sort_unstable();
+    let mut possible_words: Vec<&str> = guesser
+        .possible_words()
+        .iter()
+        .map(|word| word.as_ref())
+        .collect();
+    possible_words.sort_unstable();
+    assert_eq!(scored_words, possible_words);
+    Ok(())
+}
+
+#[test]
+fn read_scores_invalid_line() {
+    assert_matches!(
+        read_scores("abc,1\nbcd\n".as_bytes()),
+        Err(WordleError::IoError(_))
+    );
+    assert_matches!(
+        read_scores("abc,one\n".as_bytes()),
+        Err(WordleError::IoError(_))
+    );
+}
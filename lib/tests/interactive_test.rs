@@ -0,0 +1,112 @@
+#[macro_use]
+extern crate assert_matches;
+
+use rs_wordle_solver::scorers::*;
+use rs_wordle_solver::*;
+
+use std::result::Result;
+
+#[test]
+fn interactive_solver_solves_with_objective() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "wxyz", "zxyc"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+
+    for objective in bank.iter() {
+        let mut solver = InteractiveSolver::new(guesser.clone());
+        let mut last_guess = None;
+        while let Some(guess) = solver.next_guess() {
+            last_guess = Some(guess);
+            solver.submit_result_for_objective(objective)?;
+        }
+
+        assert_eq!(solver.status(), GameStatus::Won);
+        assert_eq!(last_guess.as_ref(), Some(objective));
+        assert_eq!(
+            Ok(solver.num_guesses()),
+            match play_game_with_guesser(objective, 10, guesser.clone())? {
+                GameResult::Success(data) => Ok(data.turns.len() as u32),
+                result => Err(result),
+            }
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn interactive_solver_accepts_raw_results() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd"])?;
+    let guesser = RandomGuesser::new(bank).with_seed(7);
+    let mut solver = InteractiveSolver::new(guesser);
+
+    let guess = solver.next_guess().unwrap();
+    // Repeated calls return the same outstanding guess.
+    assert_eq!(solver.next_guess(), Some(guess.clone()));
+
+    let result = get_result_for_guess("bcd", &guess)?;
+    let status = solver.submit_result(&result.results)?;
+
+    if guess.as_ref() == "bcd" {
+        assert_eq!(status, GameStatus::Won);
+    } else {
+        assert_eq!(status, GameStatus::InProgress);
+        assert!(solver
+            .guesser()
+            .possible_words()
+            .iter()
+            .all(|word| word.as_ref() != guess.as_ref()));
+    }
+    assert_eq!(solver.num_guesses(), 1);
+    Ok(())
+}
+
+#[test]
+fn interactive_solver_rejects_mismatched_results() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd"])?;
+    let mut solver = InteractiveSolver::new(RandomGuesser::new(bank));
+
+    assert_matches!(
+        solver.submit_result(&[LetterResult::Correct; 3]),
+        Err(WordleError::InvalidResults)
+    );
+
+    let guess = solver.next_guess().unwrap();
+    assert_matches!(
+        solver.submit_result(&[LetterResult::Correct; 2]),
+        Err(WordleError::WordLength(3))
+    );
+    assert_matches!(
+        solver.submit_result_for_objective("abcd"),
+        Err(WordleError::WordLength(_))
+    );
+
+    // The guess is still outstanding after an error.
+    assert_eq!(solver.num_guesses(), 0);
+    assert_eq!(solver.next_guess(), Some(guess));
+    Ok(())
+}
+
+#[test]
+fn interactive_solver_reports_contradictory_results() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd"])?;
+    let mut solver = InteractiveSolver::new(RandomGuesser::new(bank));
+
+    solver.next_guess();
+    solver.submit_result(&[
+        LetterResult::Correct,
+        LetterResult::Correct,
+        LetterResult::NotPresent,
+    ])?;
+    solver.next_guess();
+
+    assert_matches!(
+        solver.submit_result(&[LetterResult::NotPresent; 3]),
+        Err(WordleError::ContradictoryResults {
+            letter: 'a',
+            location: Some(0),
+            ..
+        })
+    );
+    assert_eq!(solver.num_guesses(), 1);
+    Ok(())
+}
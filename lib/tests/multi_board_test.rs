@@ -0,0 +1,68 @@
+#[macro_use]
+extern crate assert_matches;
+
+use rs_wordle_solver::scorers::*;
+use rs_wordle_solver::*;
+
+use std::result::Result;
+use std::sync::Arc;
+
+#[test]
+fn multi_board_guesser_solves_all_boards() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec![
+        "abcde", "bcdea", "cdeab", "deabc", "eabcd", "fghij", "fghik", "zzzzz",
+    ])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MultiBoardGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer, 4);
+
+    let results = play_multi_game(&["bcdea", "fghik", "zzzzz", "bcdea"], 10, guesser)?;
+
+    assert_eq!(results.len(), 4);
+    for result in &results {
+        assert_matches!(result, GameResult::Success(_));
+    }
+    // Both boards with the same objective are solved by the same guesses.
+    assert_eq!(results[0], results[3]);
+    Ok(())
+}
+
+#[test]
+fn multi_board_guesser_unknown_words() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd", "cde"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MultiBoardGuesser::new(GuessFrom::PossibleWords, bank, scorer, 3);
+
+    let results = play_multi_game(&["cde", "xyz", "abcd"], 6, guesser.clone())?;
+
+    assert_matches!(results[0], GameResult::Success(_));
+    assert_matches!(results[1], GameResult::UnknownWord);
+    assert_matches!(results[2], GameResult::UnknownWord);
+    assert_eq!(
+        play_multi_game(&["cde"], 6, guesser)?,
+        vec![GameResult::UnknownWord]
+    );
+    Ok(())
+}
+
+#[test]
+fn multi_board_guesser_update() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd", "cde"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let mut guesser = MultiBoardGuesser::new(GuessFrom::PossibleWords, bank, scorer, 2);
+
+    assert_matches!(
+        guesser.update(&[get_result_for_guess("abc", "abc")?]),
+        Err(WordleError::InvalidResults)
+    );
+
+    guesser.update(&[
+        get_result_for_guess("abc", "abc")?,
+        get_result_for_guess("cde", "abc")?,
+    ])?;
+
+    assert!(guesser.is_solved(0));
+    assert!(!guesser.is_solved(1));
+    assert_eq!(guesser.possible_words(1), &[Arc::from("cde")]);
+    assert_eq!(guesser.select_next_guess(), Some(Arc::from("cde")));
+    Ok(())
+}
@@ -1,8 +1,12 @@
 #[macro_use]
 extern crate assert_matches;
 
+use rs_wordle_solver::details::*;
 use rs_wordle_solver::*;
 
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
 #[test]
 fn get_result_for_guess_correct() {
     let result = get_result_for_guess("abcb", "abcb");
@@ -75,6 +79,109 @@ fn get_result_for_guess_partial() {
     );
 }
 
+#[test]
+fn get_result_for_guess_repeated_letters() -> Result<(), WordleError> {
+    use LetterResult::*;
+
+    assert_eq!(
+        get_result_for_guess("erree", "eeeee")?.results,
+        vec![Correct, NotPresent, NotPresent, Correct, Correct]
+    );
+    assert_eq!(
+        get_result_for_guess("erree", "reeer")?.results,
+        vec![
+            PresentNotHere,
+            PresentNotHere,
+            PresentNotHere,
+            Correct,
+            PresentNotHere
+        ]
+    );
+    assert_eq!(
+        get_result_for_guess("errree", "eeeeee")?.results,
+        vec![Correct, NotPresent, NotPresent, NotPresent, Correct, Correct]
+    );
+    assert_eq!(
+        get_result_for_guess("eerree", "reeeee")?.results,
+        vec![
+            PresentNotHere,
+            Correct,
+            PresentNotHere,
+            NotPresent,
+            Correct,
+            Correct
+        ]
+    );
+    assert_eq!(
+        get_result_for_guess("eerree", "xxeeex")?.results,
+        vec![
+            NotPresent,
+            NotPresent,
+            PresentNotHere,
+            PresentNotHere,
+            Correct,
+            NotPresent
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn get_result_for_guess_with_standard_matches_default() -> Result<(), WordleError> {
+    for (objective, guess) in [("mesas", "sassy"), ("abba", "babb"), ("erree", "eeeee")] {
+        assert_eq!(
+            get_result_for_guess_with(objective, guess, DuplicateMode::Standard)?,
+            get_result_for_guess(objective, guess)?
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn get_result_for_guess_with_simple() -> Result<(), WordleError> {
+    use LetterResult::*;
+
+    assert_eq!(
+        get_result_for_guess_with("mesas", "sassy", DuplicateMode::Simple)?.results,
+        vec![
+            PresentNotHere,
+            PresentNotHere,
+            Correct,
+            PresentNotHere,
+            NotPresent
+        ]
+    );
+    assert_eq!(
+        get_result_for_guess_with("erree", "eeeee", DuplicateMode::Simple)?.results,
+        vec![Correct, PresentNotHere, PresentNotHere, Correct, Correct]
+    );
+    assert_eq!(
+        get_result_for_guess_with("abcb", "abcb", DuplicateMode::Simple)?.results,
+        vec![Correct; 4]
+    );
+    assert_matches!(
+        get_result_for_guess_with("goal", "guess", DuplicateMode::Simple),
+        Err(WordleError::WordLength(4))
+    );
+    Ok(())
+}
+
+#[test]
+fn compressed_guess_result_to_results() -> Result<(), WordleError> {
+    let results = get_result_for_guess("eerree", "reeeee")?.results;
+    let compressed = details::CompressedGuessResult::from_results(&results)?;
+
+    assert_eq!(compressed.to_results(), results);
+
+    let longest = vec![LetterResult::NotPresent; details::MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT];
+    let compressed = details::CompressedGuessResult::from_results(&longest)?;
+    assert_eq!(compressed.to_results(), longest);
+
+    let compressed = details::CompressedGuessResult::from_results(&[])?;
+    assert_eq!(compressed.to_results(), vec![]);
+    Ok(())
+}
+
 #[test]
 fn get_result_for_guess_none_match() {
     let result = get_result_for_guess("abcb", "defg");
@@ -95,3 +202,484 @@ fn get_result_for_guess_invalid_guess() {
         Err(WordleError::WordLength(4))
     );
 }
+
+#[test]
+fn wordle_error_eq() {
+    assert_eq!(
+        get_result_for_guess("goal", "guess").unwrap_err(),
+        WordleError::WordLength(4)
+    );
+    assert_ne!(WordleError::WordLength(4), WordleError::WordLength(5));
+    assert_eq!(WordleError::InvalidResults, WordleError::InvalidResults);
+    let contradiction = WordleError::ContradictoryResults {
+        letter: 'a',
+        location: Some(1),
+        reason: "it is already known to be elsewhere",
+    };
+    assert_eq!(
+        contradiction,
+        WordleError::ContradictoryResults {
+            letter: 'a',
+            location: Some(1),
+            reason: "it is already known to be elsewhere",
+        }
+    );
+    assert_ne!(
+        contradiction,
+        WordleError::ContradictoryResults {
+            letter: 'a',
+            location: None,
+            reason: "it is already known to be elsewhere",
+        }
+    );
+    assert_eq!(
+        WordleError::IoError(std::io::Error::new(std::io::ErrorKind::InvalidData, "a")),
+        WordleError::IoError(std::io::Error::new(std::io::ErrorKind::InvalidData, "b"))
+    );
+    assert_ne!(
+        WordleError::IoError(std::io::Error::from(std::io::ErrorKind::InvalidData)),
+        WordleError::IoError(std::io::Error::from(std::io::ErrorKind::NotFound))
+    );
+    assert_ne!(
+        WordleError::InvalidResults,
+        WordleError::IoError(std::io::Error::from(std::io::ErrorKind::InvalidData))
+    );
+}
+
+#[test]
+fn wordle_errors_in_collections() {
+    let errors: Vec<WordleError> = ["abc", "abcd", "abcde"]
+        .iter()
+        .filter_map(|guess| get_result_for_guess("abcd", guess).err())
+        .collect();
+
+    assert_eq!(
+        errors,
+        vec![WordleError::WordLength(4), WordleError::WordLength(4)]
+    );
+}
+
+fn game_data(guesses: &[&str]) -> GameData {
+    GameData {
+        turns: guesses
+            .iter()
+            .map(|guess| TurnData {
+                guess: Box::from(*guess),
+                num_possible_words_before_guess: 1,
+                restrictions: None,
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn game_data_to_share_grid_default_theme() -> Result<(), WordleError> {
+    let data = game_data(&["sassy", "mesas"]);
+
+    assert_eq!(
+        data.to_share_grid("mesas", ShareTheme::default())?,
+        "🟨🟨🟩⬜⬜\n🟩🟩🟩🟩🟩"
+    );
+    assert_eq!(
+        data.to_share_grid("mesas", ShareTheme::Dark)?,
+        "🟨🟨🟩⬛⬛\n🟩🟩🟩🟩🟩"
+    );
+    Ok(())
+}
+
+#[test]
+fn game_data_to_share_grid_high_contrast_theme() -> Result<(), WordleError> {
+    let data = game_data(&["sassy", "mesas"]);
+
+    assert_eq!(
+        data.to_share_grid("mesas", ShareTheme::HighContrastLight)?,
+        "🟦🟦🟧⬜⬜\n🟧🟧🟧🟧🟧"
+    );
+    assert_eq!(
+        data.to_share_grid("mesas", ShareTheme::HighContrastDark)?,
+        "🟦🟦🟧⬛⬛\n🟧🟧🟧🟧🟧"
+    );
+    Ok(())
+}
+
+#[test]
+fn game_data_to_share_grid_wrong_length() {
+    assert_matches!(
+        game_data(&["sass"]).to_share_grid("mesas", ShareTheme::default()),
+        Err(WordleError::WordLength(5))
+    );
+}
+
+#[test]
+fn game_data_possible_word_trajectory() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "wxyz", "zxyc"])?;
+    let scorer = scorers::MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+
+    let data = match play_game_with_guesser("wxyz", 10, guesser)? {
+        GameResult::Success(data) => data,
+        result => panic!("Expected success, got: {:?}", result),
+    };
+    let trajectory = data.possible_word_trajectory();
+
+    assert_eq!(trajectory.len(), data.turns.len());
+    assert_eq!(trajectory[0], bank.len());
+    for (num_possible_words, turn) in trajectory.iter().zip(data.turns.iter()) {
+        assert_eq!(*num_possible_words, turn.num_possible_words_before_guess);
+    }
+    for window in trajectory.windows(2) {
+        assert!(window[0] >= window[1]);
+    }
+    assert_eq!(
+        data.total_reduction_ratio(),
+        Some(bank.len() as f64 / *trajectory.last().unwrap() as f64)
+    );
+    Ok(())
+}
+
+#[test]
+fn game_data_total_reduction_ratio_no_turns() {
+    assert_eq!(game_data(&[]).total_reduction_ratio(), None);
+}
+
+#[test]
+fn game_data_total_reduction_ratio_no_possible_words() {
+    let mut data = game_data(&["abc", "abd"]);
+    data.turns[0].num_possible_words_before_guess = 0;
+    data.turns[1].num_possible_words_before_guess = 0;
+
+    assert_eq!(data.total_reduction_ratio(), None);
+}
+
+#[test]
+fn game_data_accessors() {
+    let data = game_data(&["abc", "bcd"]);
+
+    assert_eq!(data.num_turns(), 2);
+    assert_eq!(data.final_guess(), Some("bcd"));
+    assert_eq!(data.final_num_possible_words(), Some(1));
+
+    let empty = game_data(&[]);
+
+    assert_eq!(empty.num_turns(), 0);
+    assert_eq!(empty.final_guess(), None);
+    assert_eq!(empty.final_num_possible_words(), None);
+}
+
+#[test]
+fn game_result_predicates() {
+    let success = GameResult::Success(game_data(&["abc"]));
+    let failure = GameResult::Failure(game_data(&["abc", "bcd"]));
+    let timed_out = GameResult::TimedOut(game_data(&[]));
+
+    assert!(success.is_success());
+    assert!(!success.is_failure());
+    assert!(!failure.is_success());
+    assert!(failure.is_failure());
+    assert!(!timed_out.is_success());
+    assert!(!timed_out.is_failure());
+    assert!(!GameResult::UnknownWord.is_success());
+    assert!(!GameResult::UnknownWord.is_failure());
+
+    assert_eq!(success.game_data().map(GameData::num_turns), Some(1));
+    assert_eq!(failure.game_data().map(GameData::num_turns), Some(2));
+    assert_eq!(timed_out.game_data().map(GameData::num_turns), Some(0));
+    assert_eq!(GameResult::UnknownWord.game_data(), None);
+}
+
+#[test]
+fn letter_result_parse_emoji_row() -> Result<(), WordleError> {
+    assert_eq!(
+        LetterResult::parse_emoji_row("🟨🟦🟩🟧⬛⬜")?,
+        vec![
+            LetterResult::PresentNotHere,
+            LetterResult::PresentNotHere,
+            LetterResult::Correct,
+            LetterResult::Correct,
+            LetterResult::NotPresent,
+            LetterResult::NotPresent,
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn letter_result_parse_emoji_row_with_variation_selectors() -> Result<(), WordleError> {
+    assert_eq!(
+        LetterResult::parse_emoji_row(" \u{2B1B}\u{FE0F}🟩\u{2B1C}\u{FE0F} ")?,
+        vec![
+            LetterResult::NotPresent,
+            LetterResult::Correct,
+            LetterResult::NotPresent,
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn letter_result_parse_emoji_row_invalid() {
+    assert_matches!(
+        LetterResult::parse_emoji_row("🟩a🟩"),
+        Err(WordleError::InvalidResults)
+    );
+    assert_matches!(
+        LetterResult::parse_emoji_row("🟩🟥🟩"),
+        Err(WordleError::InvalidResults)
+    );
+}
+
+#[test]
+fn letter_result_parse_emoji_grid_round_trip() -> Result<(), WordleError> {
+    let data = game_data(&["sassy", "mesas"]);
+    let grid = data.to_share_grid("mesas", ShareTheme::HighContrastDark)?;
+
+    let parsed = LetterResult::parse_emoji_grid(&format!("{}\n\n", grid))?;
+
+    assert_eq!(
+        parsed,
+        vec![
+            get_result_for_guess("mesas", "sassy")?.results,
+            get_result_for_guess("mesas", "mesas")?.results,
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn letter_result_parse_emoji_grid_invalid_row() {
+    assert_matches!(
+        LetterResult::parse_emoji_grid("🟩🟩🟩\nabc"),
+        Err(WordleError::InvalidResults)
+    );
+}
+
+#[test]
+fn letter_result_from_char() -> Result<(), WordleError> {
+    assert_eq!(LetterResult::from_char('.')?, LetterResult::NotPresent);
+    assert_eq!(LetterResult::from_char('y')?, LetterResult::PresentNotHere);
+    assert_eq!(LetterResult::from_char('Y')?, LetterResult::PresentNotHere);
+    assert_eq!(LetterResult::from_char('g')?, LetterResult::Correct);
+    assert_eq!(LetterResult::from_char('G')?, LetterResult::Correct);
+    assert_matches!(
+        LetterResult::from_char('x'),
+        Err(WordleError::InvalidResults)
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_result_string_succeeds() -> Result<(), WordleError> {
+    assert_eq!(
+        parse_result_string("gY.yG")?,
+        vec![
+            LetterResult::Correct,
+            LetterResult::PresentNotHere,
+            LetterResult::NotPresent,
+            LetterResult::PresentNotHere,
+            LetterResult::Correct,
+        ]
+    );
+    assert_eq!(parse_result_string("")?, Vec::new());
+    Ok(())
+}
+
+#[test]
+fn parse_result_string_invalid() {
+    assert_matches!(parse_result_string("g.b"), Err(WordleError::InvalidResults));
+    assert_matches!(parse_result_string("g. "), Err(WordleError::InvalidResults));
+}
+
+#[test]
+fn get_result_for_guess_multi_byte_letters() -> Result<(), WordleError> {
+    let result = get_result_for_guess("niño", "ñoña")?;
+
+    assert_eq!(
+        result.results,
+        vec![
+            LetterResult::NotPresent,
+            LetterResult::PresentNotHere,
+            LetterResult::Correct,
+            LetterResult::NotPresent,
+        ]
+    );
+    assert_matches!(
+        get_result_for_guess("niño", "nino1"),
+        Err(WordleError::WordLength(4))
+    );
+    Ok(())
+}
+
+#[test]
+fn play_game_with_guesser_records_restrictions() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix", "wxyz", "zxyc"])?;
+    let scorer = scorers::MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+
+    let data = match play_game_with_guesser("wxyz", 10, guesser)? {
+        GameResult::Success(data) => data,
+        result => panic!("Expected success, got: {:?}", result),
+    };
+
+    let mut expected_restrictions = details::WordRestrictions::new(4);
+    for turn in &data.turns {
+        assert_eq!(turn.restrictions.as_ref(), Some(&expected_restrictions));
+        let num_possible_words = bank
+            .iter()
+            .filter(|word| expected_restrictions.is_satisfied_by(word))
+            .count();
+        assert_eq!(num_possible_words, turn.num_possible_words_before_guess);
+        expected_restrictions.update(&get_result_for_guess("wxyz", &turn.guess)?)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn game_stats_from_results() {
+    let results = vec![
+        GameResult::Success(game_data(&["abc"])),
+        GameResult::Success(game_data(&["abc", "def", "ghi"])),
+        GameResult::Success(game_data(&["abc", "def"])),
+        GameResult::Success(game_data(&["abc", "bcd", "def"])),
+        GameResult::Failure(game_data(&["abc", "bcd", "def"])),
+        GameResult::UnknownWord,
+    ];
+
+    let stats = GameStats::from_results(&results);
+
+    assert_eq!(stats.histogram, BTreeMap::from([(1, 1), (2, 1), (3, 2)]));
+    assert_eq!(stats.num_unsolved, 2);
+    assert_eq!(stats.num_solved(), 4);
+    assert_eq!(stats.num_games(), 6);
+    assert_eq!(stats.mean_guesses(), 2.25);
+    assert!((stats.std_dev() - 0.8292).abs() < 0.0001);
+    assert!((stats.win_rate() - 4.0 / 6.0).abs() < f64::EPSILON);
+    assert_eq!(stats.worst_case_guesses(), Some(3));
+    assert_eq!(GameStats::from_results(results), stats);
+}
+
+#[test]
+fn game_stats_empty() {
+    let stats = GameStats::from_results(Vec::<GameResult>::new());
+
+    assert_eq!(stats.num_games(), 0);
+    assert!(stats.mean_guesses().is_nan());
+    assert!(stats.win_rate().is_nan());
+    assert_eq!(stats.worst_case_guesses(), None);
+}
+
+#[test]
+fn game_stats_display() {
+    let mut stats = GameStats::from_results(&[
+        GameResult::Success(game_data(&["abc", "def"])),
+        GameResult::Success(game_data(&["abc"])),
+    ]);
+
+    assert_eq!(
+        stats.to_string(),
+        "|Num guesses|Num games|\n|-----------|---------|\n|1|1|\n|2|1|\n"
+    );
+
+    stats.add_result(&GameResult::UnknownWord);
+
+    assert_eq!(
+        stats.to_string(),
+        "|Num guesses|Num games|\n|-----------|---------|\n|1|1|\n|2|1|\nUnsolved games: 1\n"
+    );
+}
+
+#[test]
+fn contradictory_results_display() {
+    assert_eq!(
+        WordleError::ContradictoryResults {
+            letter: 'a',
+            location: Some(2),
+            reason: "it is already known not to be in the word",
+        }
+        .to_string(),
+        "ContradictoryResults: the result for 'a' at index 2 is impossible, because it is already known not to be in the word"
+    );
+    assert_eq!(
+        WordleError::ContradictoryResults {
+            letter: 'b',
+            location: None,
+            reason: "it is already known to be in the word",
+        }
+        .to_string(),
+        "ContradictoryResults: the result for 'b' is impossible, because it is already known to be in the word"
+    );
+}
+
+#[test]
+fn guesses_reducing_below_threshold() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abb", "abc", "bad", "zza", "zzz"])?;
+
+    let guesses = guesses_reducing_below(&bank, &bank, 2)?;
+
+    assert_eq!(guesses, vec![Arc::from("abb"), Arc::from("abc")]);
+    for guess in guesses.iter() {
+        for objective in bank.iter() {
+            let result = get_result_for_guess(objective, guess)?;
+            let num_remaining = bank
+                .iter()
+                .filter(|word| get_result_for_guess(word, guess).unwrap() == result)
+                .count();
+            assert!(num_remaining < 2);
+        }
+    }
+    assert_eq!(
+        guesses_reducing_below(&bank, &bank, 1)?,
+        Vec::<Arc<str>>::new()
+    );
+    assert_eq!(guesses_reducing_below(&bank, &bank, 6)?.len(), bank.len());
+    Ok(())
+}
+
+#[test]
+fn guesses_reducing_below_wrong_length_fails() {
+    assert_matches!(
+        guesses_reducing_below(&["abc", "abd"], &[Arc::from("abcd")], 2),
+        Err(WordleError::WordLength(3))
+    );
+}
+
+#[test]
+fn ambiguous_word_pairs_flags_indistinguishable_pair() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abcd", "abce", "wxyz", "xdez", "mnop", "mnoq"])?;
+
+    // No other word contains 'p' or 'q', so "mnop" and "mnoq" are always ambiguous. Only "xdez"
+    // can distinguish "abcd" and "abce".
+    assert_eq!(
+        ambiguous_word_pairs(&bank, 3),
+        vec![
+            (Arc::from("abcd"), Arc::from("abce")),
+            (Arc::from("mnop"), Arc::from("mnoq")),
+        ]
+    );
+    assert_eq!(
+        ambiguous_word_pairs(&bank, bank.len()),
+        vec![(Arc::from("mnop"), Arc::from("mnoq"))]
+    );
+    Ok(())
+}
+
+#[test]
+fn count_result_patterns_matches_distinct_results() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abb", "abc", "bad", "zza", "zzz"])?;
+
+    for guess in bank.iter() {
+        let mut results = Vec::new();
+        for objective in bank.iter() {
+            let result = get_result_for_guess(objective, guess)?.results;
+            if !results.contains(&result) {
+                results.push(result);
+            }
+        }
+        assert_eq!(count_result_patterns(guess, &bank)?, results.len());
+    }
+    assert_eq!(count_result_patterns("abc", &[] as &[&str])?, 0);
+    assert_eq!(
+        count_result_patterns("abcd", &bank),
+        Err(WordleError::WordLength(3))
+    );
+    Ok(())
+}
@@ -5,6 +5,7 @@ use rs_wordle_solver::details::*;
 use rs_wordle_solver::scorers::*;
 use rs_wordle_solver::*;
 
+use std::collections::HashMap;
 use std::result::Result;
 use std::sync::Arc;
 
@@ -18,7 +19,7 @@ macro_rules! test_scorer {
             let scorer = $construct_scorer_from_bank_fn(&bank);
             let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
 
-            let result = play_game_with_guesser("alpha", bank.len() as u32, guesser);
+            let result = play_game_with_guesser("alpha", bank.len() as u32, guesser)?;
 
             assert_matches!(result, GameResult::Success(_guesses));
             Ok(())
@@ -32,12 +33,31 @@ macro_rules! test_scorer {
             let scorer = $construct_scorer_from_bank_fn(&bank);
             let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
 
-            let result = play_game_with_guesser("other", bank.len() as u32, guesser);
+            let result = play_game_with_guesser("other", bank.len() as u32, guesser)?;
 
             assert_matches!(result, GameResult::UnknownWord);
             Ok(())
         }
     };
+    ($construct_scorer_from_bank_fn:ident, $try_construct_scorer_from_bank_fn:ident) => {
+        test_scorer!($construct_scorer_from_bank_fn);
+
+        #[test]
+        fn new_rejects_words_too_long_to_compress() -> Result<(), WordleError> {
+            let too_long_bank = WordBank::from_iterator(vec![
+                "a".repeat(MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT + 1),
+                "b".repeat(MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT + 1),
+            ])?;
+
+            assert_eq!(
+                $try_construct_scorer_from_bank_fn(&too_long_bank).err(),
+                Some(WordleError::WordLength(
+                    MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT
+                ))
+            );
+            Ok(())
+        }
+    };
 }
 
 mod max_unique_letters_scorer {
@@ -49,6 +69,79 @@ mod max_unique_letters_scorer {
     }
 
     test_scorer!(create_scorer);
+
+    #[test]
+    fn update_with_subset_matches_rebuilt_scorer() -> Result<(), WordleError> {
+        let bank =
+            WordBank::from_iterator(vec!["alpha", "allot", "begot", "below", "endow", "ingot"])?;
+        let remaining: Vec<Arc<str>> = bank[1..5].to_vec();
+        let restrictions = WordRestrictions::new(5);
+        let mut incremental_scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+        let mut rebuilt_scorer = MaxUniqueLetterFrequencyScorer::new(&["zzzzz"]);
+
+        incremental_scorer.update("zzzzz", &restrictions, &remaining)?;
+        rebuilt_scorer.update("zzzzz", &restrictions, &remaining)?;
+
+        for word in bank.iter() {
+            assert_eq!(
+                incremental_scorer.score_word(word),
+                rebuilt_scorer.score_word(word)
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn update_with_new_words_matches_rebuilt_scorer() -> Result<(), WordleError> {
+        let bank =
+            WordBank::from_iterator(vec!["alpha", "allot", "begot", "below", "ingot", "occur"])?;
+        let mut remaining: Vec<Arc<str>> = bank[1..4].to_vec();
+        remaining.push(Arc::from("endow"));
+        let restrictions = WordRestrictions::new(5);
+        let mut incremental_scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+        let mut rebuilt_scorer = MaxUniqueLetterFrequencyScorer::new(&["zzzzz"]);
+
+        incremental_scorer.update("zzzzz", &restrictions, &remaining)?;
+        rebuilt_scorer.update("zzzzz", &restrictions, &remaining)?;
+
+        for word in bank.iter().chain(remaining.iter()) {
+            assert_eq!(
+                incremental_scorer.score_word(word),
+                rebuilt_scorer.score_word(word)
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn score_word_with_zero_position_weight() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abc", "abd", "abe", "cba"])?;
+        let scorer = MaxUniqueLetterFrequencyScorer::new(&bank);
+        let weighted_scorer = MaxUniqueLetterFrequencyScorer::new(&bank).with_position_weight(0.0);
+
+        for word in bank.iter() {
+            assert_eq!(weighted_scorer.score_word(word), scorer.score_word(word));
+        }
+        assert_eq!(scorer.score_word(&Arc::from("abc")), 4 + 4 + 2);
+        assert_eq!(scorer.score_word(&Arc::from("cba")), 2 + 4 + 4);
+        Ok(())
+    }
+
+    #[test]
+    fn score_word_with_position_weight() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abc", "abd", "abe", "cba"])?;
+        let scorer = MaxUniqueLetterFrequencyScorer::new(&bank).with_position_weight(1.0);
+
+        assert_eq!(
+            scorer.score_word(&Arc::from("abc")),
+            (4 + 4 + 2) + (3 + 4 + 1)
+        );
+        assert_eq!(
+            scorer.score_word(&Arc::from("cba")),
+            (2 + 4 + 4) + (1 + 4 + 1)
+        );
+        Ok(())
+    }
 }
 
 #[allow(clippy::identity_op)]
@@ -62,6 +155,30 @@ mod score_located_letters {
 
     test_scorer!(create_scorer);
 
+    #[test]
+    fn update_with_subset_matches_rebuilt_scorer() -> Result<(), WordleError> {
+        let bank =
+            WordBank::from_iterator(vec!["alpha", "allot", "begot", "below", "endow", "ingot"])?;
+        let remaining: Vec<Arc<str>> = bank[1..5].to_vec();
+        let restrictions = WordRestrictions::from_result(&GuessResult {
+            guess: "zzzzz",
+            results: vec![LetterResult::NotPresent; 5],
+        });
+        let mut incremental_scorer = LocatedLettersScorer::new(&bank);
+        let mut rebuilt_scorer = LocatedLettersScorer::new(&WordBank::from_iterator(["zzzzz"])?);
+
+        incremental_scorer.update("zzzzz", &restrictions, &remaining)?;
+        rebuilt_scorer.update("zzzzz", &restrictions, &remaining)?;
+
+        for word in bank.iter() {
+            assert_eq!(
+                incremental_scorer.score_word(word),
+                rebuilt_scorer.score_word(word)
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn score_word() -> Result<(), WordleError> {
         let bank =
@@ -135,6 +252,53 @@ mod score_located_letters {
     }
 }
 
+mod positional_letter_frequency_scorer {
+
+    use super::*;
+
+    fn create_scorer(bank: &WordBank) -> PositionalLetterFrequencyScorer {
+        PositionalLetterFrequencyScorer::new(bank)
+    }
+
+    test_scorer!(create_scorer);
+
+    #[test]
+    fn score_word() -> Result<(), WordleError> {
+        let bank =
+            WordBank::from_iterator(vec!["alpha", "allot", "begot", "below", "endow", "ingot"])?;
+        let scorer = PositionalLetterFrequencyScorer::new(&bank);
+
+        assert_eq!(scorer.score_word(&Arc::from("alpha")), 2 + 2 + 1 + 1 + 1);
+        assert_eq!(scorer.score_word(&Arc::from("begot")), 2 + 2 + 2 + 5 + 3);
+        assert_eq!(scorer.score_word(&Arc::from("zzzzz")), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn score_word_after_update_skips_correct_letters() -> Result<(), WordleError> {
+        let bank =
+            WordBank::from_iterator(vec!["alpha", "allot", "begot", "below", "endow", "ingot"])?;
+        let mut scorer = PositionalLetterFrequencyScorer::new(&bank);
+
+        let restrictions = WordRestrictions::from_result(&GuessResult {
+            guess: "begot",
+            results: vec![
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+                LetterResult::Correct,
+                LetterResult::NotPresent,
+            ],
+        });
+        scorer.update("begot", &restrictions, &[Arc::from("endow")])?;
+
+        // The 'o' is known to be correct, so only the other four letters count.
+        assert_eq!(scorer.score_word(&Arc::from("endow")), 4);
+        assert_eq!(scorer.score_word(&Arc::from("below")), 1);
+        Ok(())
+    }
+}
+
 mod max_approximate_eliminations_scorer {
 
     use super::*;
@@ -144,30 +308,123 @@ mod max_approximate_eliminations_scorer {
     }
 
     test_scorer!(create_scorer);
+
+    #[test]
+    fn duplicate_letters_ranked_consistently_with_max_eliminations() -> Result<(), WordleError> {
+        let bank =
+            WordBank::from_iterator(vec!["alpha", "allot", "begot", "below", "endow", "ingot"])?;
+        let scorer = MaxApproximateEliminationsScorer::new(&bank);
+        let exact_scorer = MaxEliminationsScorer::new(bank.clone())?;
+
+        // Repeated letters shouldn't re-count the eliminations of words without that letter, so
+        // "allot" and "alpha" shouldn't be ranked above words that are better guesses.
+        for word in bank.iter() {
+            for other_word in bank.iter() {
+                if exact_scorer.score_word(word) > exact_scorer.score_word(other_word) {
+                    assert!(
+                        scorer.score_word(word) >= scorer.score_word(other_word),
+                        "{} should not be ranked below {}",
+                        word,
+                        other_word
+                    );
+                }
+            }
+        }
+        assert_eq!(scorer.score_word(&Arc::from("allot")), 11666);
+        assert_eq!(scorer.score_word(&Arc::from("below")), 14333);
+        Ok(())
+    }
+
+    #[test]
+    fn score_word_with_precision() -> Result<(), WordleError> {
+        let bank =
+            WordBank::from_iterator(vec!["alpha", "allot", "begot", "below", "endow", "ingot"])?;
+        let scorer = MaxApproximateEliminationsScorer::new(&bank).with_precision(1_000_000);
+
+        assert_eq!(scorer.score_word(&Arc::from("allot")), 11666666);
+        assert_eq!(scorer.score_word(&Arc::from("below")), 14333333);
+        Ok(())
+    }
 }
 
 mod max_eliminations_scorer {
 
     use super::*;
 
-    fn create_scorer(bank: &WordBank) -> MaxEliminationsScorer {
+    fn try_create_scorer(bank: &WordBank) -> Result<MaxEliminationsScorer, WordleError> {
         MaxEliminationsScorer::new(bank.clone())
     }
 
-    test_scorer!(create_scorer);
+    fn create_scorer(bank: &WordBank) -> MaxEliminationsScorer {
+        try_create_scorer(bank).unwrap()
+    }
+
+    test_scorer!(create_scorer, try_create_scorer);
 
     #[test]
     fn score_word() {
         let possible_words =
             WordBank::from_iterator(vec![Arc::from("cod"), Arc::from("wod"), Arc::from("mod")])
                 .unwrap();
-        let scorer = MaxEliminationsScorer::new(possible_words.clone());
+        let scorer = MaxEliminationsScorer::new(possible_words.clone()).unwrap();
 
         assert_eq!(scorer.score_word(&possible_words[0]), 1333);
         assert_eq!(scorer.score_word(&Arc::from("mwc")), 2000);
         assert_eq!(scorer.score_word(&Arc::from("zzz")), 0);
     }
 
+    #[test]
+    fn other_constructors_reject_words_too_long_to_compress() -> Result<(), WordleError> {
+        let longest_bank = WordBank::from_iterator(vec![
+            "a".repeat(MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT),
+            "b".repeat(MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT),
+        ])?;
+        let too_long_bank = WordBank::from_iterator(vec![
+            "a".repeat(MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT + 1),
+            "b".repeat(MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT + 1),
+        ])?;
+        let expected_error = || WordleError::WordLength(MAX_LETTERS_IN_COMPRESSED_GUESS_RESULT);
+
+        let scorer = MaxEliminationsScorer::new(longest_bank.clone())?;
+        assert_eq!(scorer.score_word(&longest_bank[0]), 1000);
+        assert!(MaxEliminationsScorer::new_with_cache(longest_bank).is_ok());
+        assert_eq!(
+            MaxEliminationsScorer::new_with_cache(too_long_bank.clone()).err(),
+            Some(expected_error())
+        );
+        assert_eq!(
+            MaxEliminationsScorer::from_first_guess_eliminations(
+                too_long_bank
+                    .iter()
+                    .map(|word| (Arc::clone(word), 0.0))
+                    .collect()
+            )
+            .err(),
+            Some(expected_error())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn explain() {
+        let possible_words = WordBank::from_iterator(vec!["cod", "wod", "mod"]).unwrap();
+        let mut scorer = MaxEliminationsScorer::new(possible_words.clone()).unwrap();
+
+        assert_eq!(
+            scorer.explain(&possible_words[0]).as_deref(),
+            Some("leaves 1.67 of 3 possible words on average")
+        );
+        assert_eq!(
+            scorer.explain(&Arc::from("mwc")).as_deref(),
+            Some("leaves 1.00 of 3 possible words on average")
+        );
+
+        scorer
+            .update("xyz", &WordRestrictions::new(3), &[])
+            .unwrap();
+        assert_eq!(scorer.explain(&possible_words[0]), None);
+    }
+
     #[test]
     fn score_word_after_update() -> Result<(), WordleError> {
         let possible_words = WordBank::from_iterator(vec![
@@ -178,7 +435,7 @@ mod max_eliminations_scorer {
             Arc::from("zzz"),
         ])
         .unwrap();
-        let mut scorer = MaxEliminationsScorer::new(possible_words.clone());
+        let mut scorer = MaxEliminationsScorer::new(possible_words.clone())?;
 
         let restrictions = WordRestrictions::from_result(&GuessResult {
             guess: "zza",
@@ -200,17 +457,318 @@ mod max_eliminations_scorer {
         assert_eq!(scorer.score_word(&Arc::from("zzz")), 0);
         Ok(())
     }
+
+    #[test]
+    fn first_guess_eliminations() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["cod", "wod", "mod"])?;
+        let scorer = MaxEliminationsScorer::new(bank.clone())?;
+
+        let eliminations = scorer.first_guess_eliminations();
+
+        assert_eq!(eliminations.len(), 3);
+        for word in bank.iter() {
+            assert_eq!(
+                (eliminations[word] * 1000.0) as i64,
+                scorer.score_word(word)
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn from_first_guess_eliminations() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abb", "abc", "bad", "zza", "zzz"])?;
+        let table: HashMap<Arc<str>, f64> = bank
+            .iter()
+            .enumerate()
+            .map(|(index, word)| (Arc::clone(word), index as f64))
+            .collect();
+        let mut scorer = MaxEliminationsScorer::from_first_guess_eliminations(table)?;
+
+        // Cached values are used for the first guess.
+        assert_eq!(scorer.score_word(&bank[1]), 1000);
+        assert_eq!(scorer.score_word(&bank[4]), 4000);
+
+        let restrictions = WordRestrictions::from_result(&GuessResult {
+            guess: "zza",
+            results: vec![
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+                LetterResult::PresentNotHere,
+            ],
+        });
+        scorer.update("zza", &restrictions, &Vec::from(&bank[0..3]))?;
+
+        // Scores are computed after the update.
+        assert_eq!(scorer.score_word(&bank[2]), 1333);
+        assert_eq!(scorer.score_word(&bank[4]), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn from_first_guess_eliminations_mismatched_lengths() {
+        let table: HashMap<Arc<str>, f64> =
+            HashMap::from([(Arc::from("abc"), 1.0), (Arc::from("abcd"), 1.0)]);
+
+        assert_matches!(
+            MaxEliminationsScorer::from_first_guess_eliminations(table).err(),
+            Some(WordleError::WordLength(_))
+        );
+    }
+
+    #[test]
+    fn score_word_weighted() -> Result<(), WordleError> {
+        let bank =
+            WeightedWordBank::from_reader(std::io::Cursor::new("abc,1\nabd,1\nabe,1\nxyz,97\n"))?;
+        let scorer = MaxEliminationsScorer::from_weighted_bank(bank.clone())?;
+
+        // Splits into {abc}, {abd, abe}, {xyz}.
+        assert_eq!(scorer.score_word(&Arc::from("abc")), 2980);
+        // Splits into {abc, abd, abe}, {xyz}.
+        assert_eq!(scorer.score_word(&Arc::from("xyz")), 2940);
+
+        let uniform_scorer = MaxEliminationsScorer::new(bank.word_bank().clone())?;
+        assert_eq!(uniform_scorer.score_word(&Arc::from("abc")), 2500);
+        assert_eq!(uniform_scorer.score_word(&Arc::from("xyz")), 1500);
+        Ok(())
+    }
+
+    #[test]
+    fn score_word_weighted_without_weight_is_uniform() -> Result<(), WordleError> {
+        let bank =
+            WeightedWordBank::from_reader(std::io::Cursor::new("abc,0\nabd,0\nabe,0\nxyz,0\n"))?;
+        let scorer = MaxEliminationsScorer::from_weighted_bank(bank)?;
+
+        assert_eq!(scorer.score_word(&Arc::from("abc")), 2500);
+        assert_eq!(scorer.score_word(&Arc::from("xyz")), 1500);
+        Ok(())
+    }
+
+    #[test]
+    fn score_long_words() -> Result<(), WordleError> {
+        let prefix = "abcdefghijklmnopqrst";
+        let bank = WordBank::from_iterator(vec![
+            format!("{}u", prefix),
+            format!("{}v", prefix),
+            format!("{}w", prefix),
+        ])?;
+        let scorer = MaxEliminationsScorer::new(bank.clone())?;
+
+        assert_eq!(bank.word_length(), 21);
+        assert_eq!(scorer.score_word(&bank[0]), 1333);
+        assert_eq!(
+            scorer.score_word(&Arc::from(format!("{}x", prefix).as_str())),
+            0
+        );
+        Ok(())
+    }
+}
+
+mod max_eliminations_scorer_with_cache {
+
+    use super::*;
+
+    fn create_scorer(bank: &WordBank) -> MaxEliminationsScorer {
+        MaxEliminationsScorer::new_with_cache(bank.clone()).unwrap()
+    }
+
+    test_scorer!(create_scorer);
+
+    #[test]
+    fn score_word_matches_uncached() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abb", "abc", "bad", "zza", "zzz"])?;
+        let cached_scorer = MaxEliminationsScorer::new_with_cache(bank.clone())?;
+        let scorer = MaxEliminationsScorer::new(bank.clone())?;
+
+        for word in bank.iter() {
+            assert_eq!(
+                cached_scorer.score_word_precise(word),
+                scorer.score_word_precise(word)
+            );
+        }
+        // Words outside the bank are computed on the fly.
+        assert_eq!(
+            cached_scorer.score_word(&Arc::from("bbz")),
+            scorer.score_word(&Arc::from("bbz"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn score_word_after_update_matches_uncached() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abb", "abc", "bad", "zza", "zzz"])?;
+        let mut cached_scorer = MaxEliminationsScorer::new_with_cache(bank.clone())?;
+        let mut scorer = MaxEliminationsScorer::new(bank.clone())?;
+
+        let restrictions = WordRestrictions::from_result(&GuessResult {
+            guess: "zza",
+            results: vec![
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+                LetterResult::PresentNotHere,
+            ],
+        });
+        cached_scorer.update("zza", &restrictions, &Vec::from(&bank[0..3]))?;
+        scorer.update("zza", &restrictions, &Vec::from(&bank[0..3]))?;
+
+        assert_eq!(cached_scorer.score_word(&bank[0]), 2000);
+        assert_eq!(cached_scorer.score_word(&bank[2]), 1333);
+        assert_eq!(cached_scorer.score_word(&bank[4]), 0);
+        for word in bank.iter() {
+            assert_eq!(
+                cached_scorer.score_word_precise(word),
+                scorer.score_word_precise(word)
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn score_word_with_words_outside_cache() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abb", "abc", "bad"])?;
+        let mut scorer = MaxEliminationsScorer::new_with_cache(bank.clone())?;
+        let possible_words: Vec<Arc<str>> = vec![Arc::from("abb"), Arc::from("zzz")];
+
+        scorer.update("bad", &WordRestrictions::new(3), &possible_words)?;
+
+        assert_eq!(scorer.score_word(&bank[0]), 1000);
+        Ok(())
+    }
+
+    #[test]
+    fn score_long_words() -> Result<(), WordleError> {
+        let prefix = "abcdefghijklmnopqrst";
+        let bank = WordBank::from_iterator(vec![
+            format!("{}u", prefix),
+            format!("{}v", prefix),
+            format!("{}w", prefix),
+        ])?;
+        let scorer = MaxEliminationsScorer::new_with_cache(bank.clone())?;
+
+        assert_eq!(scorer.score_word(&bank[0]), 1333);
+        Ok(())
+    }
+}
+
+mod max_entropy_scorer {
+
+    use super::*;
+
+    fn try_create_scorer(bank: &WordBank) -> Result<MaxEntropyScorer, WordleError> {
+        MaxEntropyScorer::new(bank.clone())
+    }
+
+    fn create_scorer(bank: &WordBank) -> MaxEntropyScorer {
+        try_create_scorer(bank).unwrap()
+    }
+
+    test_scorer!(create_scorer, try_create_scorer);
+
+    #[test]
+    fn score_word() {
+        let possible_words =
+            WordBank::from_iterator(vec![Arc::from("cod"), Arc::from("wod"), Arc::from("mod")])
+                .unwrap();
+        let scorer = MaxEntropyScorer::new(possible_words.clone()).unwrap();
+
+        // Splits the words into groups of 1 and 2.
+        assert_eq!(scorer.score_word(&possible_words[0]), 918);
+        // Splits the words into 3 groups of 1, i.e. log2(3) bits.
+        assert_eq!(scorer.score_word(&Arc::from("mwc")), 1584);
+        assert_eq!(scorer.score_word(&Arc::from("zzz")), 0);
+    }
+
+    #[test]
+    fn explain() {
+        let possible_words = WordBank::from_iterator(vec!["cod", "wod", "mod"]).unwrap();
+        let scorer = MaxEntropyScorer::new(possible_words.clone()).unwrap();
+
+        assert_eq!(
+            scorer.explain(&Arc::from("mwc")).as_deref(),
+            Some("gains 1.58 bits of information")
+        );
+    }
+
+    #[test]
+    fn ties_match_max_eliminations_scorer() -> Result<(), WordleError> {
+        // Every guess splits the words in the same way.
+        let bank = WordBank::from_iterator(vec!["abc", "def", "ghi"])?;
+        let mut entropy_guesser = MaxScoreGuesser::new(
+            GuessFrom::AllUnguessedWords,
+            bank.clone(),
+            MaxEntropyScorer::new(bank.clone())?,
+        );
+        let mut eliminations_guesser = MaxScoreGuesser::new(
+            GuessFrom::AllUnguessedWords,
+            bank.clone(),
+            MaxEliminationsScorer::new(bank)?,
+        );
+
+        assert_eq!(
+            entropy_guesser.select_next_guess(),
+            eliminations_guesser.select_next_guess()
+        );
+        Ok(())
+    }
+}
+
+mod minimax_scorer {
+
+    use super::*;
+
+    fn try_create_scorer(bank: &WordBank) -> Result<MinimaxScorer, WordleError> {
+        MinimaxScorer::new(bank.clone())
+    }
+
+    fn create_scorer(bank: &WordBank) -> MinimaxScorer {
+        try_create_scorer(bank).unwrap()
+    }
+
+    test_scorer!(create_scorer, try_create_scorer);
+
+    #[test]
+    fn score_word() {
+        let possible_words =
+            WordBank::from_iterator(vec![Arc::from("cod"), Arc::from("wod"), Arc::from("mod")])
+                .unwrap();
+        let scorer = MinimaxScorer::new(possible_words.clone()).unwrap();
+
+        // Worst case leaves {wod, mod}.
+        assert_eq!(scorer.score_word(&possible_words[0]), -3);
+        // Always identifies the word.
+        assert_eq!(scorer.score_word(&Arc::from("mwc")), -2);
+        assert_eq!(scorer.score_word(&Arc::from("zzz")), -6);
+    }
+
+    #[test]
+    fn score_word_prefers_possible_words_on_ties() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["cod", "wod", "cwz"])?;
+        let mut scorer = MinimaxScorer::new(bank.clone())?;
+        let restrictions = WordRestrictions::from_result(&GuessResult {
+            guess: "zzz",
+            results: vec![LetterResult::NotPresent; 3],
+        });
+        scorer.update("zzz", &restrictions, &[Arc::from("cod"), Arc::from("wod")])?;
+
+        // Both guesses always identify the word, but only "cod" could be the objective.
+        assert!(scorer.score_word(&Arc::from("cod")) > scorer.score_word(&Arc::from("cwz")));
+        Ok(())
+    }
 }
 
 mod max_combo_eliminations_scorer {
 
     use super::*;
 
+    fn try_create_scorer(bank: &WordBank) -> Result<MaxComboEliminationsScorer, WordleError> {
+        MaxComboEliminationsScorer::new(bank.clone(), GuessFrom::PossibleWords, 3)
+    }
+
     fn create_scorer(bank: &WordBank) -> MaxComboEliminationsScorer {
-        MaxComboEliminationsScorer::new(bank.clone(), GuessFrom::PossibleWords, 3).unwrap()
+        try_create_scorer(bank).unwrap()
     }
 
-    test_scorer!(create_scorer);
+    test_scorer!(create_scorer, try_create_scorer);
 
     #[test]
     fn score_word_under_combo_limit() {
@@ -243,6 +801,58 @@ mod max_combo_eliminations_scorer {
         assert_eq!(scorer.score_word(&Arc::from("zzz")), 1333);
     }
 
+    #[test]
+    fn score_word_with_work_budget() -> Result<(), WordleError> {
+        let possible_words = WordBank::from_iterator(["cod", "wod", "mod"])?;
+        let scorer =
+            MaxComboEliminationsScorer::new(possible_words.clone(), GuessFrom::PossibleWords, 2)?;
+
+        // The work is 3 possible words * 3 words to guess.
+        let under_budget = scorer.clone().with_work_budget(10);
+        assert_eq!(under_budget.score_word(&possible_words[0]), 2033);
+        assert_eq!(under_budget.score_word(&Arc::from("zzz")), 1333);
+
+        let over_budget = scorer.with_work_budget(9);
+        assert_eq!(over_budget.score_word(&possible_words[0]), 1333);
+        assert_eq!(over_budget.score_word(&Arc::from("zzz")), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn work_budget_is_checked_after_each_update() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(["cod", "wod", "mod", "aaa", "bbb"])?;
+        let mut scorer =
+            MaxComboEliminationsScorer::new(bank.clone(), GuessFrom::PossibleWords, 2)?
+                .with_work_budget(10);
+        let zzz = Arc::from("zzz");
+
+        // 5 * 5 is over budget, so this only scores single-guess eliminations.
+        assert_eq!(scorer.score_word(&zzz), 0);
+
+        let restrictions = WordRestrictions::from_result(&get_result_for_guess("cod", "aaa")?);
+        scorer.update("aaa", &restrictions, &bank[0..3])?;
+
+        // 3 * 3 is within budget, so this now does the combo search.
+        assert_eq!(scorer.score_word(&zzz), 1333);
+        Ok(())
+    }
+
+    #[test]
+    fn score_word_over_combo_limit_with_precision() {
+        let possible_words =
+            WordBank::from_iterator(&[Arc::from("cod"), Arc::from("wod"), Arc::from("mod")])
+                .unwrap();
+        let scorer =
+            MaxComboEliminationsScorer::new(possible_words.clone(), GuessFrom::PossibleWords, 2)
+                .unwrap()
+                .with_precision(1_000_000);
+
+        // The bonus for finding the objective scales with the precision.
+        assert_eq!(scorer.score_word(&possible_words[0]), 2033333);
+        assert_eq!(scorer.score_word(&Arc::from("mwc")), 2100000);
+        assert_eq!(scorer.score_word(&Arc::from("zzz")), 1333333);
+    }
+
     #[test]
     fn score_word_after_update() -> Result<(), WordleError> {
         let possible_words = WordBank::from_iterator(&[
@@ -279,3 +889,428 @@ mod max_combo_eliminations_scorer {
         Ok(())
     }
 }
+
+mod biased_eliminations_scorer {
+
+    use super::*;
+
+    fn create_scorer(bank: &WordBank) -> BiasedEliminationsScorer<MaxEliminationsScorer> {
+        BiasedEliminationsScorer::new(MaxEliminationsScorer::new(bank.clone()).unwrap(), 10)
+    }
+
+    test_scorer!(create_scorer);
+
+    #[test]
+    fn score_word_adds_bonus_to_possible_words() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abb", "abc", "bad", "zza", "zzz"])?;
+        let mut scorer =
+            BiasedEliminationsScorer::new(MaxEliminationsScorer::new(bank.clone())?, 10);
+        let inner_scorer = MaxEliminationsScorer::new(bank.clone())?;
+
+        // All words are possible before the first update.
+        for word in bank.iter() {
+            assert_eq!(scorer.score_word(word), inner_scorer.score_word(word) + 10);
+        }
+
+        let restrictions = WordRestrictions::from_result(&GuessResult {
+            guess: "zza",
+            results: vec![
+                LetterResult::NotPresent,
+                LetterResult::NotPresent,
+                LetterResult::PresentNotHere,
+            ],
+        });
+        scorer.update("zza", &restrictions, &Vec::from(&bank[0..3]))?;
+
+        assert_eq!(scorer.score_word(&bank[0]), 2010);
+        assert_eq!(scorer.score_word(&bank[2]), 1343);
+        assert_eq!(scorer.score_word(&bank[3]), 0);
+        assert_eq!(scorer.score_word(&bank[4]), 0);
+        assert_eq!(scorer.score_word_precise(&bank[4]), 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn select_next_guess_prefers_possible_word_on_near_tie() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["bcz", "abc", "abd"])?;
+        let restrictions = WordRestrictions::from_result(&get_result_for_guess("abc", "xyz")?);
+        let possible_words = Vec::from(&bank[1..3]);
+
+        let mut unbiased = MaxEliminationsScorer::new(bank.clone())?;
+        unbiased.update("xyz", &restrictions, &possible_words)?;
+        let mut biased =
+            BiasedEliminationsScorer::new(MaxEliminationsScorer::new(bank.clone())?, 1);
+        biased.update("xyz", &restrictions, &possible_words)?;
+
+        // "bcz" can't be the answer, but splits the possible words just as well.
+        assert_eq!(unbiased.score_word(&bank[0]), unbiased.score_word(&bank[1]));
+        assert!(biased.score_word(&bank[1]) > biased.score_word(&bank[0]));
+        Ok(())
+    }
+}
+
+mod weighted_scorer {
+
+    use super::*;
+
+    fn create_scorer(
+        bank: &WordBank,
+    ) -> WeightedScorer<LocatedLettersScorer, MaxApproximateEliminationsScorer> {
+        WeightedScorer::new(
+            LocatedLettersScorer::new(bank),
+            0.5,
+            MaxApproximateEliminationsScorer::new(bank),
+            0.5,
+        )
+    }
+
+    test_scorer!(create_scorer);
+
+    #[test]
+    fn score_word_is_weighted_sum() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abb", "abc", "bad", "zza", "zzz"])?;
+        let mut scorer = WeightedScorer::new(
+            MaxEliminationsScorer::new(bank.clone())?,
+            0.25,
+            MinimaxScorer::new(bank.clone())?,
+            2.0,
+        );
+        let mut first = MaxEliminationsScorer::new(bank.clone())?;
+        let mut second = MinimaxScorer::new(bank.clone())?;
+
+        let restrictions = WordRestrictions::from_result(&get_result_for_guess("abb", "zza")?);
+        let possible_words = Vec::from(&bank[0..3]);
+        scorer.update("zza", &restrictions, &possible_words)?;
+        first.update("zza", &restrictions, &possible_words)?;
+        second.update("zza", &restrictions, &possible_words)?;
+
+        for word in bank.iter() {
+            let expected =
+                0.25 * first.score_word_precise(word) + 2.0 * second.score_word_precise(word);
+            assert_eq!(scorer.score_word_precise(word), expected);
+            assert_eq!(scorer.score_word(word), expected.round() as i64);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn score_word_rounds_to_nearest() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abc", "def"])?;
+        let scorer = WeightedScorer::new(
+            FnScorer::new(
+                &bank,
+                |_: &Arc<str>, _: &WordRestrictions, _: &[Arc<str>]| 3,
+            ),
+            0.5,
+            FnScorer::new(
+                &bank,
+                |_: &Arc<str>, _: &WordRestrictions, _: &[Arc<str>]| 1,
+            ),
+            0.1,
+        );
+
+        assert_eq!(scorer.score_word_precise(&bank[0]), 1.6);
+        assert_eq!(scorer.score_word(&bank[0]), 2);
+        Ok(())
+    }
+}
+
+mod exploration_scorer {
+
+    use super::*;
+
+    fn create_scorer(bank: &WordBank) -> ExplorationScorer {
+        ExplorationScorer::new(bank)
+    }
+
+    test_scorer!(create_scorer);
+
+    #[test]
+    fn score_word_counts_unknown_distinct_letters() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["alpha", "allot", "begot", "below", "endow"])?;
+        let mut scorer = create_scorer(&bank);
+
+        assert_eq!(scorer.score_word(&bank[0]), 4);
+        assert_eq!(scorer.score_word(&bank[1]), 4);
+
+        // 'b', 'e', and 'o' are now known to be present, and 'l' and 'w' are known to be absent.
+        let restrictions = WordRestrictions::from_result(&get_result_for_guess("begot", "below")?);
+        scorer.update("below", &restrictions, &bank[2..3])?;
+
+        assert_eq!(scorer.score_word(&bank[0]), 3);
+        assert_eq!(scorer.score_word(&bank[2]), 2);
+        assert_eq!(scorer.score_word(&bank[3]), 0);
+        Ok(())
+    }
+}
+
+mod constant_scorer {
+
+    use super::*;
+
+    fn create_scorer(_bank: &WordBank) -> ConstantScorer {
+        ConstantScorer::new(3)
+    }
+
+    test_scorer!(create_scorer);
+
+    #[test]
+    fn score_word_is_constant() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abc", "def", "ghi"])?;
+        let mut scorer = create_scorer(&bank);
+
+        assert_eq!(scorer.score_word(&bank[0]), 3);
+        scorer.update(
+            "abc",
+            &WordRestrictions::from_result(&get_result_for_guess("def", "abc")?),
+            &bank[1..],
+        )?;
+        assert_eq!(scorer.score_word(&bank[0]), 3);
+        assert_eq!(scorer.score_word(&Arc::from("zzz")), 3);
+        Ok(())
+    }
+}
+
+mod fn_scorer {
+
+    use super::*;
+
+    /// Scores words by the number of possible words that share at least one unknown letter with
+    /// them.
+    fn letter_coverage(
+        word: &Arc<str>,
+        restrictions: &WordRestrictions,
+        possible_words: &[Arc<str>],
+    ) -> i64 {
+        let unknown_letters: Vec<char> = word
+            .chars()
+            .filter(|letter| restrictions.state_at(*letter, None).is_none())
+            .collect();
+        possible_words
+            .iter()
+            .filter(|possible_word| {
+                unknown_letters
+                    .iter()
+                    .any(|letter| possible_word.contains(*letter))
+            })
+            .count() as i64
+    }
+
+    fn create_scorer(bank: &WordBank) -> impl WordScorer + Clone + Sync {
+        FnScorer::new(bank, letter_coverage)
+    }
+
+    test_scorer!(create_scorer);
+
+    #[test]
+    fn score_word() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+        let mut scorer = create_scorer(&bank);
+
+        assert_eq!(scorer.score_word(&Arc::from("abcz")), 2);
+        assert_eq!(scorer.score_word(&Arc::from("wdyx")), 3);
+
+        let mut restrictions = WordRestrictions::new(4);
+        let result = GuessResult {
+            guess: "ghix",
+            results: vec![LetterResult::NotPresent; 4],
+        };
+        restrictions.update(&result)?;
+        scorer.update(
+            "ghix",
+            &restrictions,
+            &[Arc::from("abcz"), Arc::from("weyz"), Arc::from("defy")],
+        )?;
+
+        // 'x' is now known to be absent, so it no longer covers any words.
+        assert_eq!(scorer.score_word(&Arc::from("wdyx")), 2);
+        assert_eq!(scorer.score_word(&Arc::from("ghix")), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn explain_defaults_to_none() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+        let scorer = create_scorer(&bank);
+
+        assert_eq!(scorer.explain(&bank[0]), None);
+        Ok(())
+    }
+
+    #[test]
+    fn select_next_guess_with_closure() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abcz", "weyz", "defy", "ghix"])?;
+        let scorer = FnScorer::new(
+            &bank,
+            |word: &Arc<str>, _restrictions: &WordRestrictions, possible_words: &[Arc<str>]| {
+                possible_words
+                    .iter()
+                    .filter(|possible_word| {
+                        word.chars().any(|letter| possible_word.contains(letter))
+                    })
+                    .count() as i64
+            },
+        );
+        let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+
+        assert_eq!(guesser.select_next_guess(), Some(Arc::from("weyz")));
+        Ok(())
+    }
+}
+
+mod min_expected_guesses_exact_scorer {
+
+    use super::*;
+
+    fn try_create_scorer(bank: &WordBank) -> Result<MinExpectedGuessesExactScorer, WordleError> {
+        MinExpectedGuessesExactScorer::new(bank.clone())
+    }
+
+    fn create_scorer(bank: &WordBank) -> MinExpectedGuessesExactScorer {
+        try_create_scorer(bank).unwrap()
+    }
+
+    test_scorer!(create_scorer, try_create_scorer);
+
+    /// Computes the minimum possible expected number of guesses by exhaustive search.
+    fn optimal_expected_guesses(possible_words: &[Arc<str>], all_guesses: &[Arc<str>]) -> f64 {
+        if possible_words.len() == 1 {
+            return 1.0;
+        }
+        all_guesses
+            .iter()
+            .filter_map(|guess| {
+                let mut buckets: Vec<(GuessResult, Vec<Arc<str>>)> = Vec::new();
+                for word in possible_words {
+                    let result = get_result_for_guess(word, guess).unwrap();
+                    match buckets.iter_mut().find(|(other, _)| *other == result) {
+                        Some((_, bucket)) => bucket.push(Arc::clone(word)),
+                        None => buckets.push((result, vec![Arc::clone(word)])),
+                    }
+                }
+                if buckets.len() == 1 && !possible_words.contains(guess) {
+                    return None;
+                }
+                let total: f64 = buckets
+                    .iter()
+                    .filter(|(_, bucket)| bucket.as_slice() != [Arc::clone(guess)])
+                    .map(|(_, bucket)| {
+                        bucket.len() as f64 * optimal_expected_guesses(bucket, all_guesses)
+                    })
+                    .sum();
+                Some(1.0 + total / possible_words.len() as f64)
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn achieves_optimal_average() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec![
+            "bat", "cat", "hat", "mat", "rat", "sat", "tab", "tam", "bam",
+        ])?;
+        let scorer = MinExpectedGuessesExactScorer::new(bank.clone())?.with_max_depth(3);
+        let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+
+        let total_guesses: usize = bank
+            .iter()
+            .map(
+                |word| match play_game_with_guesser(word, 10, guesser.clone()) {
+                    Ok(GameResult::Success(data)) => data.turns.len(),
+                    _ => panic!("Failed to solve {}", word),
+                },
+            )
+            .sum();
+        let average = total_guesses as f64 / bank.len() as f64;
+
+        let optimal = optimal_expected_guesses(&bank, &bank);
+        assert!(
+            (average - optimal).abs() < 1e-9,
+            "average {} != optimal {}",
+            average,
+            optimal
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn scores_lower_expected_guesses_higher() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["abc", "abd", "abe", "xyz"])?;
+        let scorer = MinExpectedGuessesExactScorer::new(bank)?;
+
+        // "abc" solves 1/4 immediately and leaves pairs or singles. "xyz" can't split the "ab"
+        // words.
+        assert!(scorer.score_word(&Arc::from("abc")) > scorer.score_word(&Arc::from("xyz")));
+        assert_eq!(
+            scorer.score_word_precise(&Arc::from("xyz")),
+            -(1.0 + 3.0 / 4.0 * 2.0) * 1000.0
+        );
+        Ok(())
+    }
+}
+
+mod min_expected_guesses_approximate_scorer {
+
+    use super::*;
+
+    fn try_create_scorer(
+        bank: &WordBank,
+    ) -> Result<MinExpectedGuessesApproximateScorer, WordleError> {
+        MinExpectedGuessesApproximateScorer::new(bank.clone())
+    }
+
+    fn create_scorer(bank: &WordBank) -> MinExpectedGuessesApproximateScorer {
+        try_create_scorer(bank).unwrap()
+    }
+
+    test_scorer!(create_scorer, try_create_scorer);
+
+    #[test]
+    fn score_word() {
+        let possible_words = WordBank::from_iterator(vec!["cod", "wod", "mod"]).unwrap();
+        let scorer = MinExpectedGuessesApproximateScorer::new(possible_words.clone()).unwrap();
+
+        // Solves 1/3 immediately, else leaves {wod, mod}: 1 + 2/3 * (1 + log2(2)).
+        assert_eq!(scorer.score_word(&possible_words[0]), -2333);
+        // Always identifies the word: 1 + 1.
+        assert_eq!(scorer.score_word(&Arc::from("mwc")), -2000);
+        // Provides no information: 1 + 1 + log2(3).
+        assert_eq!(scorer.score_word(&Arc::from("zzz")), -3584);
+    }
+
+    #[test]
+    fn score_word_with_precision() {
+        let possible_words = WordBank::from_iterator(vec!["cod", "wod", "mod"]).unwrap();
+        let scorer = MinExpectedGuessesApproximateScorer::new(possible_words.clone())
+            .unwrap()
+            .with_precision(10);
+
+        assert_eq!(scorer.score_word(&Arc::from("mwc")), -20);
+    }
+
+    #[test]
+    fn explain() {
+        let possible_words = WordBank::from_iterator(vec!["cod", "wod", "mod"]).unwrap();
+        let scorer = MinExpectedGuessesApproximateScorer::new(possible_words).unwrap();
+
+        assert_eq!(
+            scorer.explain(&Arc::from("mwc")).as_deref(),
+            Some("needs about 2.00 guesses on average")
+        );
+    }
+
+    #[test]
+    fn score_word_after_update() -> Result<(), WordleError> {
+        let bank = WordBank::from_iterator(vec!["cod", "wod", "mod", "cwz"])?;
+        let mut scorer = MinExpectedGuessesApproximateScorer::new(bank.clone())?;
+        let restrictions = WordRestrictions::from_result(&GuessResult {
+            guess: "zzz",
+            results: vec![LetterResult::NotPresent; 3],
+        });
+        scorer.update("zzz", &restrictions, &[Arc::from("cod"), Arc::from("wod")])?;
+
+        // Both guesses always identify the word, but only "cod" could be the objective.
+        assert_eq!(scorer.score_word(&Arc::from("cod")), -1500);
+        assert_eq!(scorer.score_word(&Arc::from("cwz")), -2000);
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+#[macro_use]
+extern crate assert_matches;
+
+use rs_wordle_solver::scorers::*;
+use rs_wordle_solver::*;
+
+use std::result::Result;
+
+fn solve_with_tree(tree: &DecisionTree, objective: &str) -> Option<u32> {
+    let mut node = tree;
+    let mut num_guesses = 1;
+    while node.guess().as_ref() != objective {
+        let result = get_result_for_guess(objective, node.guess()).ok()?;
+        node = node.next(&result.results)?;
+        num_guesses += 1;
+    }
+    Some(num_guesses)
+}
+
+#[test]
+fn decision_tree_solves_bank() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec![
+        "abcde", "bcdea", "cdeab", "deabc", "eabcd", "fghij", "fghik", "zzzzz",
+    ])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+
+    let tree = DecisionTree::from_guesser(&guesser).unwrap();
+
+    for word in bank.iter() {
+        assert_matches!(solve_with_tree(&tree, word), Some(_));
+    }
+    Ok(())
+}
+
+#[test]
+fn decision_tree_bytes_round_trip() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec![
+        "abcde", "bcdea", "cdeab", "deabc", "eabcd", "fghij", "fghik", "zzzzz",
+    ])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+    let tree = DecisionTree::from_guesser(&guesser).unwrap();
+
+    let bytes = tree.to_bytes(&bank)?;
+    let decoded = DecisionTree::from_bytes(&bytes, &bank)?;
+
+    assert_eq!(decoded, tree);
+    for word in bank.iter() {
+        assert_eq!(
+            solve_with_tree(&decoded, word),
+            solve_with_tree(&tree, word)
+        );
+        assert_matches!(solve_with_tree(&decoded, word), Some(_));
+    }
+    Ok(())
+}
+
+#[test]
+fn decision_tree_from_bytes_invalid() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+    let tree = DecisionTree::from_guesser(&guesser).unwrap();
+    let bytes = tree.to_bytes(&bank)?;
+
+    assert_matches!(
+        DecisionTree::from_bytes(&bytes[..bytes.len() - 1], &bank),
+        Err(WordleError::IoError(_))
+    );
+    assert_matches!(
+        DecisionTree::from_bytes(&[bytes.as_slice(), &[0]].concat(), &bank),
+        Err(WordleError::IoError(_))
+    );
+    // Guess index 3 is out of bounds.
+    assert_matches!(
+        DecisionTree::from_bytes(&[3, 0, 0, 0, 0, 0], &bank),
+        Err(WordleError::IoError(_))
+    );
+    Ok(())
+}
+
+#[test]
+fn decision_tree_to_bytes_requires_guesses_in_bank() -> Result<(), WordleError> {
+    let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd"])?;
+    let scorer = MaxEliminationsScorer::new(bank.clone())?;
+    let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+    let tree = DecisionTree::from_guesser(&guesser).unwrap();
+
+    let other_bank = WordBank::from_iterator(vec!["xyz"])?;
+
+    assert_matches!(tree.to_bytes(&other_bank), Err(WordleError::IoError(_)));
+    Ok(())
+}
@@ -1,18 +1,21 @@
 #[cfg(test)]
 mod tests {
 
+    use std::collections::HashMap;
     use std::error::Error;
     use std::fs::File;
     use std::io;
+    use std::sync::Arc;
 
-    use ron;
-    use rs_wordle_solver::scorers::{MaxEliminationsScorer, WordScorer};
+    use rs_wordle_solver::scorers::{
+        MaxEliminationsScorer, MaxEntropyScorer, PositionalLetterFrequencyScorer, WordScorer,
+    };
     use rs_wordle_solver::*;
 
     #[test]
     fn max_eliminations_scorer_serde() {
         let word_bank = WordBank::from_iterator(vec!["worda", "wordb"]).unwrap();
-        let scorer = MaxEliminationsScorer::new(word_bank.clone());
+        let scorer = MaxEliminationsScorer::new(word_bank.clone()).unwrap();
         let score = scorer.score_word(&(&word_bank)[0]);
 
         let ser = ron::to_string(&scorer);
@@ -24,12 +27,57 @@ mod tests {
         assert_eq!(deser_score, score);
     }
 
+    #[test]
+    fn max_eliminations_scorer_from_first_guess_eliminations_serde() -> Result<(), Box<dyn Error>> {
+        let word_bank = WordBank::from_iterator(vec!["worda", "wordb", "wordc"])?;
+        let scorer = MaxEliminationsScorer::new(word_bank.clone())?;
+        let table = scorer.first_guess_eliminations();
+
+        let ser = ron::to_string(&table)?;
+        let deser = ron::from_str::<HashMap<Arc<str>, f64>>(&ser)?;
+        let restored = MaxEliminationsScorer::from_first_guess_eliminations(deser)?;
+
+        for word in word_bank.iter() {
+            assert_eq!(restored.score_word(word), scorer.score_word(word));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn max_entropy_scorer_serde() {
+        let word_bank = WordBank::from_iterator(vec!["worda", "wordb"]).unwrap();
+        let scorer = MaxEntropyScorer::new(word_bank.clone()).unwrap();
+        let score = scorer.score_word(&word_bank[0]);
+
+        let ser = ron::to_string(&scorer);
+        assert!(ser.is_ok());
+
+        let deser = ron::from_str::<MaxEntropyScorer>(&ser.unwrap());
+        assert!(deser.is_ok());
+        let deser_score = deser.unwrap().score_word(&word_bank[0]);
+        assert_eq!(deser_score, score);
+    }
+
+    #[test]
+    fn positional_letter_frequency_scorer_serde() -> Result<(), Box<dyn Error>> {
+        let word_bank = WordBank::from_iterator(vec!["worda", "wordb", "abcde"])?;
+        let scorer = PositionalLetterFrequencyScorer::new(&word_bank);
+
+        let ser = ron::to_string(&scorer)?;
+        let deser = ron::from_str::<PositionalLetterFrequencyScorer>(&ser)?;
+
+        for word in word_bank.iter() {
+            assert_eq!(deser.score_word(word), scorer.score_word(word));
+        }
+        Ok(())
+    }
+
     #[test]
     fn max_score_guesser_serde() -> Result<(), Box<dyn Error>> {
         let all_words = io::BufReader::new(File::open("../data/1000-improved-words-shuffled.txt")?);
 
         let bank = WordBank::from_reader(all_words)?;
-        let scorer = MaxEliminationsScorer::new(bank.clone());
+        let scorer = MaxEliminationsScorer::new(bank.clone())?;
         let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
         // Assume the word is "groan".
         guesser.update(&GuessResult {
@@ -53,4 +101,73 @@ mod tests {
         assert_eq!(deser_top_guesses, top_guesses);
         Ok(())
     }
+
+    #[test]
+    fn word_bank_preserving_case_serde() -> Result<(), Box<dyn Error>> {
+        let bank = WordBank::from_iterator_preserving_case(vec!["Paris", "Nancy"])?;
+
+        let ser = ron::to_string(&bank)?;
+        let deser = ron::from_str::<WordBank>(&ser)?;
+
+        assert_eq!(deser, bank);
+        assert_eq!(deser.original("paris"), Some("Paris"));
+        Ok(())
+    }
+
+    #[test]
+    fn game_result_serde() -> Result<(), Box<dyn Error>> {
+        let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd", "xyz"])?;
+        let scorer = MaxEliminationsScorer::new(bank.clone())?;
+        let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+        let result = play_game_with_guesser("bcd", 5, guesser)?;
+
+        let ser = ron::to_string(&result)?;
+        let deser = ron::from_str::<GameResult>(&ser)?;
+
+        assert_eq!(deser, result);
+        Ok(())
+    }
+
+    #[test]
+    fn game_stats_serde() -> Result<(), Box<dyn Error>> {
+        let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd", "xyz"])?;
+        let scorer = MaxEliminationsScorer::new(bank.clone())?;
+        let guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank.clone(), scorer);
+        let results = bank
+            .iter()
+            .map(|word| play_game_with_guesser(word, 5, guesser.clone()))
+            .collect::<Result<Vec<_>, WordleError>>()?;
+        let stats = GameStats::from_results(&results);
+
+        let ser = ron::to_string(&stats)?;
+        let deser = ron::from_str::<GameStats>(&ser)?;
+
+        assert_eq!(deser, stats);
+        Ok(())
+    }
+
+    #[test]
+    fn guess_result_buf_serde() -> Result<(), Box<dyn Error>> {
+        let result = GuessResultBuf::from(get_result_for_guess("mesas", "sassy")?);
+
+        let ser = ron::to_string(&result)?;
+        let deser = ron::from_str::<GuessResultBuf>(&ser)?;
+
+        assert_eq!(deser, result);
+        Ok(())
+    }
+
+    #[test]
+    fn scored_guess_serde() -> Result<(), Box<dyn Error>> {
+        let bank = WordBank::from_iterator(vec!["abc", "abd", "bcd", "xyz"])?;
+        let scorer = MaxEliminationsScorer::new(bank.clone())?;
+        let mut guesser = MaxScoreGuesser::new(GuessFrom::AllUnguessedWords, bank, scorer);
+        let top_guesses = guesser.select_top_n_guesses(2);
+
+        let ser = ron::to_string(&top_guesses)?;
+        let deser = ron::from_str::<Vec<ScoredGuess>>(&ser)?;
+
+        assert_eq!(deser, top_guesses);
+        Ok(())
+    }
 }